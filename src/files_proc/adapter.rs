@@ -0,0 +1,66 @@
+//! A small registry mapping each [`InputFileType`] to the parser that turns its file content
+//! into a [`Bom`], so [`crate::files_proc::processor`]'s per-file dispatch is a single lookup
+//! instead of a hand-maintained match arm repeated at every call site.
+//!
+//! Candidate detection (which extensions/magic bytes claim a given format) stays centralized in
+//! [`InputFileType::with_extension`]/[`InputFileType::detect_from_content`], since that's already
+//! the single source of truth for it. Adding a new input format (e.g. SPDX, CSAF VEX) means
+//! adding a variant there, a parser function in [`crate::lib_utils::run_utils`], and one entry to
+//! [`ADAPTERS`] below; [`SingleFileProcProvider`](crate::files_proc::traits::SingleFileProcProvider)'s
+//! dispatch and the `--merge` path don't need to change. Enabling/disabling a registered format is
+//! still done via [`Config::file_types_to_process`](crate::lib_utils::config::Config::file_types_to_process).
+
+use crate::files_proc::model::input_file_type::InputFileType;
+use crate::lib_utils::run_utils::{parse_vex_json, parse_vex_protobuf, parse_vex_xml};
+use cyclonedx_bom::prelude::Bom;
+use std::error::Error;
+use std::path::Path;
+
+/// One entry in the format registry: the [`InputFileType`] it handles and the function that
+/// parses a file of that type into a [`Bom`].
+pub(crate) struct FormatAdapter {
+    pub(crate) file_type: InputFileType,
+    pub(crate) parse: fn(&Path, bool) -> Result<Bom, Box<dyn Error>>,
+}
+
+/// Every registered format adapter, in declaration order. [`adapter_for`] is the usual way to
+/// look one up by [`InputFileType`].
+pub(crate) const ADAPTERS: &[FormatAdapter] = &[
+    FormatAdapter {
+        file_type: InputFileType::JSON,
+        parse: parse_vex_json::<&Path>,
+    },
+    FormatAdapter {
+        file_type: InputFileType::XML,
+        parse: parse_vex_xml::<&Path>,
+    },
+    FormatAdapter {
+        file_type: InputFileType::PROTOBUF,
+        parse: parse_vex_protobuf::<&Path>,
+    },
+];
+
+/// Looks up the registered adapter for `file_type`. Returns `None` for
+/// [`InputFileType::UNSUPPORTED`] or any other type without a registered adapter.
+pub(crate) fn adapter_for(file_type: InputFileType) -> Option<&'static FormatAdapter> {
+    ADAPTERS
+        .iter()
+        .find(|adapter| adapter.file_type == file_type)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_adapter_for_known_types() {
+        assert!(adapter_for(InputFileType::JSON).is_some());
+        assert!(adapter_for(InputFileType::XML).is_some());
+        assert!(adapter_for(InputFileType::PROTOBUF).is_some());
+    }
+
+    #[test]
+    fn test_adapter_for_unsupported_is_none() {
+        assert!(adapter_for(InputFileType::UNSUPPORTED).is_none());
+    }
+}
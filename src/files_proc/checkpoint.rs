@@ -0,0 +1,143 @@
+//! Checkpoint manifest for resumable batch runs.
+//!
+//! When `--resume` ([`crate::lib_utils::config::Config::resume`]) is enabled,
+//! [`DefaultSingleFileProcessor`](crate::files_proc::processor::DefaultSingleFileProcessor)
+//! skips regenerating a PDF whose existing output is already newer than its source, and records
+//! a `{source_path, source_mtime, output_path, status}` entry per processed file in a small JSON
+//! file under `output_dir`. Re-invoking vex2pdf over the same tree after an interrupted run
+//! reads this manifest back so only the files that are unfinished or have changed get
+//! reprocessed.
+
+use crate::lib_utils::errors::Vex2PdfError;
+use crate::lib_utils::fs_context;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Name of the checkpoint manifest file vex2pdf maintains inside `output_dir` when `--resume`
+/// is enabled.
+pub const CHECKPOINT_FILENAME: &str = ".vex2pdf-checkpoint.json";
+
+/// Outcome of processing a single file, as recorded in the checkpoint manifest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum CheckpointStatus {
+    Success,
+    Failed,
+}
+
+/// A single source/output pairing recorded in the checkpoint manifest.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct CheckpointEntry {
+    pub source_path: PathBuf,
+    /// The source file's mtime, as seconds since the Unix epoch.
+    pub source_mtime: u64,
+    pub output_path: PathBuf,
+    pub status: CheckpointStatus,
+}
+
+/// In-memory view of the checkpoint manifest, keyed by source path.
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+pub struct CheckpointManifest {
+    entries: HashMap<PathBuf, CheckpointEntry>,
+}
+
+impl CheckpointManifest {
+    /// Loads the checkpoint manifest from `path`, returning an empty manifest if it doesn't
+    /// exist yet or fails to parse. A missing or corrupt checkpoint shouldn't block a resumed
+    /// run; it just means every file is reprocessed and re-recorded from scratch.
+    pub fn load(path: &Path) -> Self {
+        fs::read_to_string(path)
+            .ok()
+            .and_then(|contents| serde_json::from_str(&contents).ok())
+            .unwrap_or_default()
+    }
+
+    /// Looks up the recorded entry for `source_path`, if any.
+    pub fn get(&self, source_path: &Path) -> Option<&CheckpointEntry> {
+        self.entries.get(source_path)
+    }
+
+    /// Records (or replaces) the entry for `entry.source_path`.
+    pub fn record(&mut self, entry: CheckpointEntry) {
+        self.entries.insert(entry.source_path.clone(), entry);
+    }
+
+    /// Persists the manifest to `path` as JSON.
+    pub fn save(&self, path: &Path) -> Result<(), Vex2PdfError> {
+        let json =
+            serde_json::to_string_pretty(self).map_err(|e| Vex2PdfError::Parse(e.to_string()))?;
+        fs_context::write(path, json)?;
+        Ok(())
+    }
+}
+
+/// Reads `path`'s mtime as seconds since the Unix epoch, falling back to `0` if it can't be
+/// read (e.g. the file just disappeared), so a skip-existing check degrades to "reprocess"
+/// instead of panicking.
+pub fn mtime_secs(path: &Path) -> u64 {
+    fs::metadata(path)
+        .and_then(|meta| meta.modified())
+        .map(|modified| {
+            modified
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs()
+        })
+        .unwrap_or(0)
+}
+
+/// Returns `true` if `output_path` already exists and is at least as new as `source_mtime`, in
+/// which case a `--resume` run can skip regenerating it.
+pub fn output_is_up_to_date(output_path: &Path, source_mtime: u64) -> bool {
+    output_path.exists() && mtime_secs(output_path) >= source_mtime
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checkpoint_manifest_round_trip() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let checkpoint_path = temp_dir.path().join(CHECKPOINT_FILENAME);
+
+        let mut manifest = CheckpointManifest::default();
+        manifest.record(CheckpointEntry {
+            source_path: PathBuf::from("bom.json"),
+            source_mtime: 1234,
+            output_path: PathBuf::from("bom.pdf"),
+            status: CheckpointStatus::Success,
+        });
+        manifest.save(&checkpoint_path).unwrap();
+
+        let reloaded = CheckpointManifest::load(&checkpoint_path);
+        let entry = reloaded.get(Path::new("bom.json")).unwrap();
+        assert_eq!(entry.source_mtime, 1234);
+        assert_eq!(entry.status, CheckpointStatus::Success);
+    }
+
+    #[test]
+    fn test_checkpoint_manifest_load_missing_file_is_empty() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let checkpoint_path = temp_dir.path().join(CHECKPOINT_FILENAME);
+
+        let manifest = CheckpointManifest::load(&checkpoint_path);
+        assert!(manifest.get(Path::new("anything")).is_none());
+    }
+
+    #[test]
+    fn test_output_is_up_to_date() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let source = temp_dir.path().join("bom.json");
+        let output = temp_dir.path().join("bom.pdf");
+
+        fs::write(&source, "{}").unwrap();
+        let source_mtime = mtime_secs(&source);
+
+        assert!(!output_is_up_to_date(&output, source_mtime));
+
+        fs::write(&output, "%PDF-1.7").unwrap();
+        assert!(output_is_up_to_date(&output, source_mtime));
+    }
+}
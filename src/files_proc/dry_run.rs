@@ -0,0 +1,129 @@
+//! Prints a concise per-file inspection report to stdout in place of PDF generation, when
+//! [`Config::dry_run`](crate::lib_utils::config::Config::dry_run) is set. See [`print_report`].
+//!
+//! Modeled on the rust compiler's `--print` query options: discovery and parsing still run (and
+//! still honor `--max-jobs`), but the expensive font-embedding render is skipped, so a directory
+//! of BOMs can be triaged quickly.
+
+use crate::files_proc::model::input_file_type::InputFileType;
+use crate::files_proc::run_summary::format_label;
+use crate::pdf::generator::PdfGenerator;
+use cyclonedx_bom::models::tool::Tools;
+use cyclonedx_bom::prelude::Bom;
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Severity bands in the same fixed display order used by
+/// [`PdfGenerator`](crate::pdf::generator::PdfGenerator)'s severity summary row.
+const SEVERITY_BANDS: [&str; 6] = ["Critical", "High", "Medium", "Low", "None", "Unknown"];
+
+/// Collects a display name for every tool/service CycloneDX lets a BOM declare as metadata,
+/// regardless of which of the two wire shapes (`Tools::List` vs. `Tools::Object`) it used.
+fn tool_names(tools: &Tools) -> Vec<String> {
+    match tools {
+        Tools::List(tools) => tools
+            .iter()
+            .filter_map(|tool| tool.name.as_ref())
+            .map(|name| name.to_string())
+            .collect(),
+        Tools::Object {
+            services,
+            components,
+        } => {
+            let mut names = Vec::new();
+            if let Some(components) = components {
+                names.extend(
+                    components
+                        .0
+                        .iter()
+                        .map(|component| component.name.to_string()),
+                );
+            }
+            if let Some(services) = services {
+                names.extend(services.0.iter().map(|service| service.name.to_string()));
+            }
+            names
+        }
+    }
+}
+
+/// Prints `input_path`'s resolved document type, spec version, tool metadata, component count,
+/// and severity breakdown to stdout, in place of generating a PDF for it.
+pub fn print_report(input_path: &Path, file_type: InputFileType, bom: &Bom) {
+    let vulns = bom
+        .vulnerabilities
+        .as_ref()
+        .map(|vulnerabilities| vulnerabilities.0.iter().collect::<Vec<_>>())
+        .unwrap_or_default();
+    let document_type = if vulns.is_empty() { "sbom" } else { "vex" };
+    let component_count = bom.components.as_ref().map_or(0, |c| c.0.len());
+    let tools = bom
+        .metadata
+        .as_ref()
+        .and_then(|metadata| metadata.tools.as_ref())
+        .map(tool_names)
+        .unwrap_or_default();
+
+    let mut severity_counts: HashMap<&'static str, usize> = HashMap::new();
+    for vuln in &vulns {
+        *severity_counts
+            .entry(PdfGenerator::severity_bucket(vuln))
+            .or_insert(0) += 1;
+    }
+
+    println!("{}", input_path.display());
+    println!("  format:          {}", format_label(file_type));
+    println!("  document type:   {document_type}");
+    println!("  spec version:    {}", bom.spec_version);
+    println!(
+        "  tools:           {}",
+        if tools.is_empty() {
+            "none".to_string()
+        } else {
+            tools.join(", ")
+        }
+    );
+    println!("  components:      {component_count}");
+
+    let breakdown: Vec<String> = SEVERITY_BANDS
+        .iter()
+        .filter_map(|band| {
+            severity_counts
+                .get(band)
+                .map(|count| format!("{band}: {count}"))
+        })
+        .collect();
+    println!(
+        "  vulnerabilities: {}",
+        if breakdown.is_empty() {
+            "none".to_string()
+        } else {
+            breakdown.join(", ")
+        }
+    );
+    println!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cyclonedx_bom::models::tool::Tool;
+    use cyclonedx_bom::prelude::{Bom, NormalizedString};
+
+    #[test]
+    fn test_tool_names_collects_list_variant() {
+        let tools = Tools::List(vec![Tool {
+            name: Some(NormalizedString::new("syft")),
+            ..Tool::default()
+        }]);
+
+        assert_eq!(tool_names(&tools), vec!["syft".to_string()]);
+    }
+
+    #[test]
+    fn test_print_report_runs_without_panicking_on_minimal_bom() {
+        let bom = Bom::default();
+
+        print_report(Path::new("bom.json"), InputFileType::JSON, &bom);
+    }
+}
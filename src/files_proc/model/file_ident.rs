@@ -1,5 +1,7 @@
 use crate::files_proc::model::input_file_type::InputFileType;
 use crate::lib_utils::errors::Vex2PdfError;
+use log::warn;
+use std::fs;
 use std::hash::Hash;
 use std::io;
 use std::path::Path;
@@ -15,6 +17,17 @@ impl<P: AsRef<Path> + Eq + Hash> BomFileIdentifier<P> {
     /// constructs a new identifier and checks for file existence
     ///  # Caveat
     ///  Any passed folder or unsupported extension will be parsed but set to UNSUPPORTED
+    ///
+    /// # Content sniffing
+    ///
+    /// Classification trusts a decisive extension outright, the same fast path
+    /// [`InputFileType::detect`] uses, so a tree of well-named files costs no extra reads. Only
+    /// when the extension is missing or [`InputFileType::UNSUPPORTED`] is the regular file's
+    /// content sniffed via [`InputFileType::detect_from_content`], so a correctly formatted BoM
+    /// with a missing or unrecognized extension is still picked up. A sniffed JSON/XML buffer
+    /// that doesn't also look like CycloneDX (per [`InputFileType::looks_like_cyclonedx`]) is
+    /// rejected as [`InputFileType::UNSUPPORTED`] with a warning, rather than handed to a parser
+    /// that will just fail on it later.
     pub fn build(path: P) -> Result<Self, Vex2PdfError> {
         if !path.as_ref().exists() {
             return Err(Vex2PdfError::Io(io::Error::new(
@@ -23,7 +36,29 @@ impl<P: AsRef<Path> + Eq + Hash> BomFileIdentifier<P> {
             )));
         }
 
-        let file_type = InputFileType::with_extension(path.as_ref().extension());
+        let extension_type = InputFileType::with_extension(path.as_ref().extension());
+
+        let file_type =
+            if extension_type != InputFileType::UNSUPPORTED || !path.as_ref().is_file() {
+                extension_type
+            } else {
+                match fs::read(path.as_ref()).ok().and_then(|content| {
+                    Some((InputFileType::detect_from_content(&content)?, content))
+                }) {
+                    Some((sniffed, content))
+                        if !InputFileType::looks_like_cyclonedx(&content, sniffed) =>
+                    {
+                        warn!(
+                            "`{}` looks like {} but not a CycloneDX document; skipping",
+                            path.as_ref().display(),
+                            sniffed.as_str_uppercase(),
+                        );
+                        InputFileType::UNSUPPORTED
+                    }
+                    Some((sniffed, _)) => sniffed,
+                    None => InputFileType::UNSUPPORTED,
+                }
+            };
 
         Ok(Self(path, file_type))
     }
@@ -85,6 +120,36 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_build_detects_content_over_wrong_extension() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let file = temp_dir.path().join("bom.txt");
+        std::fs::write(&file, r#"{"bomFormat":"CycloneDX"}"#).unwrap();
+
+        let file_ident = BomFileIdentifier::build(file).unwrap();
+        assert!(*file_ident.get_type() == InputFileType::JSON);
+    }
+
+    #[test]
+    fn test_build_rejects_json_that_is_not_cyclonedx() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let file = temp_dir.path().join("notes.txt");
+        std::fs::write(&file, r#"{"hello":"world"}"#).unwrap();
+
+        let file_ident = BomFileIdentifier::build(file).unwrap();
+        assert!(*file_ident.get_type() == InputFileType::UNSUPPORTED);
+    }
+
+    #[test]
+    fn test_build_falls_back_to_extension_when_content_is_unrecognized() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let file = temp_dir.path().join("bom.json");
+        std::fs::write(&file, "not actually json").unwrap();
+
+        let file_ident = BomFileIdentifier::build(file).unwrap();
+        assert!(*file_ident.get_type() == InputFileType::JSON);
+    }
+
     #[test]
     fn test_supported_file() {
         let supported_file_json = BomFileIdentifier::mock_new("/path/to/file.json");
@@ -3,7 +3,7 @@ use crate::files_proc::model::input_file_type::InputFileType;
 use crate::lib_utils::errors::Vex2PdfError;
 use std::collections::HashSet;
 use std::hash::Hash;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 //FIXME add documentation
 #[derive(Default)]
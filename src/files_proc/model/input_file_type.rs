@@ -1,11 +1,14 @@
 use std::ffi::OsStr;
+use std::fs;
+use std::path::Path;
 
 /// Represents the supported input file types for VEX document processing.
 ///
 /// This enum defines the file formats that can be processed by the vex2pdf application.
-/// Currently, two formats are supported:
+/// Currently, three formats are recognized:
 /// - XML: For VEX documents in XML format
 /// - JSON: For VEX documents in JSON format
+/// - PROTOBUF: For VEX documents in CycloneDX's binary Protocol Buffers encoding
 ///
 /// The enum implements methods to obtain string representations of the file type
 /// for various use cases like file extension matching, logging, or error messages.
@@ -23,12 +26,14 @@ use std::ffi::OsStr;
 /// assert_eq!(InputFileType::XML.as_str_uppercase(), "XML");
 /// assert_eq!(InputFileType::JSON.as_str_uppercase(), "JSON");
 /// ```
-#[derive(Eq, Hash, PartialEq)]
+#[derive(Eq, Hash, PartialEq, Clone, Copy, Debug)]
 pub enum InputFileType {
     /// Represents an XML format VEX document
     XML,
     /// Represents a JSON format VEX document
     JSON,
+    /// Represents a CycloneDX binary Protocol Buffers VEX document
+    PROTOBUF,
     /// Represents an unsupported file format
     UNSUPPORTED,
 }
@@ -66,6 +71,7 @@ impl InputFileType {
         match self {
             InputFileType::XML => "xml",
             InputFileType::JSON => "json",
+            InputFileType::PROTOBUF => "protobuf",
             InputFileType::UNSUPPORTED => panic!("Can not call this on an unsupported type"),
         }
     }
@@ -101,11 +107,15 @@ impl InputFileType {
         match self {
             InputFileType::XML => "XML",
             InputFileType::JSON => "JSON",
+            InputFileType::PROTOBUF => "PROTOBUF",
             InputFileType::UNSUPPORTED => panic!("not supposed to call this on unsupported types"),
         }
     }
 
     /// parses an extension Option<OsStr> and returns the corresponding object
+    ///
+    /// `.bin` and `.pb` (including the `.cdx.pb` convention, since [`Path::extension`] only ever
+    /// returns the last dot-segment) are recognized as [`Self::PROTOBUF`].
     pub fn with_extension(ext: Option<&OsStr>) -> Self {
         let ext = if let Some(os_str) = ext {
             os_str
@@ -115,9 +125,73 @@ impl InputFileType {
         match ext.to_string_lossy().to_ascii_lowercase().as_str() {
             "xml" => Self::XML,
             "json" => Self::JSON,
+            "bin" | "pb" => Self::PROTOBUF,
             _ => Self::UNSUPPORTED,
         }
     }
+
+    /// Sniffs a file's likely type from its raw bytes, independent of any extension.
+    ///
+    /// Skips a leading UTF-8/UTF-16 byte-order mark and any ASCII whitespace, then classifies
+    /// the first remaining byte: `{` or `[` as [`Self::JSON`], `<` as [`Self::XML`] (this also
+    /// covers documents starting with an `<?xml` prolog). Returns `None` when the buffer is
+    /// empty after skipping or starts with anything else, since that's not enough to tell.
+    pub fn detect_from_content(bytes: &[u8]) -> Option<Self> {
+        let mut slice = bytes;
+
+        if let Some(rest) = slice.strip_prefix(&[0xEF, 0xBB, 0xBF]) {
+            slice = rest; // UTF-8 BOM
+        } else if let Some(rest) = slice.strip_prefix(&[0xFF, 0xFE]) {
+            slice = rest; // UTF-16 LE BOM
+        } else if let Some(rest) = slice.strip_prefix(&[0xFE, 0xFF]) {
+            slice = rest; // UTF-16 BE BOM
+        }
+
+        match *slice.iter().find(|b| !b.is_ascii_whitespace())? {
+            b'{' | b'[' => Some(Self::JSON),
+            b'<' => Some(Self::XML),
+            _ => None,
+        }
+    }
+
+    /// Classifies `path`, trusting its extension when that's already decisive and only opening
+    /// the file to sniff its content (via [`Self::detect_from_content`]) as a fallback when the
+    /// extension is missing or [`Self::UNSUPPORTED`]. Scanning a tree of already well-named
+    /// files this way costs no more than [`Self::with_extension`] did on its own, since most
+    /// entries never need a read at all.
+    ///
+    /// A file that can't be read, or whose content doesn't look like anything recognized,
+    /// resolves to [`Self::UNSUPPORTED`] rather than propagating an error; the caller will get a
+    /// clearer one once it actually tries to open the file for parsing.
+    pub fn detect(path: &Path) -> Self {
+        let extension_type = Self::with_extension(path.extension());
+        if extension_type != Self::UNSUPPORTED {
+            return extension_type;
+        }
+
+        fs::read(path)
+            .ok()
+            .and_then(|content| Self::detect_from_content(&content))
+            .unwrap_or(Self::UNSUPPORTED)
+    }
+
+    /// Confirms that a buffer sniffed as `candidate` by [`Self::detect_from_content`] actually
+    /// looks like a CycloneDX document, rather than just any JSON/XML file that happens to start
+    /// with `{`/`[`/`<`.
+    ///
+    /// This is a cheap substring probe, not a schema check (see
+    /// [`crate::lib_utils::schema_validation`](crate::lib_utils::schema_validation) for that): JSON passes if it contains a
+    /// `bomFormat` or `specVersion` key, XML passes if it has a `<bom` root element. Any other
+    /// `candidate` (there currently are none besides JSON/XML from content sniffing) passes
+    /// through unconfirmed.
+    pub fn looks_like_cyclonedx(bytes: &[u8], candidate: Self) -> bool {
+        let text = String::from_utf8_lossy(bytes);
+        match candidate {
+            Self::JSON => text.contains("bomFormat") || text.contains("specVersion"),
+            Self::XML => text.contains("<bom"),
+            _ => true,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -161,4 +235,83 @@ mod tests {
         );
         assert!(InputFileType::with_extension(None) == InputFileType::UNSUPPORTED);
     }
+
+    #[test]
+    fn test_protobuf_extension_handling() {
+        let fake_bin = Path::new("/fictional/path/file.bin");
+        let fake_pb = Path::new("/fictional/path/file.pb");
+        let fake_cdx_pb = Path::new("/fictional/path/bom.cdx.pb");
+
+        assert!(InputFileType::with_extension(fake_bin.extension()) == InputFileType::PROTOBUF);
+        assert!(InputFileType::with_extension(fake_pb.extension()) == InputFileType::PROTOBUF);
+        assert!(InputFileType::with_extension(fake_cdx_pb.extension()) == InputFileType::PROTOBUF);
+    }
+
+    #[test]
+    fn test_detect_from_content() {
+        assert!(
+            InputFileType::detect_from_content(br#"{"bomFormat":"CycloneDX"}"#)
+                == Some(InputFileType::JSON)
+        );
+        assert!(InputFileType::detect_from_content(b"[1, 2, 3]") == Some(InputFileType::JSON));
+        assert!(
+            InputFileType::detect_from_content(b"<?xml version=\"1.0\"?><bom/>")
+                == Some(InputFileType::XML)
+        );
+        assert!(InputFileType::detect_from_content(b"  \n\t<bom/>") == Some(InputFileType::XML));
+        assert!(InputFileType::detect_from_content(b"not a bom at all") == None);
+        assert!(InputFileType::detect_from_content(b"") == None);
+
+        // leading BOM bytes are skipped before classification
+        let mut with_bom = vec![0xEF, 0xBB, 0xBF];
+        with_bom.extend_from_slice(b"{\"bomFormat\":\"CycloneDX\"}");
+        assert!(InputFileType::detect_from_content(&with_bom) == Some(InputFileType::JSON));
+    }
+
+    #[test]
+    fn test_looks_like_cyclonedx() {
+        assert!(InputFileType::looks_like_cyclonedx(
+            br#"{"bomFormat":"CycloneDX","specVersion":"1.5"}"#,
+            InputFileType::JSON
+        ));
+        assert!(!InputFileType::looks_like_cyclonedx(
+            br#"{"hello":"world"}"#,
+            InputFileType::JSON
+        ));
+        assert!(InputFileType::looks_like_cyclonedx(
+            b"<?xml version=\"1.0\"?><bom xmlns=\"http://cyclonedx.org/schema/bom/1.5\"/>",
+            InputFileType::XML
+        ));
+        assert!(!InputFileType::looks_like_cyclonedx(
+            b"<?xml version=\"1.0\"?><rss></rss>",
+            InputFileType::XML
+        ));
+    }
+
+    #[test]
+    fn test_detect_trusts_a_decisive_extension_without_reading_the_file() {
+        // the path doesn't exist on disk at all; a decisive extension must short-circuit
+        // before any attempt to read it
+        let path = Path::new("/does/not/exist/bom.json");
+
+        assert_eq!(InputFileType::detect(path), InputFileType::JSON);
+    }
+
+    #[test]
+    fn test_detect_falls_back_to_content_when_extension_is_unsupported() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let file = temp_dir.path().join("sbom");
+        std::fs::write(&file, r#"{"bomFormat":"CycloneDX"}"#).unwrap();
+
+        assert_eq!(InputFileType::detect(&file), InputFileType::JSON);
+    }
+
+    #[test]
+    fn test_detect_stays_unsupported_when_content_is_unrecognized() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let file = temp_dir.path().join("notes");
+        std::fs::write(&file, "just some notes").unwrap();
+
+        assert_eq!(InputFileType::detect(&file), InputFileType::UNSUPPORTED);
+    }
 }
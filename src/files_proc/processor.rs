@@ -1,45 +1,256 @@
+use crate::files_proc::adapter;
+use crate::files_proc::checkpoint::{self, CheckpointEntry, CheckpointManifest, CheckpointStatus};
+use crate::files_proc::dry_run;
 use crate::files_proc::model::file_ident::BomFileIdentifier;
 use crate::files_proc::model::files_pending_proc::FilesPendingProc;
 use crate::files_proc::model::input_file_type::InputFileType;
+use crate::files_proc::run_summary::{RunSummary, RunSummaryEntry};
+use crate::files_proc::severity_gate;
+use crate::files_proc::summary::ProcessSummary;
 use crate::files_proc::traits::{
     FileSearchProvider, MultipleFilesProcProvider, SingleFileProcProvider,
 };
-use crate::lib_utils::config::Config;
+use crate::lib_utils::cancel::CancelFlag;
+use crate::lib_utils::concurrency::common::panic_message;
+#[cfg(feature = "concurrency")]
+use crate::lib_utils::concurrency::threadpool::ThreadPool;
+use crate::lib_utils::config::{Config, OutputFormat};
 use crate::lib_utils::errors::Vex2PdfError;
 use crate::pdf::generator::PdfGenerator;
-use crate::utils::{get_output_pdf_path, parse_vex_json, parse_vex_xml};
-#[cfg(feature = "concurrency")]
-use jlizard_simple_threadpool::threadpool::ThreadPool;
+use crate::pdf::manifest;
+use crate::pdf::merge::{self, MergeSource};
+use crate::utils::{apply_output_permissions, get_output_pdf_path};
+use globset::{Glob, GlobSet, GlobSetBuilder};
 use log::{error, info, warn};
-use std::collections::HashSet;
+use regex::Regex;
+use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::hash::Hash;
+use std::panic::{self, AssertUnwindSafe};
 use std::path::{Path, PathBuf};
-use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
+use std::time::Instant;
 
-/// The default processor implementation for this crate
-#[derive(Default)]
-pub(crate) struct DefaultFilesProcessor {
-    config: Config,
+/// Converts a boxed parsing error from [`parse_vex_json`]/[`parse_vex_xml`] into a
+/// [`Vex2PdfError`], preserving [`Vex2PdfError::SchemaInvalid`] as a distinct variant rather
+/// than flattening it into [`Vex2PdfError::Parse`] like other conversion failures. Keeping the
+/// two apart here is what lets a future outcome-aggregation pass report (and exit with a
+/// different code for) schema-invalid input versus a document that simply failed to parse.
+fn as_parse_error(err: Box<dyn std::error::Error>) -> Vex2PdfError {
+    match err.downcast::<Vex2PdfError>() {
+        Ok(boxed) => match *boxed {
+            schema_err @ Vex2PdfError::SchemaInvalid(_) => schema_err,
+            other => Vex2PdfError::Parse(other.to_string()),
+        },
+        Err(err) => Vex2PdfError::Parse(err.to_string()),
+    }
 }
 
-impl DefaultFilesProcessor {
-    pub(crate) fn new(config: Config) -> Self {
-        Self { config }
+/// Compiles a list of glob pattern strings into a [`GlobSet`], skipping (and warning about)
+/// any pattern that fails to parse rather than aborting discovery entirely.
+fn build_glob_set(patterns: &[String]) -> GlobSet {
+    let mut builder = GlobSetBuilder::new();
+
+    for pattern in patterns {
+        match Glob::new(pattern) {
+            Ok(glob) => {
+                builder.add(glob);
+            }
+            Err(e) => warn!("Ignoring invalid glob pattern `{pattern}`: {e}"),
+        }
     }
+
+    builder.build().unwrap_or_else(|_| {
+        GlobSetBuilder::new()
+            .build()
+            .expect("empty globset is always valid")
+    })
 }
 
-impl FileSearchProvider for DefaultFilesProcessor {
-    type OkType = ProcessorReady<PathBuf>;
-    type ErrType = Vex2PdfError;
-    fn find_files(self) -> Result<Self::OkType, Self::ErrType> {
-        // process map ignored pattern map
-        let ignored_patterns_map = self.config.file_types_to_process.as_ref();
+/// Extracts the literal directory prefix preceding the first glob meta-character (`*`, `?`,
+/// `[`, `{`) in `pattern`, so [`include_base_dirs`] can walk just that subtree of
+/// `working_path` instead of the whole tree. A pattern with no meta-characters, or no
+/// directory component before one, degrades to `working_path` itself.
+fn include_base_dir(working_path: &Path, pattern: &str) -> PathBuf {
+    let literal_prefix = match pattern.find(['*', '?', '[', '{']) {
+        Some(pos) => &pattern[..pos],
+        None => pattern,
+    };
+    match literal_prefix.rsplit_once('/') {
+        Some((dir, _)) if !dir.is_empty() => working_path.join(dir),
+        _ => working_path.to_path_buf(),
+    }
+}
+
+/// Computes the minimal set of base directories [`spawn_discovery`] needs to walk to cover
+/// every configured include pattern, deliberately skipping [`GlobSet`] expansion (which would
+/// mean enumerating every matching path up front) in favor of a cheap prefix split per pattern.
+/// A base directory already covered by another (a prefix of it) is dropped, and an empty
+/// pattern list degrades to walking `working_path` itself.
+fn include_base_dirs(working_path: &Path, include_patterns: &[String]) -> Vec<PathBuf> {
+    if include_patterns.is_empty() {
+        return vec![working_path.to_path_buf()];
+    }
+
+    let mut dirs: Vec<PathBuf> = include_patterns
+        .iter()
+        .map(|pattern| include_base_dir(working_path, pattern))
+        .collect();
+    dirs.sort();
+    dirs.dedup();
+
+    let candidates = dirs.clone();
+    dirs.retain(|dir| {
+        !candidates
+            .iter()
+            .any(|other| other != dir && dir.starts_with(other))
+    });
+    dirs
+}
+
+/// Compiles an optional exclude-name regex, warning and falling back to "no filter" rather than
+/// aborting discovery if the pattern fails to parse.
+fn build_exclude_name_regex(pattern: Option<&str>) -> Option<Regex> {
+    pattern.and_then(|pattern| match Regex::new(pattern) {
+        Ok(re) => Some(re),
+        Err(e) => {
+            warn!("Ignoring invalid exclude-name regex `{pattern}`: {e}");
+            None
+        }
+    })
+}
+
+/// Returns `true` if `rel_path` should be processed given the configured include/exclude glob
+/// sets and the optional exclude-name regex: it must match `includes` (or `includes` must be
+/// empty), and must not match `excludes` or `exclude_name_regex`. Unlike the glob sets, which
+/// are matched against the full path relative to `working_path`, `exclude_name_regex` is
+/// matched against just the file name, since that's how `fd`-style name filters are usually
+/// expressed.
+fn passes_name_filters(
+    rel_path: &Path,
+    includes: &GlobSet,
+    excludes: &GlobSet,
+    exclude_name_regex: Option<&Regex>,
+) -> bool {
+    let included = includes.is_empty() || includes.is_match(rel_path);
+    let excluded = excludes.is_match(rel_path);
+    let excluded_by_name = exclude_name_regex.is_some_and(|re| {
+        rel_path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| re.is_match(name))
+    });
+
+    included && !excluded && !excluded_by_name
+}
+
+/// The custom ignore-file name `--respect-ignore-files` also honors, alongside the `ignore`
+/// crate's built-in `.gitignore`/`.ignore` support.
+const CUSTOM_IGNORE_FILENAME: &str = ".vex2pdfignore";
+
+/// Walks every regular file under `dir`, descending into subdirectories via the `ignore`
+/// crate's [`ignore::WalkBuilder`], so `.gitignore`/`.ignore`/[`CUSTOM_IGNORE_FILENAME`] files
+/// encountered along the way are honored the same way `git`/`rg` would. Unreadable entries are
+/// logged and skipped rather than aborting the whole walk. Each accepted file is handed to
+/// `visit` as soon as it's found rather than being collected first, so a caller can stream
+/// results (e.g. [`spawn_discovery`]) instead of waiting for the whole tree to be walked.
+///
+/// A directory whose path relative to `exclude_root` matches `exclude_globs` is pruned the
+/// moment it's reached: `ignore::WalkBuilder` never descends into it, so an excluded subtree
+/// (say, `**/node_modules`) costs nothing beyond the single directory entry, rather than
+/// being walked in full and then discarded file by file.
+///
+/// Checked before visiting each entry, `cancel` lets a Ctrl-C abandon a still-running walk of a
+/// large tree instead of discovering files nobody will process anymore.
+fn walk_files(
+    dir: &Path,
+    max_depth: Option<usize>,
+    follow_symlinks: bool,
+    respect_ignore_files: bool,
+    include_hidden: bool,
+    exclude_root: &Path,
+    exclude_globs: &GlobSet,
+    cancel: &CancelFlag,
+    mut visit: impl FnMut(PathBuf),
+) {
+    let mut builder = ignore::WalkBuilder::new(dir);
+    builder
+        .max_depth(max_depth)
+        .follow_links(follow_symlinks)
+        .hidden(!include_hidden)
+        .parents(respect_ignore_files)
+        .ignore(respect_ignore_files)
+        .git_ignore(respect_ignore_files)
+        .git_global(respect_ignore_files)
+        .git_exclude(respect_ignore_files)
+        .add_custom_ignore_filename(CUSTOM_IGNORE_FILENAME);
+
+    let exclude_root = exclude_root.to_path_buf();
+    let exclude_globs = exclude_globs.clone();
+    builder.filter_entry(move |entry| {
+        if entry.depth() == 0 || !entry.file_type().is_some_and(|ft| ft.is_dir()) {
+            return true;
+        }
+        let rel_path = entry
+            .path()
+            .strip_prefix(&exclude_root)
+            .unwrap_or(entry.path());
+        !exclude_globs.is_match(rel_path)
+    });
 
-        // get the working path can be a file or folder
-        let working_path = &self.config.working_path;
+    for entry in builder.build() {
+        if cancel.is_cancelled() {
+            info!(
+                "Cancellation requested: stopping directory walk of {}",
+                dir.display()
+            );
+            break;
+        }
+
+        match entry {
+            Ok(entry) if entry.file_type().is_some_and(|ft| ft.is_file()) => {
+                visit(entry.into_path());
+            }
+            Ok(_) => {} // directories and other non-file entries are just descended into
+            Err(e) => warn!("Skipping an entry while walking {}: {e}", dir.display()),
+        }
+    }
+}
+
+/// Bounds how far the discovery thread spawned by [`spawn_discovery`] may run ahead of the
+/// consumer in [`ProcessorReady::process`]. This caps how many discovered files can sit in
+/// memory waiting to be processed, giving the producer/consumer pipeline natural backpressure
+/// on very large trees instead of buffering every path before the first job is dispatched.
+const DISCOVERY_CHANNEL_BOUND: usize = 256;
+
+/// Spawns a background thread that walks (or, for a non-recursive scan, lists)
+/// `config.working_path`, applies the configured include/exclude name filters and ignored file
+/// types, and streams every accepted file into the returned bounded channel as soon as it's
+/// found. [`ProcessorReady::process`] drains this channel and dispatches each file to the
+/// thread pool as it arrives, so PDF generation for the first files overlaps with discovery of
+/// the rest of the tree instead of waiting for the whole walk to finish first.
+///
+/// A recursive scan only walks the base directories [`include_base_dirs`] derives from
+/// `config.include_patterns` rather than all of `config.working_path`, and prunes any subtree
+/// matching `config.exclude_patterns` as soon as it's reached (see [`walk_files`]) instead of
+/// expanding the exclude globs into a concrete path list first.
+///
+/// `cancel` is checked between entries, so a Ctrl-C stops the walk from finding (and therefore
+/// enqueuing) any more files, the same way it stops [`ProcessorReady::process`]'s dispatch loop.
+fn spawn_discovery(
+    config: Arc<Config>,
+    bound: usize,
+    cancel: CancelFlag,
+) -> (
+    mpsc::Receiver<BomFileIdentifier<PathBuf>>,
+    thread::JoinHandle<()>,
+) {
+    let (sender, receiver) = mpsc::sync_channel(bound);
 
-        // build ignored_patterns
+    let handle = thread::spawn(move || {
+        let ignored_patterns_map = config.file_types_to_process.as_ref();
         let ignored_patterns: HashSet<&InputFileType> =
             if let Some(ignore_map) = ignored_patterns_map {
                 ignore_map
@@ -60,48 +271,192 @@ impl FileSearchProvider for DefaultFilesProcessor {
                 HashSet::new()
             };
 
-        let mut ret = FilesPendingProc::new();
+        let include_globs = build_glob_set(&config.include_patterns);
+        let exclude_globs = build_glob_set(&config.exclude_patterns);
+        let exclude_name_regex = build_exclude_name_regex(config.exclude_name_regex.as_deref());
+        let working_path = config.working_path.as_path();
 
-        if working_path.is_file() {
-            ret.add_sup_file_ignore(working_path.to_path_buf(), &ignored_patterns)?;
-        } else {
-            // is a folder
-            info!(
-                "Scanning for BoM/Vex Files in {}",
-                &self.config.working_path.display()
-            );
+        let mut visit = |path: PathBuf| {
+            let rel_path = path.strip_prefix(working_path).unwrap_or(&path);
+            if !passes_name_filters(
+                rel_path,
+                &include_globs,
+                &exclude_globs,
+                exclude_name_regex.as_ref(),
+            ) {
+                info!(
+                    "Skipping {}: excluded by --include/--exclude filters",
+                    path.display()
+                );
+                return;
+            }
+
+            match BomFileIdentifier::build(path.clone()) {
+                Ok(file_ident) if !file_ident.is_supported_type() => {
+                    error!("{}", Vex2PdfError::UnsupportedFileType);
+                }
+                Ok(file_ident) if ignored_patterns.contains(file_ident.get_type()) => {
+                    info!("{}", Vex2PdfError::IgnoredByUser);
+                }
+                Ok(file_ident) => {
+                    // if the consumer already went away there is nothing left to send to
+                    let _ = sender.send(file_ident);
+                }
+                Err(e) => error!("{e}"),
+            }
+        };
 
-            for entry in fs::read_dir(&self.config.working_path)? {
-                let path = entry?.path();
+        if config.recursive {
+            for base_dir in include_base_dirs(working_path, &config.include_patterns) {
+                if cancel.is_cancelled() {
+                    break;
+                }
 
-                if path.is_file() {
-                    if let Err(e) = ret.add_sup_file_ignore(path.to_path_buf(), &ignored_patterns) {
-                        match e {
-                            Vex2PdfError::IgnoredByUser => info!("{e}"),
-                            _ => error!("{e}"),
-                        };
+                walk_files(
+                    &base_dir,
+                    config.max_depth,
+                    config.follow_symlinks,
+                    config.respect_ignore_files,
+                    config.include_hidden,
+                    working_path,
+                    &exclude_globs,
+                    &cancel,
+                    &mut visit,
+                );
+            }
+        } else {
+            match fs::read_dir(working_path) {
+                Ok(entries) => {
+                    for entry in entries {
+                        if cancel.is_cancelled() {
+                            info!(
+                                "Cancellation requested: stopping directory listing of {}",
+                                working_path.display()
+                            );
+                            break;
+                        }
+
+                        match entry {
+                            Ok(entry) => {
+                                let path = entry.path();
+                                if path.is_file() {
+                                    visit(path);
+                                }
+                            }
+                            Err(e) => warn!(
+                                "Skipping an entry while reading {}: {e}",
+                                working_path.display()
+                            ),
+                        }
                     }
                 }
+                Err(e) => warn!("Failed to read directory {}: {e}", working_path.display()),
             }
         }
+    });
 
-        // inform over search results
-        if ret.get_files_ref().is_empty() {
-            info!("No parseable files in selected path");
-        } else {
-            info!(
-                "Found {} JSON files",
-                ret.get_file_count_by_type(InputFileType::JSON)
-            );
-            info!(
-                "Found {} XML files",
-                ret.get_file_count_by_type(InputFileType::XML)
-            );
+    (receiver, handle)
+}
+
+/// The default processor implementation for this crate
+#[derive(Default)]
+pub(crate) struct DefaultFilesProcessor {
+    config: Config,
+    cancel: CancelFlag,
+}
+
+impl DefaultFilesProcessor {
+    pub(crate) fn new(config: Config, cancel: CancelFlag) -> Self {
+        Self { config, cancel }
+    }
+}
+
+impl FileSearchProvider for DefaultFilesProcessor {
+    type OkType = ProcessorReady<PathBuf>;
+    type ErrType = Vex2PdfError;
+    fn find_files(self) -> Result<Self::OkType, Self::ErrType> {
+        let working_path = self.config.working_path.clone();
+
+        if working_path.is_file() {
+            // A single explicitly-named file needs no discovery pipeline: check it right here
+            // so an unsupported or user-ignored argument is still reported as a hard error from
+            // `find_files`, same as before the folder-scanning path became a streaming pipeline.
+            let ignored_patterns_map = self.config.file_types_to_process.as_ref();
+            let ignored_patterns: HashSet<&InputFileType> =
+                if let Some(ignore_map) = ignored_patterns_map {
+                    ignore_map
+                        .iter()
+                        .filter_map(|(k, v)| {
+                            if !(*v) {
+                                info!(
+                                    "Skipping {} files : deactivated by user",
+                                    k.as_str_uppercase()
+                                );
+                                Some(k)
+                            } else {
+                                None
+                            }
+                        })
+                        .collect()
+                } else {
+                    HashSet::new()
+                };
+
+            let include_globs = build_glob_set(&self.config.include_patterns);
+            let exclude_globs = build_glob_set(&self.config.exclude_patterns);
+            let exclude_name_regex =
+                build_exclude_name_regex(self.config.exclude_name_regex.as_deref());
+
+            let mut ret = FilesPendingProc::new();
+            let rel_path = working_path
+                .file_name()
+                .map(Path::new)
+                .unwrap_or(&working_path);
+            if passes_name_filters(
+                rel_path,
+                &include_globs,
+                &exclude_globs,
+                exclude_name_regex.as_ref(),
+            ) {
+                ret.add_sup_file_ignore(working_path.clone(), &ignored_patterns)?;
+            } else {
+                info!(
+                    "Skipping {}: excluded by --include/--exclude filters",
+                    working_path.display()
+                );
+            }
+
+            return Ok(ProcessorReady {
+                config: Arc::new(self.config),
+                cancel: self.cancel,
+                pending: PendingFiles::Collected(ret),
+            });
         }
 
+        // is a folder: hand the walk off to a background thread so PDF generation in
+        // `ProcessorReady::process` can start as soon as the first files are found instead of
+        // waiting for the whole tree to be discovered first
+        info!(
+            "Scanning for BoM/Vex Files in {}{}",
+            working_path.display(),
+            if self.config.recursive {
+                " (recursive)"
+            } else {
+                ""
+            }
+        );
+
+        let config = Arc::new(self.config);
+        let (receiver, producer) = spawn_discovery(
+            Arc::clone(&config),
+            DISCOVERY_CHANNEL_BOUND,
+            self.cancel.clone(),
+        );
+
         Ok(ProcessorReady {
-            config: Arc::new(self.config),
-            files: ret,
+            config,
+            cancel: self.cancel,
+            pending: PendingFiles::Streamed { receiver, producer },
         })
     }
 }
@@ -116,97 +471,725 @@ impl<P: AsRef<Path> + Eq + Hash + Send + 'static> SingleFileProcProvider<P>
         &self,
         file: BomFileIdentifier<P>,
         config: Arc<Config>,
-    ) -> Result<(), Vex2PdfError> {
+        checkpoint: Option<Arc<Mutex<CheckpointManifest>>>,
+        summary: Option<Arc<Mutex<RunSummary>>>,
+        gate_counts: Option<Arc<Mutex<HashMap<String, usize>>>>,
+        cancel: CancelFlag,
+    ) -> Result<PathBuf, Vex2PdfError> {
         info!("Processing {}", file.get_path().as_ref().display());
+        let started_at = Instant::now();
+        let file_type = *file.get_type();
+
+        // HTML rendering shares the same BoM model but has no implemented renderer yet
+        if config.output_format != OutputFormat::Pdf {
+            return Err(Vex2PdfError::UnsupportedOutputFormat(
+                config.output_format.as_str().to_string(),
+            ));
+        }
+
+        // Generate output PDF path with same base name
+        let mirror_root = config
+            .mirror_output_structure
+            .then_some(config.working_path.as_path());
+        let output_path = get_output_pdf_path(
+            Some(config.output_dir.as_path()),
+            file.get_path().as_ref(),
+            mirror_root,
+        )?;
+        let source_path = file.get_path().as_ref().to_path_buf();
+        let source_mtime = checkpoint::mtime_secs(&source_path);
+
+        if config.resume && checkpoint::output_is_up_to_date(&output_path, source_mtime) {
+            info!(
+                "Skipping {}: output {} is already up to date (--resume)",
+                source_path.display(),
+                output_path.display()
+            );
+            return Ok(output_path);
+        }
+
+        if cancel.is_cancelled() {
+            info!(
+                "Cancellation requested: abandoning {} before parsing",
+                source_path.display()
+            );
+            return Err(Vex2PdfError::Cancelled);
+        }
 
         // Get BoM Object
-        let bom =
-            match file.get_type() {
-                InputFileType::XML => parse_vex_xml(file.get_path())
-                    .map_err(|e| Vex2PdfError::Parse(e.to_string()))?,
-                InputFileType::JSON => parse_vex_json(file.get_path())
-                    .map_err(|e| Vex2PdfError::Parse(e.to_string()))?,
-                InputFileType::UNSUPPORTED => return Err(Vex2PdfError::UnsupportedFileType),
-            };
+        let bom = match adapter::adapter_for(file_type) {
+            Some(adapter) => (adapter.parse)(file.get_path().as_ref(), config.validate_schema)
+                .map_err(as_parse_error),
+            None => Err(Vex2PdfError::UnsupportedFileType),
+        };
+        let bom = match bom {
+            Ok(bom) => bom,
+            Err(e) => {
+                if let Some(summary) = &summary {
+                    summary
+                        .lock()
+                        .expect("run summary mutex poisoned")
+                        .entries
+                        .push(RunSummaryEntry::failure(
+                            source_path,
+                            file_type,
+                            &e,
+                            started_at.elapsed(),
+                        ));
+                }
+                return Err(e);
+            }
+        };
+
+        if let Some(gate_counts) = &gate_counts {
+            severity_gate::accumulate(
+                &mut gate_counts.lock().expect("severity gate mutex poisoned"),
+                &bom,
+                config.gate_count_analyzed,
+            );
+        }
+
+        // `--dry-run` skips PDF generation (and the checkpoint/manifest bookkeeping that assumes
+        // a real output file was written) in favor of printing an inspection report; the returned
+        // path is the source file itself, standing in only for `ProcessSummary`'s success count.
+        if config.dry_run {
+            dry_run::print_report(&source_path, file_type, &bom);
+            if let Some(summary) = &summary {
+                summary
+                    .lock()
+                    .expect("run summary mutex poisoned")
+                    .entries
+                    .push(RunSummaryEntry::success(
+                        source_path.clone(),
+                        file_type,
+                        &bom,
+                        PathBuf::new(),
+                        started_at.elapsed(),
+                    ));
+            }
+            return Ok(source_path);
+        }
 
         // Generate output PDF path with same base name
         let generator = PdfGenerator::new(Arc::clone(&config));
 
         info!("Generating PDF:  {}", file.get_path().as_ref().display());
 
-        // FIXME consider if output path is ever handled here
-        // Generate the PDF
-        let output_path =
-            get_output_pdf_path(Some(config.output_dir.as_path()), file.get_path().as_ref())?;
-        match generator.generate_pdf(&bom, &output_path) {
-            Ok(_) => info!("Successfully generated PDF: {}", output_path.display()),
+        if cancel.is_cancelled() {
+            info!(
+                "Cancellation requested: abandoning {} before PDF generation",
+                source_path.display()
+            );
+            return Err(Vex2PdfError::Cancelled);
+        }
+
+        if let Some(parent) = output_path.parent() {
+            if let Err(e) = fs::create_dir_all(parent) {
+                warn!(
+                    "Failed to create output directory {}: {}",
+                    parent.display(),
+                    e
+                );
+            }
+        }
+        let result = match generator.generate_pdf(&bom, &output_path) {
+            Ok(_) => {
+                info!("Successfully generated PDF: {}", output_path.display());
+                if let Err(e) = apply_output_permissions(
+                    &output_path,
+                    config.file_mode,
+                    config.owner.as_deref(),
+                    config.group.as_deref(),
+                ) {
+                    warn!(
+                        "Failed to apply file permissions to {}: {}",
+                        output_path.display(),
+                        e
+                    )
+                }
+                Ok(output_path.clone())
+            }
             Err(e) => {
                 warn!(
                     "Failed to generate PDF for {}: {}",
                     output_path.display(),
                     e
-                )
+                );
+                Err(e.into())
+            }
+        };
+
+        if let Some(summary) = &summary {
+            let elapsed = started_at.elapsed();
+            let entry = match &result {
+                Ok(output_path) => RunSummaryEntry::success(
+                    source_path.clone(),
+                    file_type,
+                    &bom,
+                    output_path.clone(),
+                    elapsed,
+                ),
+                Err(e) => RunSummaryEntry::failure(source_path.clone(), file_type, e, elapsed),
+            };
+            summary
+                .lock()
+                .expect("run summary mutex poisoned")
+                .entries
+                .push(entry);
+        }
+
+        if let Some(checkpoint) = checkpoint {
+            let entry = CheckpointEntry {
+                source_path,
+                source_mtime,
+                output_path: output_path.clone(),
+                status: if result.is_ok() {
+                    CheckpointStatus::Success
+                } else {
+                    CheckpointStatus::Failed
+                },
+            };
+            let checkpoint_path = config.output_dir.join(checkpoint::CHECKPOINT_FILENAME);
+            let mut manifest = checkpoint
+                .lock()
+                .expect("checkpoint manifest mutex poisoned");
+            manifest.record(entry);
+            if let Err(e) = manifest.save(&checkpoint_path) {
+                warn!("Failed to write checkpoint manifest: {e}");
             }
         }
 
-        Ok(())
+        result
     }
 }
+/// Where [`ProcessorReady`] gets the files it hands to [`SingleFileProcProvider`].
+///
+/// A single explicitly-named file is cheap enough to resolve eagerly in `find_files`, so it
+/// stays a plain [`FilesPendingProc`]. A folder scan instead streams in from the background
+/// walker spawned by [`spawn_discovery`]: `process` dispatches each file to the thread pool as
+/// it arrives over the channel, rather than waiting for the whole tree to be discovered first.
+pub(crate) enum PendingFiles<P: AsRef<Path> + Eq + Hash> {
+    Collected(FilesPendingProc<P>),
+    Streamed {
+        receiver: mpsc::Receiver<BomFileIdentifier<P>>,
+        producer: thread::JoinHandle<()>,
+    },
+}
+
 pub(crate) struct ProcessorReady<P: AsRef<Path> + Eq + Hash> {
     config: Arc<Config>,
-    pub(super) files: FilesPendingProc<P>,
+    cancel: CancelFlag,
+    pub(super) pending: PendingFiles<P>,
+}
+
+/// Name of the single consolidated PDF [`ProcessorReady::process_merged`] writes under
+/// [`Config::output_dir`].
+const MERGED_OUTPUT_FILENAME: &str = "merged.pdf";
+
+/// Drains `pending` into a flat list of discovered files, blocking on the discovery thread (if
+/// any) the same way [`ProcessorReady::process`]'s normal dispatch loop does. Used by
+/// [`ProcessorReady::process_merged`], which (unlike the normal per-file pipeline) needs every
+/// source parsed before it can build the single merged document.
+fn collect_pending<P: AsRef<Path> + Eq + Hash>(
+    pending: PendingFiles<P>,
+    cancel: &CancelFlag,
+) -> Vec<BomFileIdentifier<P>> {
+    match pending {
+        PendingFiles::Collected(files) => files.into_iter().collect(),
+        PendingFiles::Streamed { receiver, producer } => {
+            let mut collected = Vec::new();
+            for file in receiver.iter() {
+                if cancel.is_cancelled() {
+                    break;
+                }
+                collected.push(file);
+            }
+            drop(receiver);
+            producer
+                .join()
+                .expect("discovery thread panicked while walking for files");
+            collected
+        }
+    }
+}
+
+impl<P: AsRef<Path> + Eq + Hash + Send + 'static> ProcessorReady<P> {
+    /// Backs `--merge`: parses every discovered file into a `Bom`, folds them into one
+    /// consolidated document via [`crate::pdf::merge::merge_sources`], and renders a single PDF
+    /// at [`MERGED_OUTPUT_FILENAME`] instead of one-PDF-per-file. A file that fails to parse is
+    /// recorded in `failures` and excluded from the merge rather than aborting the whole run.
+    fn process_merged(self) -> Result<ProcessSummary, Vex2PdfError> {
+        let config = self.config;
+        let files = collect_pending(self.pending, &self.cancel);
+        let total = files.len();
+
+        let mut sources = Vec::with_capacity(files.len());
+        let mut failures = Vec::new();
+        // Entries for files that parsed and are going into the merge; `output_path` is filled in
+        // once the merged PDF's final fate (written, or failed) is known below.
+        let mut summary_entries = Vec::new();
+
+        for file in &files {
+            let started_at = Instant::now();
+            let source_path = file.get_path().as_ref().to_path_buf();
+            let file_type = *file.get_type();
+            let bom = match adapter::adapter_for(file_type) {
+                Some(adapter) => (adapter.parse)(file.get_path().as_ref(), config.validate_schema)
+                    .map_err(as_parse_error),
+                None => Err(Vex2PdfError::UnsupportedFileType),
+            };
+
+            match bom {
+                Ok(bom) => {
+                    if config.summary_json.is_some() {
+                        summary_entries.push(RunSummaryEntry::success(
+                            source_path.clone(),
+                            file_type,
+                            &bom,
+                            PathBuf::new(),
+                            started_at.elapsed(),
+                        ));
+                    }
+                    sources.push(MergeSource {
+                        filename: source_path.display().to_string(),
+                        bom,
+                    });
+                }
+                Err(e) => {
+                    if config.summary_json.is_some() {
+                        summary_entries.push(RunSummaryEntry::failure(
+                            source_path.clone(),
+                            file_type,
+                            &e,
+                            started_at.elapsed(),
+                        ));
+                    }
+                    error!("{e}");
+                    failures.push((source_path, e));
+                }
+            }
+        }
+
+        let succeeded_count = sources.len();
+        let failed_count = failures.len();
+
+        if sources.is_empty() {
+            info!("No file could be parsed: skipping merged PDF generation");
+            if let Some(summary_path) = config.summary_json.as_deref() {
+                let summary = RunSummary {
+                    entries: summary_entries,
+                };
+                if let Err(e) = summary.write(summary_path, config.report_format) {
+                    warn!("Failed to write run summary: {e}");
+                }
+            }
+            return Ok(ProcessSummary {
+                total,
+                succeeded: 0,
+                failed: failed_count,
+                skipped: 0,
+                failures,
+            });
+        }
+
+        let merged = merge::merge_sources(sources);
+        let output_path = config.output_dir.join(MERGED_OUTPUT_FILENAME);
+
+        if let Err(e) = fs::create_dir_all(&config.output_dir) {
+            warn!(
+                "Failed to create output directory {}: {}",
+                config.output_dir.display(),
+                e
+            );
+        }
+
+        let generator = PdfGenerator::new(Arc::clone(&config));
+        match generator.generate_merged_pdf(&merged, &output_path) {
+            Ok(_) => {
+                info!(
+                    "Successfully generated merged PDF: {}",
+                    output_path.display()
+                );
+                if let Err(e) = apply_output_permissions(
+                    &output_path,
+                    config.file_mode,
+                    config.owner.as_deref(),
+                    config.group.as_deref(),
+                ) {
+                    warn!(
+                        "Failed to apply file permissions to {}: {}",
+                        output_path.display(),
+                        e
+                    )
+                }
+
+                if let Some(manifest_path) = config.manifest_path.as_deref() {
+                    let checksum = manifest::compute_pdf_checksum(&output_path)?;
+                    manifest::write_manifest(
+                        &[manifest::ManifestEntry {
+                            path: output_path.clone(),
+                            checksum,
+                        }],
+                        manifest_path,
+                    )?;
+                }
+
+                if let Some(summary_path) = config.summary_json.as_deref() {
+                    for entry in &mut summary_entries {
+                        entry.output_path = Some(output_path.clone());
+                    }
+                    let summary = RunSummary {
+                        entries: summary_entries,
+                    };
+                    if let Err(e) = summary.write(summary_path, config.report_format) {
+                        warn!("Failed to write run summary: {e}");
+                    }
+                }
+
+                // Evaluated last, after the merged PDF has already been generated, so the gate
+                // never withholds a report that's otherwise ready to ship as a CI artifact.
+                if !config.max_allowed.is_empty() {
+                    let mut gate_counts = HashMap::new();
+                    severity_gate::accumulate(
+                        &mut gate_counts,
+                        &merged,
+                        config.gate_count_analyzed,
+                    );
+                    severity_gate::check(&gate_counts, &config.max_allowed)?;
+                }
+
+                Ok(ProcessSummary {
+                    total,
+                    succeeded: succeeded_count,
+                    failed: failed_count,
+                    skipped: 0,
+                    failures,
+                })
+            }
+            Err(e) => {
+                warn!(
+                    "Failed to generate merged PDF at {}: {}",
+                    output_path.display(),
+                    e
+                );
+
+                if let Some(summary_path) = config.summary_json.as_deref() {
+                    let merge_error = e.to_string();
+                    for entry in &mut summary_entries {
+                        entry.success = false;
+                        entry.output_path = None;
+                        entry.error_variant = Some("Io".to_string());
+                        entry.error = Some(format!("merged PDF generation failed: {merge_error}"));
+                    }
+                    let summary = RunSummary {
+                        entries: summary_entries,
+                    };
+                    if let Err(write_err) = summary.write(summary_path, config.report_format) {
+                        warn!("Failed to write run summary: {write_err}");
+                    }
+                }
+
+                failures.push((output_path, e.into()));
+                Ok(ProcessSummary {
+                    total,
+                    succeeded: 0,
+                    failed: failed_count + 1,
+                    skipped: 0,
+                    failures,
+                })
+            }
+        }
+    }
 }
 
 impl<P: AsRef<Path> + Eq + Hash + Send + 'static> MultipleFilesProcProvider<P>
     for ProcessorReady<P>
 {
-    type OkType = ();
+    type OkType = ProcessSummary;
     type ErrType = Vex2PdfError;
 
     fn process(self) -> Result<Self::OkType, Self::ErrType> {
+        if self.config.merge {
+            return self.process_merged();
+        }
+
         #[cfg(feature = "concurrency")]
-        let pool = if let Some(num_jobs) = self.config.max_jobs {
+        let mut pool = if let Some(num_jobs) = self.config.max_jobs {
             ThreadPool::new(num_jobs)
         } else {
             ThreadPool::default()
         };
+        // `--items-per-job`/`VEX2PDF_ITEMS_PER_JOB` override the pool's own chunking default
+        // (see `chunk_size` below); left alone, `pool` keeps using `DEFAULT_ITEMS_PER_JOB`.
+        #[cfg(feature = "concurrency")]
+        if let Some(items_per_job) = self.config.items_per_job {
+            pool = pool.with_items_per_job(items_per_job);
+        }
 
         #[cfg(feature = "concurrency")]
         info!("{pool}");
 
         let config = self.config;
-        let file_count = self.files.get_file_count();
+        let cancel = self.cancel;
+        let failures = Arc::new(Mutex::new(Vec::<(PathBuf, Vex2PdfError)>::new()));
+        let skipped_count = Arc::new(AtomicUsize::new(0));
+        let generated_paths = Arc::new(Mutex::new(Vec::<PathBuf>::new()));
+        let mut file_count = 0usize;
+
+        // Loading an existing checkpoint manifest lets a re-invoked `--resume` run pick up the
+        // skip/record state a prior, possibly interrupted, run left behind.
+        let checkpoint = config.resume.then(|| {
+            let checkpoint_path = config.output_dir.join(checkpoint::CHECKPOINT_FILENAME);
+            Arc::new(Mutex::new(CheckpointManifest::load(&checkpoint_path)))
+        });
+
+        // Accumulates a `RunSummaryEntry` per dispatched file when `--summary-json` is set; the
+        // emitter below writes it out after the batch completes, regardless of failures.
+        let summary = config
+            .summary_json
+            .is_some()
+            .then(|| Arc::new(Mutex::new(RunSummary::default())));
+
+        // Accumulates per-severity vulnerability counts across the batch when `--max-allowed`/
+        // `--fail-on-severity` configure a gate; checked against `config.max_allowed` once
+        // dispatch completes below.
+        let gate_counts = (!config.max_allowed.is_empty())
+            .then(|| Arc::new(Mutex::new(HashMap::<String, usize>::new())));
 
-        for file in self.files {
-            let single_file_proc = DefaultSingleFileProcessor;
+        // Number of files `dispatch` batches into a single job before handing the batch to the
+        // pool; irrelevant without the "concurrency" feature, where `submit_chunk` below always
+        // runs its (single-file) "chunk" inline on the calling thread anyway.
+        #[cfg(feature = "concurrency")]
+        let chunk_size = pool.items_per_job();
+        #[cfg(feature = "concurrency")]
+        let pending_chunk = Mutex::new(Vec::<BomFileIdentifier<P>>::with_capacity(chunk_size));
+
+        // Runs every file in `chunk` through `process_single_file`, recording each one's outcome
+        // the same way regardless of whether the chunk was handed to a worker or run inline.
+        let submit_chunk = |chunk: Vec<BomFileIdentifier<P>>| {
             let config_clone = Arc::clone(&config);
+            let failures_clone = Arc::clone(&failures);
+            let skipped_count_clone = Arc::clone(&skipped_count);
+            let generated_paths_clone = Arc::clone(&generated_paths);
+            let checkpoint_clone = checkpoint.as_ref().map(Arc::clone);
+            let summary_clone = summary.as_ref().map(Arc::clone);
+            let gate_counts_clone = gate_counts.as_ref().map(Arc::clone);
+            let cancel_clone = cancel.clone();
+
+            let run_chunk = move || {
+                for file in chunk {
+                    let source_path = file.get_path().as_ref().to_path_buf();
+                    let config_clone = Arc::clone(&config_clone);
+                    let checkpoint_clone = checkpoint_clone.as_ref().map(Arc::clone);
+                    let summary_clone = summary_clone.as_ref().map(Arc::clone);
+                    let gate_counts_clone = gate_counts_clone.as_ref().map(Arc::clone);
+                    let cancel_clone = cancel_clone.clone();
+
+                    // Caught per file, not around the whole chunk: one malformed BOM panicking
+                    // deep in a parser would otherwise abort the `for` loop and silently drop
+                    // every other file still queued in this chunk, even though they were already
+                    // counted into `total` when dispatched. This is the chunk's own safety net;
+                    // `ThreadPool::join` still catches anything that panics outside it (e.g. in
+                    // the bookkeeping below) so a worker thread never aborts the process either.
+                    let outcome = panic::catch_unwind(AssertUnwindSafe(|| {
+                        DefaultSingleFileProcessor.process_single_file(
+                            file,
+                            config_clone,
+                            checkpoint_clone,
+                            summary_clone,
+                            gate_counts_clone,
+                            cancel_clone,
+                        )
+                    }));
+
+                    match outcome {
+                        Ok(Ok(output_path)) => generated_paths_clone
+                            .lock()
+                            .expect("generated paths mutex poisoned")
+                            .push(output_path),
+                        Ok(Err(Vex2PdfError::Cancelled)) => {
+                            skipped_count_clone.fetch_add(1, Ordering::Relaxed);
+                        }
+                        Ok(Err(e)) => {
+                            error!("{e}");
+                            failures_clone
+                                .lock()
+                                .expect("failures mutex poisoned")
+                                .push((source_path, e));
+                        }
+                        Err(payload) => {
+                            let message = panic_message(&payload);
+                            error!("processing `{}` panicked: {message}", source_path.display());
+                            failures_clone
+                                .lock()
+                                .expect("failures mutex poisoned")
+                                .push((
+                                    source_path,
+                                    Vex2PdfError::WorkerPanicked {
+                                        worker_id: 0,
+                                        message,
+                                    },
+                                ));
+                        }
+                    }
+                }
+            };
+
+            #[cfg(feature = "concurrency")]
+            pool.execute(run_chunk).expect(
+                "Failed to send job to pool. Consider disabling Multithreading if issues persist",
+            ); // we do not want to immediatly return an error if one of the jobs failed hence why we did not propagate
+
+            #[cfg(not(feature = "concurrency"))]
+            run_chunk();
+        };
+
+        let dispatch = |file: BomFileIdentifier<P>| {
             #[cfg(feature = "concurrency")]
             {
-                pool.execute(move || {
-                    if let Err(e) = single_file_proc.process_single_file(file, config_clone) {
-                        error!("{e}");
-                    }
-                })
-                    .expect(
-                        "Failed to send job to pool. Consider disabling Multithreading if issues persist",
-                    ); // we do not want to immediatly return an error if one of the jobs failed hence why we did not propagate
+                let mut chunk = pending_chunk.lock().expect("pending chunk mutex poisoned");
+                chunk.push(file);
+                if chunk.len() >= chunk_size {
+                    let batch = std::mem::replace(&mut *chunk, Vec::with_capacity(chunk_size));
+                    drop(chunk);
+                    submit_chunk(batch);
+                }
             }
 
             #[cfg(not(feature = "concurrency"))]
-            {
-                single_file_proc
-                    .process_single_file(file, config_clone)
-                    .unwrap_or_else(|e| error!("{e}"));
+            submit_chunk(vec![file]);
+        };
+
+        // Files never dispatched at all because the loop below stopped enqueuing on
+        // cancellation; kept separate from `skipped_count` (which covers files that were
+        // dispatched but abandoned mid-processing) since only the latter factors into the
+        // dispatched/succeeded/failed counts below.
+        let mut undispatched_count = 0usize;
+
+        match self.pending {
+            PendingFiles::Collected(files) => {
+                let total = files.get_file_count();
+                for file in files {
+                    if cancel.is_cancelled() {
+                        info!("Cancellation requested: no longer enqueuing new files");
+                        undispatched_count = total - file_count;
+                        break;
+                    }
+                    file_count += 1;
+                    dispatch(file);
+                }
+            }
+            PendingFiles::Streamed { receiver, producer } => {
+                // the discovery thread is already walking concurrently with this loop, so every
+                // job below is dispatched to the pool as soon as it's found rather than after
+                // the whole tree has been scanned
+                for file in receiver.iter() {
+                    if cancel.is_cancelled() {
+                        info!("Cancellation requested: no longer enqueuing new files");
+                        break;
+                    }
+                    file_count += 1;
+                    dispatch(file);
+                }
+                // any files the discovery thread had already buffered past this point are
+                // counted as undispatched rather than dispatched; draining them here (instead
+                // of just dropping the receiver) also guarantees the producer's next `send`
+                // fails fast instead of blocking on a full channel with nobody left to read it
+                undispatched_count += receiver.try_iter().count();
+                drop(receiver);
+                producer
+                    .join()
+                    .expect("discovery thread panicked while walking for files");
             }
         }
 
+        // Whatever didn't reach a full `chunk_size` batch above is still a real batch of one or
+        // more files, so it gets dispatched here rather than silently dropped.
         #[cfg(feature = "concurrency")]
-        drop(pool); // dropping here to show information message after worker status messages
-                    // pool drops gracefully and cleans up here blocking until all jobs are finished
+        {
+            let leftover =
+                std::mem::take(&mut *pending_chunk.lock().expect("pending chunk mutex poisoned"));
+            if !leftover.is_empty() {
+                submit_chunk(leftover);
+            }
+        }
 
-        info!("Processed {file_count} files");
+        // Joined explicitly (rather than just letting `pool` drop here) so a caught
+        // `Vex2PdfError::WorkerPanicked` counts toward `failed` in the returned `ProcessSummary`
+        // instead of only being logged: a panicking conversion job is still a failed file from
+        // the caller's point of view, even though the worker survived it. There's no single
+        // input path to blame it on (the panic could have interrupted any job the worker ever
+        // picked up), so it's recorded under a placeholder path instead of a real one.
+        #[cfg(feature = "concurrency")]
+        if let Err(panics) = pool.join() {
+            let mut failures = failures.lock().expect("failures mutex poisoned");
+            for panic_err in panics {
+                error!("{panic_err}");
+                failures.push((PathBuf::from("<worker panic>"), panic_err));
+            }
+        }
+
+        let generated_paths = std::mem::take(
+            &mut *generated_paths
+                .lock()
+                .expect("generated paths mutex poisoned"),
+        );
+        let failures = std::mem::take(&mut *failures.lock().expect("failures mutex poisoned"));
+        let succeeded_count = generated_paths.len();
+        let failed_count = failures.len();
+        let skipped_count = skipped_count.load(Ordering::Relaxed) + undispatched_count;
 
-        Ok(())
+        if file_count == 0 {
+            info!("No parseable files in selected path");
+        }
+        info!(
+            "Processed {file_count} files ({succeeded_count} succeeded, {failed_count} failed, {skipped_count} cancelled)"
+        );
+        if cancel.is_cancelled() {
+            info!("Cancelled by user: {skipped_count} file(s) skipped in total");
+        }
+
+        // `generated_paths` holds input paths rather than real PDF output paths in dry-run mode
+        // (see `process_single_file`), so there's nothing meaningful to checksum.
+        if let Some(manifest_path) = config.manifest_path.as_deref().filter(|_| !config.dry_run) {
+            let entries = generated_paths
+                .iter()
+                .filter_map(|path| match manifest::compute_pdf_checksum(path) {
+                    Ok(checksum) => Some(manifest::ManifestEntry {
+                        path: path.clone(),
+                        checksum,
+                    }),
+                    Err(e) => {
+                        warn!("Skipping {} in manifest: {e}", path.display());
+                        None
+                    }
+                })
+                .collect::<Vec<_>>();
+
+            manifest::write_manifest(&entries, manifest_path)?;
+        }
+
+        if let (Some(summary_path), Some(summary)) = (config.summary_json.as_deref(), &summary) {
+            let summary = summary.lock().expect("run summary mutex poisoned");
+            if let Err(e) = summary.write(summary_path, config.report_format) {
+                warn!("Failed to write run summary: {e}");
+            }
+        }
+
+        // Evaluated last, after every PDF in the batch has already been generated, so the gate
+        // never withholds a report that's otherwise ready to ship as a CI artifact.
+        if let Some(gate_counts) = &gate_counts {
+            let gate_counts = gate_counts.lock().expect("severity gate mutex poisoned");
+            severity_gate::check(&gate_counts, &config.max_allowed)?;
+        }
+
+        Ok(ProcessSummary {
+            total: file_count + undispatched_count,
+            succeeded: succeeded_count,
+            failed: failed_count,
+            skipped: skipped_count,
+            failures,
+        })
     }
 }
 
@@ -218,7 +1201,7 @@ mod tests {
     #[test]
     fn test_default_files_processor_new() {
         let config = Config::default();
-        let processor = DefaultFilesProcessor::new(config);
+        let processor = DefaultFilesProcessor::new(config, CancelFlag::default());
 
         // Verify processor created with config (working_path should be set)
         assert!(processor.config.working_path.exists());
@@ -231,11 +1214,15 @@ mod tests {
 
         let processor_ready = ProcessorReady {
             config: Arc::clone(&config),
-            files,
+            cancel: CancelFlag::default(),
+            pending: PendingFiles::Collected(files),
         };
 
         // Verify state is accessible
-        assert_eq!(processor_ready.files.get_file_count(), 0);
+        match &processor_ready.pending {
+            PendingFiles::Collected(files) => assert_eq!(files.get_file_count(), 0),
+            PendingFiles::Streamed { .. } => panic!("expected a collected file set"),
+        }
         assert!(processor_ready.config.working_path.exists());
     }
 
@@ -247,4 +1234,311 @@ mod tests {
         fn assert_send<T: Send>() {}
         assert_send::<DefaultSingleFileProcessor>();
     }
+
+    #[test]
+    fn test_passes_name_filters_empty_include_matches_everything() {
+        let includes = build_glob_set(&[]);
+        let excludes = build_glob_set(&[]);
+
+        assert!(passes_name_filters(
+            Path::new("bom.json"),
+            &includes,
+            &excludes,
+            None
+        ));
+    }
+
+    #[test]
+    fn test_passes_name_filters_respects_include_and_exclude() {
+        let includes = build_glob_set(&["*.json".to_string()]);
+        let excludes = build_glob_set(&["*-draft.json".to_string()]);
+
+        assert!(passes_name_filters(
+            Path::new("bom.json"),
+            &includes,
+            &excludes,
+            None
+        ));
+        assert!(!passes_name_filters(
+            Path::new("bom.xml"),
+            &includes,
+            &excludes,
+            None
+        ));
+        assert!(!passes_name_filters(
+            Path::new("bom-draft.json"),
+            &includes,
+            &excludes,
+            None
+        ));
+    }
+
+    #[test]
+    fn test_passes_name_filters_respects_exclude_name_regex() {
+        let includes = build_glob_set(&[]);
+        let excludes = build_glob_set(&[]);
+        let exclude_name_regex = build_exclude_name_regex(Some(r"-draft(-v\d+)?\."));
+
+        assert!(passes_name_filters(
+            Path::new("nested/bom.json"),
+            &includes,
+            &excludes,
+            exclude_name_regex.as_ref()
+        ));
+        assert!(!passes_name_filters(
+            Path::new("nested/bom-draft-v2.json"),
+            &includes,
+            &excludes,
+            exclude_name_regex.as_ref()
+        ));
+    }
+
+    #[test]
+    fn test_build_glob_set_ignores_invalid_pattern() {
+        // an unbalanced bracket is not a valid glob; the set should still build and simply
+        // not contain that pattern rather than failing discovery outright
+        let set = build_glob_set(&["[".to_string(), "*.json".to_string()]);
+
+        assert!(set.is_match(Path::new("bom.json")));
+    }
+
+    #[test]
+    fn test_build_exclude_name_regex_ignores_invalid_pattern() {
+        assert!(build_exclude_name_regex(Some("(unclosed")).is_none());
+        assert!(build_exclude_name_regex(None).is_none());
+    }
+
+    #[test]
+    fn test_include_base_dirs_extracts_literal_prefix() {
+        let working_path = Path::new("/work");
+
+        let dirs = include_base_dirs(working_path, &["src/**/bom.json".to_string()]);
+
+        assert_eq!(dirs, vec![PathBuf::from("/work/src")]);
+    }
+
+    #[test]
+    fn test_include_base_dirs_defaults_to_working_path_when_empty() {
+        let working_path = Path::new("/work");
+
+        assert_eq!(
+            include_base_dirs(working_path, &[]),
+            vec![working_path.to_path_buf()]
+        );
+    }
+
+    #[test]
+    fn test_include_base_dirs_drops_nested_duplicates() {
+        let working_path = Path::new("/work");
+
+        let dirs = include_base_dirs(
+            working_path,
+            &["src/**/bom.json".to_string(), "src/a/bom.json".to_string()],
+        );
+
+        assert_eq!(dirs, vec![PathBuf::from("/work/src")]);
+    }
+
+    #[test]
+    fn test_as_parse_error_preserves_schema_invalid_variant() {
+        let boxed: Box<dyn std::error::Error> = Box::new(Vex2PdfError::SchemaInvalid(vec![
+            "/specVersion: is not one of [\"1.5\"]".to_string(),
+        ]));
+
+        match as_parse_error(boxed) {
+            Vex2PdfError::SchemaInvalid(violations) => assert_eq!(violations.len(), 1),
+            other => panic!("expected SchemaInvalid, got {other}"),
+        }
+    }
+
+    #[test]
+    fn test_as_parse_error_flattens_other_errors_into_parse() {
+        let boxed: Box<dyn std::error::Error> = Box::new(Vex2PdfError::UnsupportedFileType);
+
+        match as_parse_error(boxed) {
+            Vex2PdfError::Parse(_) => (),
+            other => panic!("expected Parse, got {other}"),
+        }
+    }
+
+    /// Test-only helper that drives [`walk_files`] into a `Vec` for easy assertions, since
+    /// production code now consumes it via a streaming `visit` closure instead.
+    fn collect_files_walked(
+        dir: &Path,
+        max_depth: Option<usize>,
+        follow_symlinks: bool,
+        respect_ignore_files: bool,
+        include_hidden: bool,
+        exclude_patterns: &[String],
+    ) -> Vec<PathBuf> {
+        let exclude_globs = build_glob_set(exclude_patterns);
+        let mut out = Vec::new();
+        walk_files(
+            dir,
+            max_depth,
+            follow_symlinks,
+            respect_ignore_files,
+            include_hidden,
+            dir,
+            &exclude_globs,
+            &CancelFlag::default(),
+            |path| out.push(path),
+        );
+        out
+    }
+
+    #[test]
+    fn test_collect_files_walked_descends_into_subdirectories() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let nested = temp_dir.path().join("a").join("b");
+        fs::create_dir_all(&nested).unwrap();
+
+        fs::write(temp_dir.path().join("top.json"), "{}").unwrap();
+        fs::write(nested.join("nested.xml"), "<bom/>").unwrap();
+
+        let out = collect_files_walked(temp_dir.path(), None, false, true, false, &[]);
+
+        assert_eq!(out.len(), 2);
+        assert!(out.iter().any(|p| p.ends_with("top.json")));
+        assert!(out.iter().any(|p| p.ends_with("nested.xml")));
+    }
+
+    #[test]
+    fn test_collect_files_walked_respects_max_depth() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let nested = temp_dir.path().join("a").join("b");
+        fs::create_dir_all(&nested).unwrap();
+
+        fs::write(temp_dir.path().join("top.json"), "{}").unwrap();
+        fs::write(nested.join("nested.xml"), "<bom/>").unwrap();
+
+        // depth 0 is the root itself and depth 1 its direct children, so only top-level files
+        // are picked up
+        let out = collect_files_walked(temp_dir.path(), Some(1), false, true, false, &[]);
+
+        assert_eq!(out.len(), 1);
+        assert!(out.iter().any(|p| p.ends_with("top.json")));
+    }
+
+    #[test]
+    fn test_collect_files_walked_honors_gitignore() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        fs::write(temp_dir.path().join(".gitignore"), "ignored.json\n").unwrap();
+        fs::write(temp_dir.path().join("ignored.json"), "{}").unwrap();
+        fs::write(temp_dir.path().join("kept.json"), "{}").unwrap();
+
+        let out = collect_files_walked(temp_dir.path(), None, false, true, false, &[]);
+
+        assert_eq!(out.len(), 1);
+        assert!(out.iter().any(|p| p.ends_with("kept.json")));
+    }
+
+    #[test]
+    fn test_collect_files_walked_can_ignore_gitignore() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        fs::write(temp_dir.path().join(".gitignore"), "ignored.json\n").unwrap();
+        fs::write(temp_dir.path().join("ignored.json"), "{}").unwrap();
+        fs::write(temp_dir.path().join("kept.json"), "{}").unwrap();
+
+        let out = collect_files_walked(temp_dir.path(), None, false, false, false, &[]);
+
+        assert_eq!(out.len(), 2);
+    }
+
+    #[test]
+    fn test_collect_files_walked_prunes_excluded_directories() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let node_modules = temp_dir.path().join("node_modules");
+        fs::create_dir_all(&node_modules).unwrap();
+
+        fs::write(temp_dir.path().join("top.json"), "{}").unwrap();
+        fs::write(node_modules.join("pkg.json"), "{}").unwrap();
+
+        let out = collect_files_walked(
+            temp_dir.path(),
+            None,
+            false,
+            true,
+            false,
+            &["node_modules".to_string()],
+        );
+
+        assert_eq!(out.len(), 1);
+        assert!(out.iter().any(|p| p.ends_with("top.json")));
+    }
+
+    #[test]
+    fn test_spawn_discovery_streams_matching_files() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        fs::write(temp_dir.path().join("bom.json"), "{}").unwrap();
+        fs::write(temp_dir.path().join("bom.xml"), "<bom/>").unwrap();
+        fs::write(temp_dir.path().join("notes.txt"), "not a bom").unwrap();
+
+        let config = Arc::new(
+            Config::default()
+                .working_path(temp_dir.path())
+                .recursive(false),
+        );
+
+        let (receiver, producer) = spawn_discovery(
+            Arc::clone(&config),
+            DISCOVERY_CHANNEL_BOUND,
+            CancelFlag::default(),
+        );
+        let received: Vec<_> = receiver.iter().collect();
+        producer.join().unwrap();
+
+        assert_eq!(received.len(), 2);
+        assert!(received.iter().any(|f| f.get_path().ends_with("bom.json")));
+        assert!(received.iter().any(|f| f.get_path().ends_with("bom.xml")));
+    }
+
+    #[test]
+    fn test_walk_files_stops_when_already_cancelled() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        fs::write(temp_dir.path().join("bom.json"), "{}").unwrap();
+
+        let cancel = CancelFlag::default();
+        cancel.cancel();
+
+        let out = {
+            let mut out = Vec::new();
+            walk_files(
+                temp_dir.path(),
+                None,
+                false,
+                true,
+                false,
+                temp_dir.path(),
+                &build_glob_set(&[]),
+                &cancel,
+                |path| out.push(path),
+            );
+            out
+        };
+
+        assert!(out.is_empty());
+    }
+
+    #[test]
+    fn test_process_single_file_returns_cancelled_when_flag_set() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let source = temp_dir.path().join("bom.json");
+        fs::write(&source, "{}").unwrap();
+
+        let config = Arc::new(
+            Config::default()
+                .working_path(temp_dir.path())
+                .output_dir(temp_dir.path()),
+        );
+        let file = BomFileIdentifier::build(source).unwrap();
+
+        let cancel = CancelFlag::default();
+        cancel.cancel();
+
+        let result =
+            DefaultSingleFileProcessor.process_single_file(file, config, None, None, None, cancel);
+
+        assert!(matches!(result, Err(Vex2PdfError::Cancelled)));
+    }
 }
@@ -0,0 +1,318 @@
+//! Machine-readable JSON summary of a batch run, written by
+//! [`RunSummary::write`] to [`Config::summary_json`](crate::lib_utils::config::Config::summary_json)
+//! regardless of partial failures, so CI systems can ingest it without parsing log lines.
+//!
+//! One [`RunSummaryEntry`] is recorded per input file, whether it succeeded or failed. The
+//! "document type" field is a best-effort guess (`"vex"` if the document carries any
+//! vulnerabilities, `"sbom"` otherwise) since CycloneDX's own wire format doesn't carry a VEX/VDR/
+//! SBOM discriminator; distinguishing VDR from VEX would need the BOM's `metadata.properties` or
+//! out-of-band knowledge this crate doesn't have.
+
+use crate::files_proc::model::input_file_type::InputFileType;
+use crate::lib_utils::config::ReportFormat;
+use crate::lib_utils::errors::Vex2PdfError;
+use crate::lib_utils::fs_context;
+use crate::pdf::generator::PdfGenerator;
+use cyclonedx_bom::prelude::Bom;
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Renders a [`InputFileType`] for the `format` field without panicking on
+/// [`InputFileType::UNSUPPORTED`], which has no lowercase string representation of its own.
+/// Shared with [`crate::files_proc::dry_run`], which has the same need.
+pub(crate) fn format_label(file_type: InputFileType) -> String {
+    if file_type == InputFileType::UNSUPPORTED {
+        "unsupported".to_string()
+    } else {
+        file_type.as_str_lowercase().to_string()
+    }
+}
+
+/// The `Vex2PdfError` variant name carried by a failed [`RunSummaryEntry`], e.g. `"Parse"` or
+/// `"SchemaInvalid"` ‒ kept separate from the variant's `Display` message (`error`) so a consumer
+/// can branch on the failure kind without parsing prose.
+const fn error_variant_name(error: &Vex2PdfError) -> &'static str {
+    match error {
+        Vex2PdfError::Io(_) => "Io",
+        Vex2PdfError::InvalidOutputDir(_) => "InvalidOutputDir",
+        Vex2PdfError::InvalidFileStem(_) => "InvalidFileStem",
+        Vex2PdfError::Parse(_) => "Parse",
+        Vex2PdfError::UnsupportedFileType => "UnsupportedFileType",
+        Vex2PdfError::IgnoredByUser => "IgnoredByUser",
+        Vex2PdfError::ConcurrencyError(_) => "ConcurrencyError",
+        Vex2PdfError::UnsupportedOutputFormat(_) => "UnsupportedOutputFormat",
+        Vex2PdfError::Upgrade(_) => "Upgrade",
+        Vex2PdfError::SchemaInvalid(_) => "SchemaInvalid",
+        Vex2PdfError::PdfValidation(_) => "PdfValidation",
+        Vex2PdfError::ManifestMismatch(_) => "ManifestMismatch",
+        Vex2PdfError::Cancelled => "Cancelled",
+        Vex2PdfError::SeverityThresholdExceeded(_) => "SeverityThresholdExceeded",
+        Vex2PdfError::Watch(_) => "Watch",
+        Vex2PdfError::SnapshotMismatch(_) => "SnapshotMismatch",
+        Vex2PdfError::WorkerPanicked { .. } => "WorkerPanicked",
+    }
+}
+
+/// Escapes the five characters XML 1.0 requires escaped in text/attribute content.
+fn escape_xml(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            other => escaped.push(other),
+        }
+    }
+    escaped
+}
+
+/// One input file's outcome, as recorded by [`RunSummary`].
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct RunSummaryEntry {
+    pub input_path: PathBuf,
+    /// `"json"`/`"xml"`/`"protobuf"`, lowercase.
+    pub format: String,
+    /// `"vex"` or `"sbom"`; see the module docs for how this is inferred.
+    pub document_type: String,
+    pub spec_version: String,
+    pub output_path: Option<PathBuf>,
+    pub success: bool,
+    /// Wall-clock time spent parsing and (unless `--dry-run`) rendering this file.
+    pub elapsed_secs: f64,
+    /// The failed [`Vex2PdfError`]'s variant name (e.g. `"Parse"`), for consumers that want to
+    /// branch on failure kind without parsing `error`'s prose. `None` on success.
+    pub error_variant: Option<String>,
+    pub error: Option<String>,
+    /// Counts of vulnerabilities per severity band (`Critical`/`High`/`Medium`/`Low`/`None`/
+    /// `Unknown`), via [`PdfGenerator::severity_bucket`]. Empty for a failed or vulnerability-free
+    /// document.
+    pub severity_counts: HashMap<String, usize>,
+    /// Counts of vulnerabilities per VEX analysis state (`"none"` for unanalyzed entries), via
+    /// [`PdfGenerator::vuln_state`].
+    pub state_counts: HashMap<String, usize>,
+}
+
+impl RunSummaryEntry {
+    /// Records a file that was successfully parsed and converted.
+    pub fn success(
+        input_path: PathBuf,
+        file_type: InputFileType,
+        bom: &Bom,
+        output_path: PathBuf,
+        elapsed: Duration,
+    ) -> Self {
+        let mut severity_counts = HashMap::new();
+        let mut state_counts = HashMap::new();
+        let vulns = bom
+            .vulnerabilities
+            .as_ref()
+            .map(|v| v.0.iter().collect::<Vec<_>>())
+            .unwrap_or_default();
+
+        for vuln in &vulns {
+            *severity_counts
+                .entry(PdfGenerator::severity_bucket(vuln).to_string())
+                .or_insert(0) += 1;
+            *state_counts
+                .entry(PdfGenerator::vuln_state(vuln))
+                .or_insert(0) += 1;
+        }
+
+        Self {
+            input_path,
+            format: format_label(file_type),
+            document_type: if vulns.is_empty() { "sbom" } else { "vex" }.to_string(),
+            spec_version: bom.spec_version.to_string(),
+            output_path: Some(output_path),
+            success: true,
+            elapsed_secs: elapsed.as_secs_f64(),
+            error_variant: None,
+            error: None,
+            severity_counts,
+            state_counts,
+        }
+    }
+
+    /// Records a file that failed discovery, parsing, or rendering, before a [`Bom`] (or even a
+    /// confirmed format) was necessarily available.
+    pub fn failure(
+        input_path: PathBuf,
+        file_type: InputFileType,
+        error: &Vex2PdfError,
+        elapsed: Duration,
+    ) -> Self {
+        Self {
+            input_path,
+            format: format_label(file_type),
+            document_type: "unknown".to_string(),
+            spec_version: String::new(),
+            output_path: None,
+            success: false,
+            elapsed_secs: elapsed.as_secs_f64(),
+            error_variant: Some(error_variant_name(error).to_string()),
+            error: Some(error.to_string()),
+            severity_counts: HashMap::new(),
+            state_counts: HashMap::new(),
+        }
+    }
+}
+
+/// Every per-file outcome accumulated over one batch run.
+#[derive(Debug, Clone, Default, serde::Serialize)]
+pub struct RunSummary {
+    pub entries: Vec<RunSummaryEntry>,
+}
+
+impl RunSummary {
+    /// Serializes `self` as pretty-printed JSON, one object per [`RunSummaryEntry`] in an
+    /// `entries` array.
+    fn to_json(&self) -> Result<String, Vex2PdfError> {
+        serde_json::to_string_pretty(self)
+            .map_err(|e| Vex2PdfError::Parse(format!("failed to serialize run summary: {e}")))
+    }
+
+    /// Renders `self` as a JUnit `<testsuite>` document: each entry becomes a `<testcase>` named
+    /// after its input path, and a failed entry gets a nested `<failure>` carrying the
+    /// [`Vex2PdfError`] variant name as `type` and its `Display` message as the failure text.
+    /// `time` on both the suite and each testcase is `elapsed_secs`, matching the attribute JUnit
+    /// consumers (e.g. CI dashboards) already expect from other tools.
+    fn to_junit_xml(&self) -> String {
+        let failures = self.entries.iter().filter(|e| !e.success).count();
+        let total_time: f64 = self.entries.iter().map(|e| e.elapsed_secs).sum();
+
+        let mut xml = String::new();
+        let _ = writeln!(xml, r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+        let _ = writeln!(
+            xml,
+            r#"<testsuite name="vex2pdf" tests="{}" failures="{}" time="{:.6}">"#,
+            self.entries.len(),
+            failures,
+            total_time
+        );
+        for entry in &self.entries {
+            let _ = write!(
+                xml,
+                r#"  <testcase name="{}" classname="vex2pdf.{}" time="{:.6}""#,
+                escape_xml(&entry.input_path.display().to_string()),
+                escape_xml(&entry.format),
+                entry.elapsed_secs
+            );
+            match (&entry.error_variant, &entry.error) {
+                (Some(variant), Some(message)) => {
+                    let _ = writeln!(xml, ">");
+                    let _ = writeln!(
+                        xml,
+                        r#"    <failure type="{}" message="{}">{}</failure>"#,
+                        escape_xml(variant),
+                        escape_xml(message),
+                        escape_xml(message)
+                    );
+                    let _ = writeln!(xml, "  </testcase>");
+                }
+                _ => {
+                    let _ = writeln!(xml, " />");
+                }
+            }
+        }
+        xml.push_str("</testsuite>\n");
+        xml
+    }
+
+    /// Serializes `self` in `format` and writes it to `path`. Runs regardless of whether the
+    /// batch itself had failures, so a CI system always gets an artifact to gate on.
+    pub fn write(&self, path: impl AsRef<Path>, format: ReportFormat) -> Result<(), Vex2PdfError> {
+        let path = path.as_ref();
+        let rendered = match format {
+            ReportFormat::Json => self.to_json()?,
+            ReportFormat::Junit => self.to_junit_xml(),
+        };
+        fs_context::write(path, rendered)?;
+        log::info!(
+            "Wrote {} run summary for {} file(s) to {}",
+            format.as_str(),
+            self.entries.len(),
+            path.display()
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_summary_writes_pretty_json() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("summary.json");
+
+        let summary = RunSummary {
+            entries: vec![RunSummaryEntry::failure(
+                PathBuf::from("bad.json"),
+                InputFileType::JSON,
+                &Vex2PdfError::UnsupportedFileType,
+                Duration::from_millis(5),
+            )],
+        };
+
+        summary.write(&path, ReportFormat::Json).unwrap();
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&contents).unwrap();
+        assert_eq!(parsed["entries"][0]["format"], "json");
+        assert_eq!(parsed["entries"][0]["success"], false);
+        assert_eq!(parsed["entries"][0]["error_variant"], "UnsupportedFileType");
+        assert!(parsed["entries"][0]["elapsed_secs"].as_f64().unwrap() > 0.0);
+    }
+
+    #[test]
+    fn test_run_summary_writes_junit_testsuite_with_failure() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("summary.xml");
+
+        let summary = RunSummary {
+            entries: vec![RunSummaryEntry::failure(
+                PathBuf::from("bad.json"),
+                InputFileType::JSON,
+                &Vex2PdfError::Parse("unexpected token".to_string()),
+                Duration::from_millis(5),
+            )],
+        };
+
+        summary.write(&path, ReportFormat::Junit).unwrap();
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        assert!(contents.contains(r#"<testsuite name="vex2pdf" tests="1" failures="1""#));
+        assert!(contents.contains(r#"name="bad.json""#));
+        assert!(contents.contains(r#"<failure type="Parse""#));
+        assert!(contents.contains("unexpected token"));
+    }
+
+    #[test]
+    fn test_junit_testcase_has_no_failure_element_on_success() {
+        let xml = RunSummary {
+            entries: vec![RunSummaryEntry {
+                input_path: PathBuf::from("ok.json"),
+                format: "json".to_string(),
+                document_type: "sbom".to_string(),
+                spec_version: "1.5".to_string(),
+                output_path: Some(PathBuf::from("ok.pdf")),
+                success: true,
+                elapsed_secs: 0.01,
+                error_variant: None,
+                error: None,
+                severity_counts: HashMap::new(),
+                state_counts: HashMap::new(),
+            }],
+        }
+        .to_junit_xml();
+
+        assert!(!xml.contains("<failure"));
+        assert!(xml.contains(r#"name="ok.json""#));
+    }
+}
@@ -0,0 +1,173 @@
+//! Aggregates per-vulnerability severity counts across a batch run so
+//! [`crate::files_proc::processor`] can fail the process when findings exceed a
+//! [`Config::max_allowed`](crate::lib_utils::config::Config::max_allowed) threshold, once every
+//! PDF has already been generated and is available as a CI artifact regardless of outcome.
+
+use crate::lib_utils::errors::Vex2PdfError;
+use crate::pdf::generator::PdfGenerator;
+use cyclonedx_bom::prelude::Bom;
+use std::collections::HashMap;
+
+/// VEX analysis states excluded from the gate by default; see
+/// [`Config::gate_count_analyzed`](crate::lib_utils::config::Config::gate_count_analyzed).
+const EXCLUDED_STATES: [&str; 2] = ["not_affected", "resolved"];
+
+/// Adds `bom`'s vulnerabilities to `counts`, bucketed by severity band (via
+/// [`PdfGenerator::severity_bucket`]). A vulnerability whose VEX analysis state is
+/// `"not_affected"` or `"resolved"` is skipped unless `count_analyzed` is set, so a build only
+/// breaks on genuinely exploitable findings by default.
+pub(crate) fn accumulate(counts: &mut HashMap<String, usize>, bom: &Bom, count_analyzed: bool) {
+    let vulns = bom
+        .vulnerabilities
+        .as_ref()
+        .map(|vulnerabilities| vulnerabilities.0.iter().collect::<Vec<_>>())
+        .unwrap_or_default();
+
+    for vuln in &vulns {
+        if !count_analyzed && EXCLUDED_STATES.contains(&PdfGenerator::vuln_state(vuln).as_str()) {
+            continue;
+        }
+        *counts
+            .entry(PdfGenerator::severity_bucket(vuln).to_string())
+            .or_insert(0) += 1;
+    }
+}
+
+/// Compares `counts` against `max_allowed`, returning
+/// [`Vex2PdfError::SeverityThresholdExceeded`] naming every severity band whose count exceeds
+/// its configured maximum, or `Ok(())` if none do (or no thresholds are configured).
+///
+/// `max_allowed`'s keys come straight from user input (e.g. `--max-allowed critical=0`) while
+/// `counts`'s keys are [`PdfGenerator::severity_bucket`]'s capitalized band names, so both sides
+/// are run through [`PdfGenerator::normalize_filter_token`] before comparing — same as
+/// `--only-severity`/`--skip-severity` already do.
+pub(crate) fn check(
+    counts: &HashMap<String, usize>,
+    max_allowed: &HashMap<String, usize>,
+) -> Result<(), Vex2PdfError> {
+    let normalized_counts: HashMap<String, usize> = counts
+        .iter()
+        .map(|(severity, &count)| (PdfGenerator::normalize_filter_token(severity), count))
+        .collect();
+
+    let mut violations: Vec<String> = max_allowed
+        .iter()
+        .filter_map(|(severity, &allowed)| {
+            let found = normalized_counts
+                .get(&PdfGenerator::normalize_filter_token(severity))
+                .copied()
+                .unwrap_or(0);
+            (found > allowed).then(|| format!("{severity}: {found} found, {allowed} allowed"))
+        })
+        .collect();
+    violations.sort();
+
+    if violations.is_empty() {
+        Ok(())
+    } else {
+        Err(Vex2PdfError::SeverityThresholdExceeded(violations))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Parses a minimal CycloneDX 1.5 VEX document with one vulnerability of `severity`, and
+    /// `analysis.state` set to `state` (omitted entirely when `None`). Going through the real
+    /// JSON parser (rather than hand-building a [`Vulnerability`] literal) sidesteps having to
+    /// track the crate's internal analysis-state enum across versions.
+    fn bom_with_vuln(severity: &str, state: Option<&str>) -> Bom {
+        let analysis = match state {
+            Some(state) => format!(r#","analysis":{{"state":"{state}"}}"#),
+            None => String::new(),
+        };
+        let json = format!(
+            r#"{{
+                "bomFormat": "CycloneDX",
+                "specVersion": "1.5",
+                "version": 1,
+                "vulnerabilities": [
+                    {{
+                        "id": "CVE-2024-0001",
+                        "ratings": [{{"severity": "{severity}"}}]
+                        {analysis}
+                    }}
+                ]
+            }}"#
+        );
+
+        Bom::parse_from_json(json.as_bytes()).expect("fixture VEX JSON failed to parse")
+    }
+
+    #[test]
+    fn test_accumulate_excludes_not_affected_by_default() {
+        let bom = bom_with_vuln("critical", Some("not_affected"));
+        let mut counts = HashMap::new();
+
+        accumulate(&mut counts, &bom, false);
+
+        assert!(counts.is_empty());
+    }
+
+    #[test]
+    fn test_accumulate_counts_not_affected_when_opted_in() {
+        let bom = bom_with_vuln("critical", Some("not_affected"));
+        let mut counts = HashMap::new();
+
+        accumulate(&mut counts, &bom, true);
+
+        assert_eq!(counts.get("Critical"), Some(&1));
+    }
+
+    #[test]
+    fn test_accumulate_counts_unanalyzed_findings() {
+        let bom = bom_with_vuln("high", None);
+        let mut counts = HashMap::new();
+
+        accumulate(&mut counts, &bom, false);
+
+        assert_eq!(counts.get("High"), Some(&1));
+    }
+
+    #[test]
+    fn test_check_passes_within_threshold() {
+        let mut counts = HashMap::new();
+        counts.insert("High".to_string(), 2);
+        let mut max_allowed = HashMap::new();
+        max_allowed.insert("High".to_string(), 2);
+
+        assert!(check(&counts, &max_allowed).is_ok());
+    }
+
+    #[test]
+    fn test_check_fails_over_threshold() {
+        let mut counts = HashMap::new();
+        counts.insert("Critical".to_string(), 3);
+        let mut max_allowed = HashMap::new();
+        max_allowed.insert("Critical".to_string(), 0);
+
+        let err = check(&counts, &max_allowed).unwrap_err();
+        assert!(matches!(err, Vex2PdfError::SeverityThresholdExceeded(_)));
+    }
+
+    /// Regression test for the real `--max-allowed critical=0` path: `max_allowed`'s keys come
+    /// from [`crate::lib_utils::cli_args::parse_severity_threshold`] (lowercase, as every CLI
+    /// example documents it), while `counts`'s keys come from [`accumulate`] (capitalized band
+    /// names via [`PdfGenerator::severity_bucket`]). Unlike the hand-inserted-matching-case
+    /// tests above, this one would have caught the case mismatch that made the gate a no-op.
+    #[test]
+    fn test_check_matches_lowercase_cli_token_against_capitalized_bucket() {
+        let (severity, allowed) =
+            crate::lib_utils::cli_args::parse_severity_threshold("critical=0").unwrap();
+        let mut max_allowed = HashMap::new();
+        max_allowed.insert(severity, allowed);
+
+        let bom = bom_with_vuln("critical", None);
+        let mut counts = HashMap::new();
+        accumulate(&mut counts, &bom, false);
+
+        let err = check(&counts, &max_allowed).unwrap_err();
+        assert!(matches!(err, Vex2PdfError::SeverityThresholdExceeded(_)));
+    }
+}
@@ -0,0 +1,57 @@
+//! The outcome of a batch run, as returned by
+//! [`MultipleFilesProcProvider::process`](crate::files_proc::traits::MultipleFilesProcProvider::process).
+//!
+//! Every file that was actually dispatched for processing ends up counted in exactly one of
+//! `succeeded`/`failed`/`skipped`, so a caller (or the CLI's exit code) can tell a clean run
+//! apart from a batch where some files quietly failed, instead of only seeing log output.
+
+use crate::lib_utils::errors::Vex2PdfError;
+use std::path::PathBuf;
+
+/// Aggregated per-file outcomes from one [`process`](crate::files_proc::traits::MultipleFilesProcProvider::process) call.
+#[derive(Debug, Default)]
+pub struct ProcessSummary {
+    /// Every file considered for this run, whether or not it was actually dispatched.
+    pub total: usize,
+    /// Files that were successfully converted to PDF.
+    pub succeeded: usize,
+    /// Files that failed to parse or render; see `failures` for the individual errors.
+    pub failed: usize,
+    /// Files that were never attempted: either abandoned by a Ctrl-C cancellation, or
+    /// discovered after enqueuing had already stopped.
+    pub skipped: usize,
+    /// `(source_path, error)` for every file counted in `failed`, in the order failures were
+    /// observed.
+    pub failures: Vec<(PathBuf, Vex2PdfError)>,
+}
+
+impl ProcessSummary {
+    /// Returns `true` if every dispatched file succeeded (a cancelled/skipped run can still be
+    /// "ok" in this sense; `failed == 0` is what should drive a non-zero process exit code).
+    pub fn is_ok(&self) -> bool {
+        self.failed == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_summary_is_ok() {
+        assert!(ProcessSummary::default().is_ok());
+    }
+
+    #[test]
+    fn test_summary_with_failures_is_not_ok() {
+        let summary = ProcessSummary {
+            total: 2,
+            succeeded: 1,
+            failed: 1,
+            skipped: 0,
+            failures: vec![(PathBuf::from("bom.json"), Vex2PdfError::UnsupportedFileType)],
+        };
+
+        assert!(!summary.is_ok());
+    }
+}
@@ -1,9 +1,13 @@
+use crate::files_proc::checkpoint::CheckpointManifest;
 use crate::files_proc::model::file_ident::BomFileIdentifier;
+use crate::files_proc::run_summary::RunSummary;
+use crate::lib_utils::cancel::CancelFlag;
 use crate::lib_utils::config::Config;
 use crate::lib_utils::errors::Vex2PdfError;
+use std::collections::HashMap;
 use std::hash::Hash;
-use std::path::Path;
-use std::sync::Arc;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
 
 pub trait FileSearchProvider {
     type OkType;
@@ -12,15 +16,52 @@ pub trait FileSearchProvider {
 }
 
 pub trait SingleFileProcProvider<P: AsRef<Path> + Eq + Hash>: Send + 'static {
+    /// Processes a single file, returning the path of the PDF it generated on success so
+    /// callers can aggregate the output set (e.g. for [`crate::pdf::manifest`]).
+    ///
+    /// When `checkpoint` is `Some` (i.e. [`Config::resume`] is on), an output whose
+    /// [`crate::files_proc::checkpoint::output_is_up_to_date`] is skipped rather than
+    /// regenerated, and the outcome is recorded back into the shared manifest.
+    ///
+    /// `cancel` is checked before parsing and again before PDF generation; once it trips
+    /// (Ctrl-C), the file is abandoned with [`Vex2PdfError::Cancelled`] instead of being
+    /// parsed/rendered, and no checkpoint entry is recorded for it.
+    ///
+    /// When `summary` is `Some` (i.e. [`Config::summary_json`] is set), a
+    /// [`crate::files_proc::run_summary::RunSummaryEntry`] describing this file's outcome is
+    /// recorded into the shared [`RunSummary`], regardless of whether it succeeded or failed.
+    ///
+    /// When `gate_counts` is `Some` (i.e. [`Config::max_allowed`] is non-empty), every
+    /// vulnerability found in a successfully parsed document is folded into the shared
+    /// per-severity counts via [`crate::files_proc::severity_gate::accumulate`], for
+    /// `process`/`process_merged` to check against the configured thresholds once dispatch
+    /// completes.
     fn process_single_file(
         &self,
         file: BomFileIdentifier<P>,
         config: Arc<Config>,
-    ) -> Result<(), Vex2PdfError>;
+        checkpoint: Option<Arc<Mutex<CheckpointManifest>>>,
+        summary: Option<Arc<Mutex<RunSummary>>>,
+        gate_counts: Option<Arc<Mutex<HashMap<String, usize>>>>,
+        cancel: CancelFlag,
+    ) -> Result<PathBuf, Vex2PdfError>;
 }
 
-/// no need to restrict this to send as typically threads are created inside this function
-/// TODO complete documentation
+/// Drives a batch of discovered files to completion and reports an aggregate outcome.
+///
+/// Implementations are expected to fan each file out across a bounded worker pool (see
+/// [`ProcessorReady`](crate::files_proc::processor::ProcessorReady), whose `process` dispatches
+/// to a [`ThreadPool`](crate::lib_utils::concurrency::threadpool::ThreadPool) sized by
+/// [`Config::max_jobs`], defaulting to [`std::thread::available_parallelism`]) rather than
+/// spawning one thread per file or running strictly sequentially. A single file's failure is
+/// recorded and the batch continues; `process`'s `OkType` (typically
+/// [`ProcessSummary`](crate::files_proc::summary::ProcessSummary)) aggregates succeeded/failed/
+/// skipped counts alongside the individual errors instead of surfacing only the first failure.
+///
+/// No bound on `Self` beyond the supertrait requirements: unlike
+/// [`SingleFileProcProvider`], which is dispatched onto worker threads and therefore needs
+/// `Send + 'static`, `process` itself runs on the calling thread and only spawns/joins workers
+/// internally.
 pub trait MultipleFilesProcProvider<P: AsRef<Path> + Eq + Hash> {
     type OkType;
     type ErrType;
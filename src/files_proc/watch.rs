@@ -0,0 +1,105 @@
+//! Backs `--watch`: keeps the process alive after the initial conversion and reconverts any BOM
+//! under [`Config::working_path`] whenever it changes on disk, instead of exiting once the batch
+//! completes. Modeled on Deno's `file_watcher` subsystem: raw filesystem events are coalesced
+//! over a short debounce window into a deduplicated set of affected paths before anything is
+//! reconverted, so a burst of writes to the same file collapses into a single re-run.
+
+use crate::files_proc::model::file_ident::BomFileIdentifier;
+use crate::files_proc::processor::DefaultSingleFileProcessor;
+use crate::files_proc::traits::SingleFileProcProvider;
+use crate::lib_utils::cancel::CancelFlag;
+use crate::lib_utils::config::Config;
+use crate::lib_utils::errors::Vex2PdfError;
+use log::{error, info, warn};
+use notify::{RecursiveMode, Watcher};
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::sync::{mpsc, Arc};
+use std::time::Duration;
+
+/// How long to wait after the last filesystem event before reconverting, so a burst of events
+/// for the same save (e.g. an editor's write-then-touch) collapses into a single re-run.
+const DEBOUNCE_WINDOW: Duration = Duration::from_millis(200);
+
+/// Watches [`Config::working_path`] for changes and reconverts each affected BOM into
+/// [`Config::output_dir`], blocking until `cancel` trips (e.g. Ctrl-C). A file whose conversion
+/// fails is logged and watching continues rather than exiting the whole process; only a failure
+/// to start or keep running the notifier itself is returned as an error.
+pub(crate) fn watch_and_reprocess(
+    config: Arc<Config>,
+    cancel: CancelFlag,
+) -> Result<(), Vex2PdfError> {
+    let (tx, rx) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(move |res: notify::Result<notify::Event>| {
+        match res {
+            Ok(event) => {
+                // the receiver only goes away once this function returns, at which point the
+                // watcher itself is about to be dropped too
+                let _ = tx.send(event);
+            }
+            Err(e) => warn!("Filesystem watcher reported an error: {e}"),
+        }
+    })
+    .map_err(|e| Vex2PdfError::Watch(format!("failed to start file watcher: {e}")))?;
+
+    watcher
+        .watch(&config.working_path, RecursiveMode::Recursive)
+        .map_err(|e| {
+            Vex2PdfError::Watch(format!(
+                "failed to watch {}: {e}",
+                config.working_path.display()
+            ))
+        })?;
+
+    info!(
+        "Watching {} for changes (--watch); press Ctrl-C to stop",
+        config.working_path.display()
+    );
+
+    let mut pending: HashSet<PathBuf> = HashSet::new();
+    while !cancel.is_cancelled() {
+        match rx.recv_timeout(DEBOUNCE_WINDOW) {
+            Ok(event) => pending.extend(
+                event
+                    .paths
+                    .into_iter()
+                    .filter(|path| !path.starts_with(&config.output_dir)),
+            ),
+            Err(mpsc::RecvTimeoutError::Timeout) => {
+                if !pending.is_empty() {
+                    reprocess_changed(&config, std::mem::take(&mut pending), &cancel);
+                }
+            }
+            Err(mpsc::RecvTimeoutError::Disconnected) => break,
+        }
+    }
+
+    Ok(())
+}
+
+/// Reconverts every path in `paths` that resolves to a supported BOM type, logging (and moving
+/// past) any individual file's conversion failure instead of aborting the rest of the batch.
+fn reprocess_changed(config: &Arc<Config>, paths: HashSet<PathBuf>, cancel: &CancelFlag) {
+    for path in paths {
+        let file = match BomFileIdentifier::build(path.clone()) {
+            Ok(file) if file.is_supported_type() => file,
+            Ok(_) => continue,
+            Err(e) => {
+                warn!("Skipping {}: {e}", path.display());
+                continue;
+            }
+        };
+
+        info!("Change detected: reconverting {}", path.display());
+        if let Err(e) = DefaultSingleFileProcessor.process_single_file(
+            file,
+            Arc::clone(config),
+            None,
+            None,
+            None,
+            cancel.clone(),
+        ) {
+            error!("Failed to reconvert {}: {e}", path.display());
+        }
+    }
+}
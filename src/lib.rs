@@ -6,9 +6,14 @@
 //!
 //! ## CycloneDX Compatibility
 //!
-//! This library fully supports CycloneDX schema version 1.5 and provides compatibility
-//! for version 1.6 documents that only use 1.5 fields. Documents using 1.6-specific
-//! fields may not process correctly.
+//! This library parses both CycloneDX schema versions 1.5 and 1.6, auto-detecting the
+//! document's spec version, and [`Config::validate_schema`](lib_utils::config::Config::validate_schema)
+//! can check a document against the matching bundled JSON Schema before rendering. The
+//! renderer surfaces 1.6's richer vulnerability analysis detail (state, justification,
+//! response, detail) alongside the rest of the report — but not the rest of 1.6's expanded
+//! object model (lifecycles, formulation, external reference hashes, or the `affects`
+//! structure naming impacted components/versions), which parses without error but isn't
+//! rendered yet.
 //!
 //! ## Quick Start
 //!
@@ -69,7 +74,7 @@
 //!
 //! ## Features
 //!
-//! - **Multi-format support**: JSON and XML CycloneDX documents
+//! - **Multi-format support**: JSON and XML CycloneDX documents (binary Protobuf input is recognized but not yet decoded, see [`files_proc::model::input_file_type`])
 //! - **Document types**: VEX, VDR, and SBOM/BOM
 //! - **Vulnerability analysis rendering**: Color-coded states (Exploitable, Resolved, In Triage, etc.) and response actions
 //! - **Concurrent processing**: Custom threadpool with configurable job limits (single-threaded to max parallelism)
@@ -77,6 +82,18 @@
 //! - **Structured logging**: Info/debug to stdout, warnings/errors to stderr
 //! - **Memory safe**: Unsafe code forbidden at compile-time
 //! - **CLI and library**: Use as standalone tool or integrate into your application
+//! - **Self-update**: `vex2pdf upgrade` checks the release channel and updates the binary in place
+//! - **Schema validation**: `--validate` checks each input against the bundled CycloneDX JSON Schema before conversion
+//! - **Output file permissions**: `--file-mode`/`--owner`/`--group` control the mode and ownership of generated PDFs (Unix only)
+//! - **Reproducible output**: `--reproducible` (with `SOURCE_DATE_EPOCH`) pins generated PDFs' dates and content-derived ids for byte-identical output
+//! - **Structural PDF validation**: `vex2pdf --verify <file-or-dir>` re-parses emitted PDFs with the `pdf` crate and reports page counts or structural errors
+//! - **Vulnerability filtering**: `--only-severity`/`--skip-severity`/`--skip-state` filter which vulnerabilities are rendered, with a summary line noting how many were filtered out
+//! - **Checksum manifests**: `--manifest <file>` writes a BLAKE3 digest sidecar for every generated PDF; `vex2pdf --check-manifest <file>` recomputes and reports tamper/drift
+//! - **Resumable batch runs**: `--resume` skips a source file whose output PDF is already newer than it, and records a per-file checkpoint manifest in `output_dir` so an interrupted run only reprocesses what's left
+//! - **Graceful cancellation**: Ctrl-C during a batch stops new files from being enqueued and lets in-flight PDF generations finish, instead of leaving a half-written file or blocking until the whole run drains
+//! - **Severity color coding**: rendered vulnerabilities are color-coded by severity (Critical/High/Medium/Low/None-Info) with a legend, using a palette overridable via [`lib_utils::config::Config::severity_palette`]
+//! - **CVSS v3.1 base score derivation**: when a rating carries a `CVSS:3.x/...` vector, its base score and severity are computed locally and rendered alongside a per-metric breakdown, even if the document itself omits the numeric score
+//! - **Severity-sorted vulnerability listing**: optionally (via [`lib_utils::config::Config::sort_vulns_by_severity`]) lists vulnerabilities worst-first instead of in document order
 //!
 //! ## Documentation
 //!
@@ -91,11 +108,19 @@
 //! - `pdf`: PDF generation functionality
 //!   - `font_config`: Embedded font management
 //!   - `generator`: PDF document generation with analysis rendering
+//!   - `validate`: Structural validation of generated PDFs via the `pdf` crate
+//!   - `manifest`: Checksum manifest sidecar for generated PDFs
+//!   - `snapshot`: PDF checksum snapshot testing shared by the test suite and `examples/generate_checksums`
 //! - `lib_utils`: Configuration, CLI arguments, environment variables, and concurrency
 //!   - `concurrency`: Custom threadpool and worker implementation
 //! - `files_proc`: File discovery, processing pipeline, and trait system
 //!   - `processor`: Main processing logic with trait abstractions
 //!   - `model`: File identification and processing state
+//!   - `checkpoint`: Resumable-run checkpoint manifest (`--resume`)
+//!   - `run_summary`: Machine-readable JSON run summary (`--summary-json`)
+//!   - `dry_run`: Per-file inspection report printed instead of PDF generation (`--dry-run`)
+//!   - `severity_gate`: Severity-threshold CI gate evaluated after PDF generation (`--max-allowed`/`--fail-on-severity`)
+//!   - `watch`: Keeps running and reconverts BOMs as they change on disk (`--watch`)
 //!
 
 #![forbid(unsafe_code)]
@@ -105,37 +130,61 @@ pub use crate::lib_utils::run_utils as utils;
 pub use cyclonedx_bom;
 
 pub mod files_proc {
+    pub(crate) mod adapter;
+    pub mod checkpoint;
+    pub mod dry_run;
     pub mod model {
         pub mod file_ident;
         pub mod files_pending_proc;
         pub mod input_file_type;
     }
     pub mod processor;
+    pub mod run_summary;
+    pub mod severity_gate;
+    pub mod summary;
     pub mod traits;
+    pub mod watch;
 }
 pub mod pdf {
+    pub mod advisory;
+    pub(crate) mod cvss;
     pub mod font_config;
     pub mod generator;
+    pub mod manifest;
+    pub mod merge;
+    pub(crate) mod natural_sort;
+    pub(crate) mod reproducible;
+    pub mod snapshot;
+    pub mod template;
+    pub mod validate;
 }
 
 pub mod lib_utils {
     pub mod errors;
 
+    pub mod cancel;
     pub mod cli_args;
     pub mod config;
     pub mod env_vars;
+    pub(crate) mod fs_context;
     pub mod run_utils;
+    pub mod schema_validation;
+    pub mod upgrade;
     pub(crate) mod concurrency {
         pub(crate) mod common;
         pub(crate) mod threadpool;
+        #[cfg(feature = "threads")]
         pub(crate) mod worker;
     }
 }
 
 use crate::files_proc::processor::DefaultFilesProcessor;
+use crate::files_proc::summary::ProcessSummary;
 use crate::files_proc::traits::{FileSearchProvider, MultipleFilesProcProvider};
+use crate::files_proc::watch;
 use crate::lib_utils::errors::Vex2PdfError;
 use lib_utils::config::Config;
+use std::sync::Arc;
 
 /// Processes CycloneDX VEX documents according to the provided configuration.
 ///
@@ -149,8 +198,9 @@ use lib_utils::config::Config;
 ///
 /// # Returns
 ///
-/// * `Result<(), Box<dyn Error>>` - Success (`Ok`) if processing completes without errors,
-///   or an error (`Err`) if something goes wrong
+/// * `Result<ProcessSummary, Vex2PdfError>` - `Ok` with a per-file breakdown of how many files
+///   succeeded, failed, or were skipped once the batch has run to completion (or was cancelled),
+///   or `Err` if discovery itself could not even start (e.g. an invalid working path)
 ///
 /// # Behavior
 ///
@@ -163,6 +213,10 @@ use lib_utils::config::Config;
 /// 3. Finds XML files according to the configuration
 /// 4. Processes found XML files to generate PDFs
 ///
+/// When [`Config::watch`] is `true`, this function keeps running after that initial pass and
+/// reconverts any BOM under [`Config::working_path`] as it changes on disk, returning only once
+/// `cancel`'s Ctrl-C handler trips. See [`files_proc::watch`].
+///
 /// # Fonts
 ///
 /// Liberation Sans fonts are embedded in the generated PDFs, eliminating the need
@@ -194,10 +248,21 @@ use lib_utils::config::Config;
 ///     process::exit(1);
 /// }
 /// ```
-pub fn run(config: Config) -> Result<(), Vex2PdfError> {
-    let _ = DefaultFilesProcessor::new(config).find_files()?.process();
+pub fn run(config: Config) -> Result<ProcessSummary, Vex2PdfError> {
+    let cancel = lib_utils::cancel::install();
+    // `DefaultFilesProcessor::new` consumes `config`, so a clone is kept aside up front for
+    // `--watch`'s reconversion loop below rather than trying to reconstruct it afterward.
+    let watch_config = config.watch.then(|| Arc::new(config.clone()));
+
+    let summary = DefaultFilesProcessor::new(config, cancel.clone())
+        .find_files()?
+        .process()?;
+
+    if let Some(watch_config) = watch_config {
+        watch::watch_and_reprocess(watch_config, cancel)?;
+    }
 
-    Ok(())
+    Ok(summary)
 }
 
 /// Helper to show OSS License information
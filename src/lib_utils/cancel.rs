@@ -0,0 +1,62 @@
+//! Cooperative cancellation for long batch runs.
+//!
+//! Without this, a Ctrl-C during a large batch either gets ignored until the whole thread pool
+//! drains on its own, or (if the process is killed outright) leaves a half-written PDF on disk.
+//! [`install`] instead installs a SIGINT/Ctrl-C handler that flips a shared flag, which the
+//! discovery walker and [`crate::files_proc::processor`] check at safe points between files so
+//! in-flight PDF generations finish cleanly, no new ones start, and nothing is left half-written.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// A cheaply-cloned handle to the cancellation flag shared by every thread in a batch run.
+#[derive(Clone, Default)]
+pub struct CancelFlag(Arc<AtomicBool>);
+
+impl CancelFlag {
+    /// Returns `true` once Ctrl-C has been pressed (or [`install`]'s handler otherwise fired).
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::SeqCst)
+    }
+
+    /// Trips the flag directly, bypassing [`install`]'s Ctrl-C handler. Exposed at `pub(crate)`
+    /// so tests elsewhere in the crate can simulate a cancellation without sending a real
+    /// signal to the test process.
+    pub(crate) fn cancel(&self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+}
+
+/// Installs a SIGINT/Ctrl-C handler that flips the returned [`CancelFlag`], and returns it.
+///
+/// [`ctrlc::set_handler`] can only be registered once per process; a second call (e.g. from a
+/// test that builds more than one processor) just hands back a flag that never trips, rather
+/// than failing the caller.
+pub fn install() -> CancelFlag {
+    let flag = CancelFlag::default();
+    let handler_flag = flag.clone();
+    if let Err(e) = ctrlc::set_handler(move || handler_flag.cancel()) {
+        log::warn!("Failed to install Ctrl-C handler: {e}");
+    }
+    flag
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_flag_is_not_cancelled() {
+        assert!(!CancelFlag::default().is_cancelled());
+    }
+
+    #[test]
+    fn test_clones_share_the_same_flag() {
+        let flag = CancelFlag::default();
+        let clone = flag.clone();
+
+        flag.cancel();
+
+        assert!(clone.is_cancelled());
+    }
+}
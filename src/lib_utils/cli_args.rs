@@ -3,13 +3,50 @@
 //! Whether it is environment variables or cli arguments.
 //!
 
+use super::config::{OutputFormat, ReportFormat};
 use super::env_vars::EnvVarNames;
-use clap::Parser;
-use std::path::PathBuf;
+use crate::lib_utils::fs_context;
+use crate::lib_utils::run_utils;
+use clap::{Parser, Subcommand};
+use log::warn;
+use std::path::{Path, PathBuf};
 use std::{fs, io};
+
+/// Subcommands that replace the default conversion behavior. Leaving this unset keeps the
+/// existing flat-flags invocation (`vex2pdf [OPTIONS] [FILE_OR_FOLDER]`) working unchanged.
+#[derive(Subcommand)]
+pub enum Command {
+    /// Checks the release channel for a newer version and installs it in place
+    Upgrade {
+        /// Only report the available version; don't download or install anything
+        #[arg(long)]
+        dry_run: bool,
+        /// Reinstall the latest release even if it matches the running version
+        #[arg(long)]
+        force: bool,
+    },
+    /// Re-parses a generated PDF (or every `.pdf` in a directory) and reports page counts or
+    /// structural errors, instead of trusting a bare `%PDF-` header check
+    Verify {
+        /// PDF file, or directory of PDF files, to re-parse and structurally validate
+        #[arg(value_name = "FILE_OR_FOLDER")]
+        path: PathBuf,
+    },
+    /// Re-hashes every file listed in a checksum manifest (written by `--manifest`) and
+    /// reports entries whose digest no longer matches, or whose file is missing
+    CheckManifest {
+        /// Manifest file to re-check, as written by `--manifest`
+        #[arg(value_name = "MANIFEST_FILE")]
+        path: PathBuf,
+    },
+}
+
 #[derive(Parser)]
 #[command(version,about,long_about = None)]
 pub struct CliArgs {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
     /// File to process (JSON or XML) or Folder containing said file types. Please note that
     /// this tool is designed for batch processing. So If this is not set the tool scans the current directory for all parseable files and converts them.
     /// if a folder is set the tool scans just the first level of the directory (non-recursive).
@@ -43,34 +80,285 @@ pub struct CliArgs {
     /// using the maximum available parallelism on the system which is given by [`std::thread::available_parallelism`]
     #[arg(short='j', long, env=EnvVarNames::MaxJobs.as_str())]
     pub max_jobs: Option<u8>,
+
+    /// Number of items batched into a single concurrent job before dispatch, trading per-item
+    /// channel/lock overhead against load-balancing granularity. Defaults to a tuned constant
+    /// when not set.
+    #[arg(long = "items-per-job", value_name = "N", env=EnvVarNames::ItemsPerJob.as_str())]
+    pub items_per_job: Option<usize>,
+
+    /// Glob patterns a file must match (relative to the input directory) to be processed.
+    /// May be passed multiple times or as a comma-separated list; if unset, every discovered
+    /// file is a candidate.
+    #[arg(long="include", value_delimiter=',', env=EnvVarNames::IncludePatterns.as_str())]
+    pub include_patterns: Option<Vec<String>>,
+
+    /// Glob patterns that exclude a file from processing even if it matches `--include`. May
+    /// be passed multiple times or as a comma-separated list.
+    #[arg(long="exclude", value_delimiter=',', env=EnvVarNames::ExcludePatterns.as_str())]
+    pub exclude_patterns: Option<Vec<String>>,
+
+    /// Regex matched against a candidate file's name; a match excludes the file from
+    /// processing, same as a hit on `--exclude`. Useful for filters that are awkward to
+    /// express as a glob.
+    #[arg(long="exclude-name-regex", value_name = "REGEX", env=EnvVarNames::ExcludeNameRegex.as_str())]
+    pub exclude_name_regex: Option<String>,
+
+    /// Selects the report renderer: `pdf` (default) or `html`
+    #[arg(long="format", value_name = "FORMAT", env=EnvVarNames::OutputFormat.as_str())]
+    pub output_format: Option<OutputFormat>,
+
+    /// Applies a named report profile (e.g. `pure-bom`, `full`, `minimal`, or one defined in
+    /// `.vex2pdf.toml`) before any other explicit flag/env value is layered on top
+    #[arg(long="profile", value_name = "PROFILE", env=EnvVarNames::Profile.as_str())]
+    pub profile: Option<String>,
+
+    /// Scans the working directory tree recursively instead of just its first level
+    #[arg(short='r', long="recursive", env=EnvVarNames::Recursive.as_str())]
+    pub recursive: Option<bool>,
+
+    /// When scanning recursively with an output directory set, reproduces each input file's
+    /// subdirectory (relative to the working path) under the output directory instead of
+    /// flattening every generated PDF into it
+    #[arg(long="mirror-output-structure", env=EnvVarNames::MirrorOutputStructure.as_str())]
+    pub mirror_output_structure: Option<bool>,
+
+    /// Caps the depth of a `--recursive` scan, counting the working path's own entries as depth 1
+    /// (so `1` reads no subdirectories). Unset descends without limit.
+    #[arg(long="max-depth", value_name = "DEPTH", env=EnvVarNames::MaxDepth.as_str())]
+    pub max_depth: Option<usize>,
+
+    /// When scanning recursively, follows symbolic links to directories instead of skipping
+    /// them
+    #[arg(long="follow-symlinks", env=EnvVarNames::FollowSymlinks.as_str())]
+    pub follow_symlinks: Option<bool>,
+
+    /// When scanning recursively, honors `.gitignore`/`.ignore`/`.vex2pdfignore` files
+    /// encountered while walking. On by default.
+    #[arg(long="respect-ignore-files", env=EnvVarNames::RespectIgnoreFiles.as_str())]
+    pub respect_ignore_files: Option<bool>,
+
+    /// When scanning recursively, also considers hidden files and directories (dotfiles)
+    #[arg(long="include-hidden", env=EnvVarNames::IncludeHidden.as_str())]
+    pub include_hidden: Option<bool>,
+
+    /// Validates each input against the bundled CycloneDX JSON Schema for its effective spec
+    /// version before conversion, collecting every violation instead of stopping at the first
+    #[arg(long="validate", env=EnvVarNames::ValidateSchema.as_str())]
+    pub validate_schema: Option<bool>,
+
+    /// Loads configuration from this file instead of discovering a `.vex2pdf.toml` by walking
+    /// up from the input path. Errors if the file doesn't exist or fails to parse.
+    #[arg(long="config", value_name = "CONFIG_FILE", env=EnvVarNames::ConfigFilePath.as_str())]
+    pub config_file: Option<PathBuf>,
+
+    /// Octal file mode (e.g. `640`) applied to each generated PDF after it's written.
+    /// No-op (with a warning) on non-Unix platforms.
+    #[arg(long="file-mode", value_name = "OCTAL_MODE", env=EnvVarNames::FileMode.as_str())]
+    pub file_mode: Option<String>,
+
+    /// Unix user name to `chown` each generated PDF to after it's written.
+    /// No-op (with a warning) on non-Unix platforms.
+    #[arg(long="owner", value_name = "USER", env=EnvVarNames::Owner.as_str())]
+    pub owner: Option<String>,
+
+    /// Unix group name to `chown` each generated PDF to after it's written.
+    /// No-op (with a warning) on non-Unix platforms.
+    #[arg(long="group", value_name = "GROUP", env=EnvVarNames::Group.as_str())]
+    pub group: Option<String>,
+
+    /// Pins the dynamic `CreationDate`/`ModDate`/XMP date fields of generated PDFs (to
+    /// `SOURCE_DATE_EPOCH`, or the current time if unset) and derives their trailer `/ID` and
+    /// XMP ids from the rendered content, so identical input yields byte-identical output.
+    #[arg(long="reproducible", env=EnvVarNames::Reproducible.as_str())]
+    pub reproducible: Option<bool>,
+
+    /// Severity names (e.g. `critical,high`) a vulnerability's rating must match at least one
+    /// of to be included in the report. May be passed multiple times or as a comma-separated
+    /// list; if unset, every severity is included.
+    #[arg(long="only-severity", value_delimiter=',', env=EnvVarNames::OnlySeverity.as_str())]
+    pub only_severity: Option<Vec<String>>,
+
+    /// Severity names that exclude a vulnerability from the report even if it matches
+    /// `--only-severity`. May be passed multiple times or as a comma-separated list.
+    #[arg(long="skip-severity", value_delimiter=',', env=EnvVarNames::SkipSeverity.as_str())]
+    pub skip_severity: Option<Vec<String>>,
+
+    /// Analysis state names (e.g. `not_affected,resolved`) that exclude a vulnerability from
+    /// the report. May be passed multiple times or as a comma-separated list.
+    #[arg(long="skip-state", value_delimiter=',', env=EnvVarNames::SkipState.as_str())]
+    pub skip_state: Option<Vec<String>>,
+
+    /// After conversion, writes a BLAKE3 checksum manifest listing every generated PDF, so
+    /// downstream pipelines can verify archived reports against what was actually produced
+    #[arg(long="manifest", value_name = "MANIFEST_FILE", env=EnvVarNames::Manifest.as_str())]
+    pub manifest: Option<PathBuf>,
+
+    /// Skips regenerating a PDF whose output is already newer than its source, and maintains a
+    /// checkpoint manifest in `output_dir` so a run interrupted partway through can be
+    /// re-invoked and only reprocess what's unfinished or changed
+    #[arg(long = "resume", env=EnvVarNames::Resume.as_str())]
+    pub resume: Option<bool>,
+
+    /// Combines every discovered document into a single consolidated PDF report instead of
+    /// converting each one individually. Components are deduplicated and vulnerabilities are
+    /// unioned by id across sources; conflicting analyses are resolved by severity and recorded
+    /// in a "Merge Conflicts" appendix. Incompatible with `--resume`.
+    #[arg(long = "merge", env=EnvVarNames::Merge.as_str())]
+    pub merge: Option<bool>,
+
+    /// Path to a JSON report template describing an ordered section layout (cover/metadata/
+    /// vulnerabilities/components/custom text blocks) to use instead of the built-in fixed
+    /// layout. See `vex2pdf::pdf::template`.
+    #[arg(long = "template", value_name = "TEMPLATE_FILE", env=EnvVarNames::Template.as_str())]
+    pub template: Option<PathBuf>,
+
+    /// Path to write a machine-readable JSON summary of the run (one record per input file, with
+    /// detected format, document type, spec version, output path, success/error, and severity/
+    /// analysis-state counts). See `vex2pdf::files_proc::run_summary`. Written regardless of
+    /// partial failures, so CI systems can gate on it.
+    #[arg(long = "summary-json", value_name = "SUMMARY_FILE", env=EnvVarNames::SummaryJson.as_str())]
+    pub summary_json: Option<PathBuf>,
+
+    /// Format `--summary-json` is written in: `json` for a single JSON document, or `junit` for
+    /// a JUnit `<testsuite>` document (one `<testcase>` per converted BoM). Defaults to `json`.
+    #[arg(long = "report-format", value_name = "FORMAT", env=EnvVarNames::ReportFormat.as_str())]
+    pub report_format: Option<ReportFormat>,
+
+    /// Skips PDF generation entirely; instead, for each discovered file, prints its resolved
+    /// document type, CycloneDX spec version, tool metadata, component count, and a
+    /// vulnerability breakdown by severity to stdout. Still honors `--max-jobs` for parallel
+    /// parsing, and exits non-zero if any file fails to parse. Modeled on the rust compiler's
+    /// `--print` query options
+    #[arg(long = "dry-run", env=EnvVarNames::DryRun.as_str())]
+    pub dry_run: Option<bool>,
+
+    /// Severity names (e.g. `critical,high`) that must have zero findings after processing, or
+    /// the run fails with a non-zero exit once every PDF has been generated. Shorthand for
+    /// `--max-allowed <severity>=0`. May be passed multiple times or as a comma-separated list.
+    #[arg(long = "fail-on-severity", value_delimiter=',', env=EnvVarNames::FailOnSeverity.as_str())]
+    pub fail_on_severity: Option<Vec<String>>,
+
+    /// `severity=count` pairs (e.g. `critical=0,high=2`) capping how many vulnerabilities of
+    /// each severity band are tolerated before the run fails, once every PDF has been generated.
+    /// May be passed multiple times or as a comma-separated list.
+    #[arg(long = "max-allowed", value_name = "SEVERITY=COUNT", value_delimiter=',', env=EnvVarNames::MaxAllowed.as_str())]
+    pub max_allowed: Option<Vec<String>>,
+
+    /// Counts vulnerabilities analyzed as `not_affected`/`resolved` toward `--max-allowed`'s
+    /// gate too. Off by default, so a build only breaks on findings that haven't been triaged
+    /// away as non-exploitable.
+    #[arg(long = "gate-count-analyzed", env=EnvVarNames::GateCountAnalyzed.as_str())]
+    pub gate_count_analyzed: Option<bool>,
+
+    /// Keeps running after the initial conversion and reconverts any BOM under `WorkingPath`
+    /// whenever it changes on disk, instead of exiting once the batch completes.
+    #[arg(long = "watch", env=EnvVarNames::Watch.as_str())]
+    pub watch: Option<bool>,
+}
+
+/// Parses a `severity=count` CLI token (e.g. `critical=2`) into its parts, rejecting a missing
+/// `=count` suffix or a `count` that isn't a valid `usize`.
+pub(crate) fn parse_severity_threshold(token: &str) -> Result<(String, usize), io::Error> {
+    let (severity, count) = token.split_once('=').ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("invalid `--max-allowed` entry `{token}`: expected `SEVERITY=COUNT`"),
+        )
+    })?;
+    let count = count.parse::<usize>().map_err(|e| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("invalid `--max-allowed` count in `{token}`: {e}"),
+        )
+    })?;
+
+    Ok((severity.to_string(), count))
+}
+
+/// Parses `mode_str` (e.g. `"640"`) as an octal file permission mode, rejecting anything that
+/// isn't valid octal or doesn't fit in the 12 bits POSIX permission bits use.
+pub(crate) fn parse_file_mode(mode_str: &str) -> Result<u32, io::Error> {
+    let mode = u32::from_str_radix(mode_str, 8).map_err(|e| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("invalid octal file mode `{mode_str}`: {e}"),
+        )
+    })?;
+
+    if mode > 0o7777 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("file mode `{mode_str}` is out of range for a POSIX permission mode"),
+        ));
+    }
+
+    Ok(mode)
+}
+
+/// Verifies that `path` is a directory and that we have permission to create and remove files
+/// in it.
+///
+/// Shared by [`CliArgs::validate`] (for a CLI/env-sourced `--output-dir`) and
+/// [`crate::lib_utils::config::Config::build_with_env_cli`] (for one sourced from the
+/// config-file layer), so the check applies the same way no matter where the value came from.
+pub(crate) fn validate_output_dir_permissions(path: &Path) -> Result<(), io::Error> {
+    if !path.is_dir() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("Expected a directory got {}", path.display()),
+        ));
+    }
+
+    // test if we have permissions to write
+    let tmp_file = path.join("vex2pdf_perm_test_file");
+    let res_io = fs_context::create(&tmp_file);
+
+    if let Err(e) = res_io {
+        return Err(io::Error::new(
+            io::ErrorKind::PermissionDenied,
+            e.to_string(),
+        ));
+    } else if fs::remove_file(tmp_file).is_err() {
+        return Err(io::Error::new(
+            io::ErrorKind::PermissionDenied,
+            "unable to delete permissions test file",
+        ));
+    }
+
+    Ok(())
 }
 
 impl CliArgs {
-    /// validates paths that may be passed by the user and verifies write permission
+    /// Validates paths and permission-related options that may be passed by the user so
+    /// misconfiguration fails fast before any conversion work begins.
+    ///
+    /// This checks that `--output-dir` is writable, that `--file-mode` is valid octal, and (on
+    /// Unix) that `--owner`/`--group` name an existing user/group. `--owner`/`--group` are
+    /// no-ops on non-Unix platforms, since there's no portable `chown` equivalent there, so they
+    /// only warn rather than fail validation.
     pub fn validate(&self) -> Result<(), io::Error> {
         if let Some(path) = self.output_dir.as_ref() {
-            if !path.is_dir() {
-                return Err(io::Error::new(
-                    io::ErrorKind::InvalidInput,
-                    format!("Expected a directory got {}", path.display()),
-                ));
-            } else {
-                // test if we have permissions to write
-
-                let tmp_file = path.join("vex2pdf_perm_test_file");
-                let res_io = fs::File::create(&tmp_file);
-
-                if res_io.is_err() {
-                    return Err(io::Error::new(
-                        io::ErrorKind::PermissionDenied,
-                        "Could not create a test file. possible permissions issue",
-                    ));
-                } else if res_io.is_ok() && fs::remove_file(tmp_file).is_err() {
-                    return Err(io::Error::new(
-                        io::ErrorKind::PermissionDenied,
-                        "unable to delete permissions test file",
-                    ));
-                }
+            validate_output_dir_permissions(path)?;
+        }
+
+        if let Some(mode) = self.file_mode.as_deref() {
+            parse_file_mode(mode)?;
+        }
+
+        #[cfg(unix)]
+        {
+            if let Some(name) = self.owner.as_deref() {
+                run_utils::resolve_uid(name)?;
+            }
+            if let Some(name) = self.group.as_deref() {
+                run_utils::resolve_gid(name)?;
+            }
+        }
+        #[cfg(not(unix))]
+        {
+            if self.owner.is_some() || self.group.is_some() {
+                warn!("--owner/--group have no effect on this platform; ignoring");
             }
         }
 
@@ -86,6 +374,7 @@ mod tests {
     #[test]
     fn test_validate_no_output_dir() {
         let args = CliArgs {
+            command: None,
             input: None,
             show_novulns_msg: None,
             report_title: None,
@@ -94,6 +383,38 @@ mod tests {
             show_components: None,
             output_dir: None,
             max_jobs: None,
+            items_per_job: None,
+            include_patterns: None,
+            exclude_patterns: None,
+            exclude_name_regex: None,
+            output_format: None,
+            profile: None,
+            recursive: None,
+            mirror_output_structure: None,
+            max_depth: None,
+            follow_symlinks: None,
+            respect_ignore_files: None,
+            include_hidden: None,
+            validate_schema: None,
+            config_file: None,
+            file_mode: None,
+            owner: None,
+            group: None,
+            reproducible: None,
+            only_severity: None,
+            skip_severity: None,
+            skip_state: None,
+            manifest: None,
+            resume: None,
+            merge: None,
+            template: None,
+            summary_json: None,
+            report_format: None,
+            dry_run: None,
+            fail_on_severity: None,
+            max_allowed: None,
+            gate_count_analyzed: None,
+            watch: None,
         };
         assert!(args.validate().is_ok());
     }
@@ -102,6 +423,7 @@ mod tests {
     fn test_validate_valid_directory() {
         let temp_dir = TempDir::new().unwrap();
         let args = CliArgs {
+            command: None,
             input: None,
             show_novulns_msg: None,
             report_title: None,
@@ -110,6 +432,38 @@ mod tests {
             show_components: None,
             output_dir: Some(temp_dir.path().to_path_buf()),
             max_jobs: None,
+            items_per_job: None,
+            include_patterns: None,
+            exclude_patterns: None,
+            exclude_name_regex: None,
+            output_format: None,
+            profile: None,
+            recursive: None,
+            mirror_output_structure: None,
+            max_depth: None,
+            follow_symlinks: None,
+            respect_ignore_files: None,
+            include_hidden: None,
+            validate_schema: None,
+            config_file: None,
+            file_mode: None,
+            owner: None,
+            group: None,
+            reproducible: None,
+            only_severity: None,
+            skip_severity: None,
+            skip_state: None,
+            manifest: None,
+            resume: None,
+            merge: None,
+            template: None,
+            summary_json: None,
+            report_format: None,
+            dry_run: None,
+            fail_on_severity: None,
+            max_allowed: None,
+            gate_count_analyzed: None,
+            watch: None,
         };
         assert!(args.validate().is_ok());
     }
@@ -121,6 +475,7 @@ mod tests {
         fs::write(&file, r#"{"test": "data"}"#).unwrap();
 
         let args = CliArgs {
+            command: None,
             input: None,
             show_novulns_msg: None,
             report_title: None,
@@ -129,6 +484,38 @@ mod tests {
             show_components: None,
             output_dir: Some(file),
             max_jobs: None,
+            items_per_job: None,
+            include_patterns: None,
+            exclude_patterns: None,
+            exclude_name_regex: None,
+            output_format: None,
+            profile: None,
+            recursive: None,
+            mirror_output_structure: None,
+            max_depth: None,
+            follow_symlinks: None,
+            respect_ignore_files: None,
+            include_hidden: None,
+            validate_schema: None,
+            config_file: None,
+            file_mode: None,
+            owner: None,
+            group: None,
+            reproducible: None,
+            only_severity: None,
+            skip_severity: None,
+            skip_state: None,
+            manifest: None,
+            resume: None,
+            merge: None,
+            template: None,
+            summary_json: None,
+            report_format: None,
+            dry_run: None,
+            fail_on_severity: None,
+            max_allowed: None,
+            gate_count_analyzed: None,
+            watch: None,
         };
         let err = args.validate().unwrap_err();
         assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
@@ -137,6 +524,7 @@ mod tests {
     #[test]
     fn test_validate_nonexistent_directory() {
         let args = CliArgs {
+            command: None,
             input: None,
             show_novulns_msg: None,
             report_title: None,
@@ -145,6 +533,38 @@ mod tests {
             show_components: None,
             output_dir: Some(PathBuf::from("/nonexistent/path/that/does/not/exist")),
             max_jobs: None,
+            items_per_job: None,
+            include_patterns: None,
+            exclude_patterns: None,
+            exclude_name_regex: None,
+            output_format: None,
+            profile: None,
+            recursive: None,
+            mirror_output_structure: None,
+            max_depth: None,
+            follow_symlinks: None,
+            respect_ignore_files: None,
+            include_hidden: None,
+            validate_schema: None,
+            config_file: None,
+            file_mode: None,
+            owner: None,
+            group: None,
+            reproducible: None,
+            only_severity: None,
+            skip_severity: None,
+            skip_state: None,
+            manifest: None,
+            resume: None,
+            merge: None,
+            template: None,
+            summary_json: None,
+            report_format: None,
+            dry_run: None,
+            fail_on_severity: None,
+            max_allowed: None,
+            gate_count_analyzed: None,
+            watch: None,
         };
         let err = args.validate().unwrap_err();
         assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
@@ -162,6 +582,7 @@ mod tests {
         fs::set_permissions(&readonly_dir, perms).unwrap();
 
         let args = CliArgs {
+            command: None,
             input: None,
             show_novulns_msg: None,
             report_title: None,
@@ -170,6 +591,38 @@ mod tests {
             show_components: None,
             output_dir: Some(readonly_dir.clone()),
             max_jobs: None,
+            items_per_job: None,
+            include_patterns: None,
+            exclude_patterns: None,
+            exclude_name_regex: None,
+            output_format: None,
+            profile: None,
+            recursive: None,
+            mirror_output_structure: None,
+            max_depth: None,
+            follow_symlinks: None,
+            respect_ignore_files: None,
+            include_hidden: None,
+            validate_schema: None,
+            config_file: None,
+            file_mode: None,
+            owner: None,
+            group: None,
+            reproducible: None,
+            only_severity: None,
+            skip_severity: None,
+            skip_state: None,
+            manifest: None,
+            resume: None,
+            merge: None,
+            template: None,
+            summary_json: None,
+            report_format: None,
+            dry_run: None,
+            fail_on_severity: None,
+            max_allowed: None,
+            gate_count_analyzed: None,
+            watch: None,
         };
 
         let err = args.validate().unwrap_err();
@@ -185,6 +638,7 @@ mod tests {
     fn test_validate_can_create_and_delete_test_file() {
         let temp_dir = TempDir::new().unwrap();
         let args = CliArgs {
+            command: None,
             input: None,
             show_novulns_msg: None,
             report_title: None,
@@ -193,6 +647,38 @@ mod tests {
             show_components: None,
             output_dir: Some(temp_dir.path().to_path_buf()),
             max_jobs: None,
+            items_per_job: None,
+            include_patterns: None,
+            exclude_patterns: None,
+            exclude_name_regex: None,
+            output_format: None,
+            profile: None,
+            recursive: None,
+            mirror_output_structure: None,
+            max_depth: None,
+            follow_symlinks: None,
+            respect_ignore_files: None,
+            include_hidden: None,
+            validate_schema: None,
+            config_file: None,
+            file_mode: None,
+            owner: None,
+            group: None,
+            reproducible: None,
+            only_severity: None,
+            skip_severity: None,
+            skip_state: None,
+            manifest: None,
+            resume: None,
+            merge: None,
+            template: None,
+            summary_json: None,
+            report_format: None,
+            dry_run: None,
+            fail_on_severity: None,
+            max_allowed: None,
+            gate_count_analyzed: None,
+            watch: None,
         };
 
         // This validates write + delete permissions
@@ -201,4 +687,143 @@ mod tests {
         // Verify no test file was left behind
         assert!(!temp_dir.path().join("vex2pdf_perm_test_file").exists());
     }
+
+    #[test]
+    fn test_parse_file_mode_valid_octal() {
+        assert_eq!(parse_file_mode("640").unwrap(), 0o640);
+        assert_eq!(parse_file_mode("0755").unwrap(), 0o755);
+    }
+
+    #[test]
+    fn test_parse_file_mode_rejects_non_octal() {
+        let err = parse_file_mode("abc").unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn test_parse_file_mode_rejects_out_of_range() {
+        let err = parse_file_mode("17777").unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn test_parse_severity_threshold_valid_pair() {
+        assert_eq!(
+            parse_severity_threshold("critical=2").unwrap(),
+            ("critical".to_string(), 2)
+        );
+    }
+
+    #[test]
+    fn test_parse_severity_threshold_rejects_missing_count() {
+        let err = parse_severity_threshold("critical").unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn test_parse_severity_threshold_rejects_non_numeric_count() {
+        let err = parse_severity_threshold("critical=many").unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn test_validate_rejects_bad_file_mode() {
+        let args = CliArgs {
+            command: None,
+            input: None,
+            show_novulns_msg: None,
+            report_title: None,
+            meta_name: None,
+            pure_bom_novulns: None,
+            show_components: None,
+            output_dir: None,
+            max_jobs: None,
+            items_per_job: None,
+            include_patterns: None,
+            exclude_patterns: None,
+            exclude_name_regex: None,
+            output_format: None,
+            profile: None,
+            recursive: None,
+            mirror_output_structure: None,
+            max_depth: None,
+            follow_symlinks: None,
+            respect_ignore_files: None,
+            include_hidden: None,
+            validate_schema: None,
+            config_file: None,
+            file_mode: Some("not-octal".to_string()),
+            owner: None,
+            group: None,
+            reproducible: None,
+            only_severity: None,
+            skip_severity: None,
+            skip_state: None,
+            manifest: None,
+            resume: None,
+            merge: None,
+            template: None,
+            summary_json: None,
+            report_format: None,
+            dry_run: None,
+            fail_on_severity: None,
+            max_allowed: None,
+            gate_count_analyzed: None,
+            watch: None,
+        };
+
+        let err = args.validate().unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_validate_rejects_unknown_owner() {
+        let args = CliArgs {
+            command: None,
+            input: None,
+            show_novulns_msg: None,
+            report_title: None,
+            meta_name: None,
+            pure_bom_novulns: None,
+            show_components: None,
+            output_dir: None,
+            max_jobs: None,
+            items_per_job: None,
+            include_patterns: None,
+            exclude_patterns: None,
+            exclude_name_regex: None,
+            output_format: None,
+            profile: None,
+            recursive: None,
+            mirror_output_structure: None,
+            max_depth: None,
+            follow_symlinks: None,
+            respect_ignore_files: None,
+            include_hidden: None,
+            validate_schema: None,
+            config_file: None,
+            file_mode: None,
+            owner: Some("vex2pdf_definitely_not_a_real_user".to_string()),
+            group: None,
+            reproducible: None,
+            only_severity: None,
+            skip_severity: None,
+            skip_state: None,
+            manifest: None,
+            resume: None,
+            merge: None,
+            template: None,
+            summary_json: None,
+            report_format: None,
+            dry_run: None,
+            fail_on_severity: None,
+            max_allowed: None,
+            gate_count_analyzed: None,
+            watch: None,
+        };
+
+        let err = args.validate().unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::NotFound);
+    }
 }
@@ -0,0 +1,28 @@
+//! Shared type definitions and helpers for the thread pool and its workers.
+
+use std::any::Any;
+
+/// A boxed, type-erased unit of work dispatched to a worker thread via the job channel.
+///
+/// Only meaningful with the `threads` feature enabled; with it disabled,
+/// [`super::threadpool::ThreadPool`] never spawns a worker to send one to.
+#[cfg(feature = "threads")]
+pub(crate) type Job = Box<dyn FnOnce() + Send + 'static>;
+
+/// Extracts a human-readable message from a caught panic payload, falling back to a generic
+/// description for payloads that are neither `&str` nor `String` (the two types `panic!` and
+/// friends actually produce).
+///
+/// Lives here, rather than on [`super::worker::Worker`] or inlined at each call site, so both
+/// `Worker` (gated by the `threads` feature, catching panics that unwind out of a whole job) and
+/// [`crate::files_proc::processor`] (always built, catching panics per file within a job) format
+/// a caught payload the same way.
+pub(crate) fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        (*message).to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "job panicked with a non-string payload".to_string()
+    }
+}
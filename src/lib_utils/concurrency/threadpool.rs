@@ -1,24 +1,58 @@
 //! Thread pool implementation for concurrent PDF generation.
 //!
+//! This is the pool [`crate::files_proc::processor::ProcessorReady::process`] actually dispatches
+//! onto (behind the `concurrency` feature); it isn't a standalone utility kept around for its own
+//! sake, so toggling `threads` below changes real, observable dispatch behavior rather than
+//! shuffling dead code.
+//!
 //! Supports single-threaded mode (`max_jobs=1`) for debugging and sequential processing,
 //! or multi-threaded mode for parallel processing of multiple BOM files.
 //!
 //! When `max_jobs` is 0 or not set, the pool uses all available CPU cores for maximum parallelism.
-
+//!
+//! Multithreading itself is behind the `threads` feature (default-on). With it disabled,
+//! [`Worker`], the job channel, and `std::thread` usage all compile out entirely: `ThreadPool`
+//! becomes a thin shim that always runs jobs inline on the calling thread. `new`, `execute`,
+//! and `join` keep the same signatures either way, so callers don't need to know which build
+//! they're linked against — only [`Self::is_single_threaded`]'s answer changes, to honestly
+//! reflect that there's no concurrency to report. [`Display`] prints the same message in both
+//! builds.
+
+#[cfg(feature = "threads")]
 use crate::lib_utils::concurrency::common::Job;
+#[cfg(feature = "threads")]
 use crate::lib_utils::concurrency::worker::Worker;
 use crate::lib_utils::errors::Vex2PdfError;
+#[cfg(feature = "threads")]
 use log::debug;
+use log::warn;
 use std::fmt::{Display, Formatter};
+#[cfg(feature = "threads")]
 use std::sync::mpsc::Sender;
-use std::sync::{mpsc, Arc, Mutex};
+use std::sync::Arc;
+#[cfg(feature = "threads")]
+use std::sync::{mpsc, Mutex};
+#[cfg(feature = "threads")]
 use std::thread;
 
+/// Default number of items [`crate::files_proc::processor::ProcessorReady::process`] batches
+/// into a single job before dispatching it to the pool. Tuned to keep the
+/// channel-send-plus-mutex-lock overhead of dispatch well below the cost of processing a single
+/// small BOM, without growing a chunk so large that one slow worker stalls the batch.
+///
+/// Kept regardless of the `threads` feature (see the module docs): it's irrelevant without
+/// threads, where batches always run sequentially one item at a time, but
+/// [`ThreadPool::items_per_job`]/[`ThreadPool::with_items_per_job`] stay available either way.
+pub(crate) const DEFAULT_ITEMS_PER_JOB: usize = 32;
+
 // TODO split this off into its own crate
 pub(crate) struct ThreadPool {
+    #[cfg(feature = "threads")]
     workers: Vec<Worker>,
+    #[cfg(feature = "threads")]
     sender: Option<Sender<Job>>,
     num_threads: u8,
+    items_per_job: usize,
 }
 
 impl ThreadPool {
@@ -26,6 +60,10 @@ impl ThreadPool {
     /// - pool_size is `0`: runs in multithreaded default mode using maximum parallelism
     /// - pool_size is `1`: runs in single-threaded mode (all jobs are run in the main thread)
     /// - pool_size is `1<N<=255`: runs in multithreaded mode with `N` jobs
+    ///
+    /// The pool's chunk size starts at [`DEFAULT_ITEMS_PER_JOB`]; override it with
+    /// [`Self::with_items_per_job`].
+    #[cfg(feature = "threads")]
     pub(crate) fn new(pool_size: u8) -> Self {
         if pool_size == 0 {
             Self::default()
@@ -34,6 +72,7 @@ impl ThreadPool {
                 workers: Vec::new(),
                 sender: None,
                 num_threads: pool_size,
+                items_per_job: DEFAULT_ITEMS_PER_JOB,
             }
         } else {
             let (sender, receiver) = mpsc::channel::<Job>();
@@ -50,15 +89,44 @@ impl ThreadPool {
                 workers,
                 sender: Some(sender),
                 num_threads: pool_size,
+                items_per_job: DEFAULT_ITEMS_PER_JOB,
             }
         }
     }
 
+    /// Without the `threads` feature there are no worker threads to spawn, so `pool_size` is
+    /// kept only for informational purposes and every job simply
+    /// runs inline. `0` is treated the same as `1` (there's no parallelism to default to).
+    #[cfg(not(feature = "threads"))]
+    pub(crate) fn new(pool_size: u8) -> Self {
+        Self {
+            num_threads: pool_size.max(1),
+            items_per_job: DEFAULT_ITEMS_PER_JOB,
+        }
+    }
+
+    /// Overrides the chunk size [`crate::files_proc::processor::ProcessorReady::process`] groups
+    /// incoming items into before dispatching each group as a single job. Exposed as a builder
+    /// method (rather than a `new` parameter) so benchmarks can sweep it independently of pool
+    /// size. Clamped to at least 1.
+    pub(crate) fn with_items_per_job(mut self, items_per_job: usize) -> Self {
+        self.items_per_job = items_per_job.max(1);
+        self
+    }
+
+    /// The chunk size [`crate::files_proc::processor::ProcessorReady::process`] currently groups
+    /// items into.
+    pub(crate) fn items_per_job(&self) -> usize {
+        self.items_per_job
+    }
+
     /// Executes a job on the thread pool.
     ///
     /// # Behavior
-    /// - **Single-threaded mode** (`max_jobs=1`): Job executes synchronously in the calling thread
+    /// - **Single-threaded mode** (`max_jobs=1`, or any build without the `threads` feature):
+    ///   Job executes synchronously in the calling thread
     /// - **Multi-threaded mode**: Job is queued and executed asynchronously by worker threads
+    #[cfg(feature = "threads")]
     pub fn execute<F>(&self, f: F) -> Result<(), Vex2PdfError>
     where
         F: FnOnce() + Send + 'static,
@@ -75,37 +143,103 @@ impl ThreadPool {
         }
     }
 
+    /// Without the `threads` feature, [`Self::is_single_threaded`] is always `true`, so every job
+    /// just runs inline on the calling thread.
+    #[cfg(not(feature = "threads"))]
+    pub fn execute<F>(&self, f: F) -> Result<(), Vex2PdfError>
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        f();
+        Ok(())
+    }
+
     /// Returns `true` if running in single-threaded mode.
     ///
-    /// Single-threaded mode is active when `max_jobs=1`, resulting in:
+    /// Single-threaded mode is active when `max_jobs=1` (or, without the `threads` feature,
+    /// always), resulting in:
     /// - No worker threads spawned
     /// - No message passing channel created
     /// - All jobs executed synchronously in the main thread
+    #[cfg(feature = "threads")]
     pub fn is_single_threaded(&self) -> bool {
         self.sender.is_none() && self.workers.is_empty()
     }
-}
 
-impl Drop for ThreadPool {
-    fn drop(&mut self) {
+    /// Always `true`: without the `threads` feature there's no worker thread or channel to have
+    /// set up in the first place.
+    #[cfg(not(feature = "threads"))]
+    pub fn is_single_threaded(&self) -> bool {
+        true
+    }
+
+    /// Closes the job channel and waits for every worker to drain its queue and exit,
+    /// aggregating every [`Vex2PdfError::WorkerPanicked`] any of them caught along the way.
+    ///
+    /// Called from [`Drop`] so teardown always happens, but exposed separately so callers that
+    /// want to surface worker failures as part of a batch outcome (rather than just a teardown
+    /// log line) can call it explicitly before the pool goes out of scope.
+    #[cfg(feature = "threads")]
+    pub(crate) fn join(&mut self) -> Result<(), Vec<Vex2PdfError>> {
         // drop the sender first which causes receivers to error out gracefully
         drop(self.sender.take());
         // now workers will error out thus unblocking their recv calls
 
+        let mut errors = Vec::new();
         for worker in &mut self.workers {
             debug!("Shutting down worker {}", worker.id);
-            worker.thread.take().unwrap().join().unwrap();
+            let Some(thread) = worker.thread.take() else {
+                continue;
+            };
+            match thread.join() {
+                Ok(panics) => errors.extend(panics),
+                Err(_) => errors.push(Vex2PdfError::WorkerPanicked {
+                    worker_id: worker.id,
+                    message: "worker thread terminated unexpectedly outside of a job".to_string(),
+                }),
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Without the `threads` feature there are no worker threads to drain, so there's nothing to
+    /// do and nothing that can have panicked out-of-band.
+    #[cfg(not(feature = "threads"))]
+    pub(crate) fn join(&mut self) -> Result<(), Vec<Vex2PdfError>> {
+        Ok(())
+    }
+}
+
+impl Drop for ThreadPool {
+    fn drop(&mut self) {
+        if let Err(errors) = self.join() {
+            for error in errors {
+                warn!("{error}");
+            }
         }
     }
 }
 
 impl Default for ThreadPool {
+    #[cfg(feature = "threads")]
     fn default() -> Self {
         let max_threads = thread::available_parallelism().map(|e| e.get()).expect("Unable to find any threads to run with. Possible system-side restrictions or limitations");
 
         // saturate to u8::MAX if number of threads is larger than what u8 can hold
         ThreadPool::new(u8::try_from(max_threads).unwrap_or(u8::MAX))
     }
+
+    /// Without the `threads` feature there's no CPU parallelism to query, so this always maps to
+    /// the single-threaded path rather than calling `available_parallelism`.
+    #[cfg(not(feature = "threads"))]
+    fn default() -> Self {
+        ThreadPool::new(1)
+    }
 }
 
 impl Display for ThreadPool {
@@ -125,7 +259,7 @@ impl Display for ThreadPool {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::sync::{Arc, Mutex};
+    use std::sync::Mutex;
     use std::time::Duration;
 
     #[test]
@@ -133,21 +267,28 @@ mod tests {
         // Test pool with size 0 (default - max parallelism)
         let pool_default = ThreadPool::new(0);
         assert!(pool_default.num_threads > 0);
+        #[cfg(feature = "threads")]
         assert!(!pool_default.is_single_threaded());
 
         // Test pool with size 1 (single-threaded)
         let pool_single = ThreadPool::new(1);
         assert_eq!(pool_single.num_threads, 1);
         assert!(pool_single.is_single_threaded());
-        assert!(pool_single.workers.is_empty());
-        assert!(pool_single.sender.is_none());
+        #[cfg(feature = "threads")]
+        {
+            assert!(pool_single.workers.is_empty());
+            assert!(pool_single.sender.is_none());
+        }
 
         // Test pool with size 4 (multi-threaded)
         let pool_multi = ThreadPool::new(4);
         assert_eq!(pool_multi.num_threads, 4);
-        assert!(!pool_multi.is_single_threaded());
-        assert_eq!(pool_multi.workers.len(), 4);
-        assert!(pool_multi.sender.is_some());
+        #[cfg(feature = "threads")]
+        {
+            assert!(!pool_multi.is_single_threaded());
+            assert_eq!(pool_multi.workers.len(), 4);
+            assert!(pool_multi.sender.is_some());
+        }
     }
 
     #[test]
@@ -168,6 +309,7 @@ mod tests {
         assert_eq!(value, 1);
     }
 
+    #[cfg(feature = "threads")]
     #[test]
     fn test_multi_threaded_execution() {
         let pool = ThreadPool::new(2);
@@ -195,6 +337,15 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_with_items_per_job_overrides_default() {
+        let pool = ThreadPool::new(2);
+        assert_eq!(pool.items_per_job(), DEFAULT_ITEMS_PER_JOB);
+
+        let pool = pool.with_items_per_job(8);
+        assert_eq!(pool.items_per_job(), 8);
+    }
+
     #[test]
     fn test_get_num_threads() {
         let pool1 = ThreadPool::new(1);
@@ -212,13 +363,24 @@ mod tests {
         let pool_single = ThreadPool::new(1);
         assert!(pool_single.is_single_threaded());
 
-        let pool_multi = ThreadPool::new(2);
-        assert!(!pool_multi.is_single_threaded());
+        #[cfg(feature = "threads")]
+        {
+            let pool_multi = ThreadPool::new(2);
+            assert!(!pool_multi.is_single_threaded());
 
-        let pool_default = ThreadPool::default();
-        assert!(!pool_default.is_single_threaded());
+            let pool_default = ThreadPool::default();
+            assert!(!pool_default.is_single_threaded());
+        }
+        #[cfg(not(feature = "threads"))]
+        {
+            // Without the `threads` feature, every pool reports single-threaded honestly,
+            // regardless of the size it was asked for.
+            assert!(ThreadPool::new(2).is_single_threaded());
+            assert!(ThreadPool::default().is_single_threaded());
+        }
     }
 
+    #[cfg(feature = "threads")]
     #[test]
     fn test_pool_graceful_shutdown() {
         let pool = ThreadPool::new(3);
@@ -240,4 +402,27 @@ mod tests {
         // All jobs should have completed
         assert_eq!(*completed.lock().unwrap(), 10);
     }
+
+    #[cfg(feature = "threads")]
+    #[test]
+    fn test_join_reports_panicking_job_without_losing_others() {
+        let mut pool = ThreadPool::new(2);
+        let completed = Arc::new(Mutex::new(0));
+
+        pool.execute(|| panic!("boom"))
+            .expect("Failed to execute job");
+
+        let completed_clone = Arc::clone(&completed);
+        pool.execute(move || {
+            *completed_clone.lock().unwrap() += 1;
+        })
+        .expect("Failed to execute job");
+
+        let result = pool.join();
+
+        assert_eq!(*completed.lock().unwrap(), 1);
+        let errors = result.expect_err("expected the panicking job to be reported");
+        assert_eq!(errors.len(), 1);
+        assert!(matches!(errors[0], Vex2PdfError::WorkerPanicked { .. }));
+    }
 }
@@ -1,35 +1,55 @@
-//! Worker model for concurrent jobs handling
-use crate::lib_utils::concurrency::common::Job;
-use log::debug;
+//! Worker model for concurrent jobs handling.
+//!
+//! Only compiled in with the `threads` feature (default-on); with it disabled,
+//! [`super::threadpool::ThreadPool`] is an always-single-threaded shim that never spawns one of
+//! these.
+use crate::lib_utils::concurrency::common::{panic_message, Job};
+use crate::lib_utils::errors::Vex2PdfError;
+use log::{debug, error};
+use std::panic::{self, AssertUnwindSafe};
 use std::sync::mpsc::Receiver;
 use std::sync::{Arc, Mutex};
 use std::thread::JoinHandle;
 
 pub(crate) struct Worker {
     pub(super) id: u8,
-    pub(super) thread: Option<JoinHandle<()>>,
+    pub(super) thread: Option<JoinHandle<Vec<Vex2PdfError>>>,
 }
 
 impl Worker {
     /// Creates a new worker that spawns a thread to process jobs from the shared receiver.
     ///
-    /// The worker continuously receives jobs from the channel until the sender is dropped,
-    /// at which point it exits gracefully.
+    /// The worker continuously receives jobs from the channel until the sender is dropped, at
+    /// which point it exits gracefully and returns every [`Vex2PdfError::WorkerPanicked`] it
+    /// caught over its lifetime, so [`super::threadpool::ThreadPool::join`] can report them
+    /// instead of letting a panicking job unwind into `Drop` and abort the process.
     pub(crate) fn new(id: u8, receiver: Arc<Mutex<Receiver<Job>>>) -> Self {
-        let thread = std::thread::spawn(move || loop {
-            // FIXME modify this to handle errors and push them to the joinhandle
-            let job_msg = receiver.lock().unwrap().recv();
-
-            match job_msg {
-                Ok(job) => {
-                    debug!("Worker {id} got a job; executing.");
-                    job();
-                }
-                Err(_) => {
-                    debug!("Worker {id} disconnected; shutting down;");
-                    break;
+        let thread = std::thread::spawn(move || {
+            let mut panics = Vec::new();
+
+            loop {
+                let job_msg = receiver.lock().unwrap().recv();
+
+                match job_msg {
+                    Ok(job) => {
+                        debug!("Worker {id} got a job; executing.");
+                        if let Err(payload) = panic::catch_unwind(AssertUnwindSafe(job)) {
+                            let message = panic_message(&payload);
+                            error!("Worker {id} job panicked: {message}");
+                            panics.push(Vex2PdfError::WorkerPanicked {
+                                worker_id: id,
+                                message,
+                            });
+                        }
+                    }
+                    Err(_) => {
+                        debug!("Worker {id} disconnected; shutting down;");
+                        break;
+                    }
                 }
             }
+
+            panics
         });
 
         Self {
@@ -57,7 +77,7 @@ mod tests {
 
         // Clean up
         drop(sender);
-        worker.thread.unwrap().join().unwrap();
+        assert!(worker.thread.unwrap().join().unwrap().is_empty());
     }
 
     #[test]
@@ -85,7 +105,7 @@ mod tests {
 
         // Clean up
         drop(sender);
-        worker.thread.unwrap().join().unwrap();
+        assert!(worker.thread.unwrap().join().unwrap().is_empty());
     }
 
     #[test]
@@ -102,4 +122,40 @@ mod tests {
         let result = worker.thread.unwrap().join();
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn test_worker_survives_panicking_job() {
+        let (sender, receiver) = mpsc::channel::<Job>();
+        let receiver = Arc::new(Mutex::new(receiver));
+
+        let worker = Worker::new(4, Arc::clone(&receiver));
+
+        sender
+            .send(Box::new(|| panic!("boom")))
+            .expect("failed to send job");
+
+        let executed = Arc::new(Mutex::new(false));
+        let executed_clone = Arc::clone(&executed);
+        sender
+            .send(Box::new(move || {
+                *executed_clone.lock().unwrap() = true;
+            }))
+            .expect("failed to send job");
+
+        drop(sender);
+
+        let panics = worker.thread.unwrap().join().unwrap();
+
+        // The panicking job is caught and reported rather than killing the worker thread, so
+        // the job sent right after it still ran.
+        assert!(*executed.lock().unwrap());
+        assert_eq!(panics.len(), 1);
+        match &panics[0] {
+            Vex2PdfError::WorkerPanicked { worker_id, message } => {
+                assert_eq!(*worker_id, 4);
+                assert_eq!(message, "boom");
+            }
+            other => panic!("expected WorkerPanicked, got {other:?}"),
+        }
+    }
 }
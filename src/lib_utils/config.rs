@@ -39,7 +39,7 @@
 //! Create the struct directly for full control:
 //!
 //! ```rust
-//! use vex2pdf::lib_utils::config::Config;
+//! use vex2pdf::lib_utils::config::{Config, OutputFormat, ReportFormat, SeverityPalette, Theme};
 //! use std::path::PathBuf;
 //!
 //! let config = Config {
@@ -52,6 +52,46 @@
 //!     report_title: Some("Custom Report".to_string()),
 //!     pdf_meta_name: Some("My PDF".to_string()),
 //!     max_jobs: Some(4),
+//!     items_per_job: None,
+//!     include_patterns: Vec::new(),
+//!     exclude_patterns: Vec::new(),
+//!     output_format: OutputFormat::Pdf,
+//!     profile: None,
+//!     recursive: false,
+//!     mirror_output_structure: false,
+//!     max_depth: None,
+//!     follow_symlinks: false,
+//!     respect_ignore_files: true,
+//!     include_hidden: false,
+//!     validate_schema: false,
+//!     file_mode: None,
+//!     owner: None,
+//!     group: None,
+//!     reproducible: false,
+//!     source_date_epoch: None,
+//!     only_severity: Vec::new(),
+//!     skip_severity: Vec::new(),
+//!     skip_state: Vec::new(),
+//!     manifest_path: None,
+//!     resume: false,
+//!     exclude_name_regex: None,
+//!     severity_palette: SeverityPalette::default(),
+//!     sort_vulns_by_severity: false,
+//!     advisory_db_path: None,
+//!     enrich_with_advisory_db: false,
+//!     natural_sort_lists: false,
+//!     theme: Theme::Light,
+//!     show_component_licenses: true,
+//!     show_component_hashes: true,
+//!     show_severity_summary: true,
+//!     merge: false,
+//!     template: None,
+//!     summary_json: None,
+//!     report_format: ReportFormat::Json,
+//!     dry_run: false,
+//!     max_allowed: std::collections::HashMap::new(),
+//!     gate_count_analyzed: false,
+//!     watch: false,
 //! };
 //! ```
 //!
@@ -83,6 +123,44 @@
 //! - [`report_title()`](Config::report_title) - Custom report title
 //! - [`pdf_meta_name()`](Config::pdf_meta_name) - Custom PDF metadata
 //! - [`max_jobs()`](Config::max_jobs) - Set concurrent job limit
+//! - [`items_per_job()`](Config::items_per_job) - Set the in-pool job batching chunk size
+//! - [`output_format()`](Config::output_format) - Select the report renderer (PDF/HTML)
+//! - [`apply_profile()`](Config::apply_profile) - Apply a named report profile preset
+//! - [`recursive()`](Config::recursive) - Scan the working directory tree recursively
+//! - [`mirror_output_structure()`](Config::mirror_output_structure) - Reproduce the source subdirectory tree under the output directory
+//! - [`max_depth()`](Config::max_depth) - Cap how many directory levels a recursive scan descends
+//! - [`follow_symlinks()`](Config::follow_symlinks) - Follow symlinked directories during a recursive scan
+//! - [`respect_ignore_files()`](Config::respect_ignore_files) - Honor `.gitignore`/`.ignore`/`.vex2pdfignore` during a recursive scan
+//! - [`include_hidden()`](Config::include_hidden) - Consider hidden files/directories during a recursive scan
+//! - [`validate_schema()`](Config::validate_schema) - Validate inputs against the bundled CycloneDX JSON Schema
+//! - [`file_mode()`](Config::file_mode) - Set the POSIX permission mode applied to generated PDFs
+//! - [`owner()`](Config::owner) / [`group()`](Config::group) - `chown` generated PDFs (Unix only)
+//! - [`reproducible()`](Config::reproducible) - Pin generated PDFs' dates/ids for byte-identical output
+//! - [`source_date_epoch()`](Config::source_date_epoch) - The fixed instant used when `reproducible` is on
+//! - [`only_severity()`](Config::only_severity) - Include only vulnerabilities matching a given severity
+//! - [`skip_severity()`](Config::skip_severity) - Exclude vulnerabilities matching a given severity
+//! - [`skip_state()`](Config::skip_state) - Exclude vulnerabilities matching a given analysis state
+//! - [`manifest_path()`](Config::manifest_path) - Write a BLAKE3 checksum manifest for generated PDFs
+//! - [`resume()`](Config::resume) - Skip regenerating up-to-date PDFs and checkpoint progress for resumable runs
+//! - [`exclude_name_regex()`](Config::exclude_name_regex) - Exclude files whose name matches a regex
+//! - [`severity_palette()`](Config::severity_palette) - Override the severity→color mapping used to color-code rendered vulnerabilities
+//! - [`sort_vulns_by_severity()`](Config::sort_vulns_by_severity) - List vulnerabilities worst-first instead of in document order
+//! - [`advisory_db_path()`](Config::advisory_db_path) - Path to a local RustSec-style advisory database
+//! - [`enrich_with_advisory_db()`](Config::enrich_with_advisory_db) - Enable advisory database enrichment of rendered vulnerabilities
+//! - [`natural_sort_lists()`](Config::natural_sort_lists) - Order components and tools/services with version-aware natural sorting
+//! - [`theme()`](Config::theme) - Select the color scheme used for structural (non-severity) report text
+//! - [`show_component_licenses()`](Config::show_component_licenses) - Show/hide each component's license list
+//! - [`show_component_hashes()`](Config::show_component_hashes) - Show/hide each component's hash list
+//! - [`show_severity_summary()`](Config::show_severity_summary) - Show/hide the severity count summary above the detailed vulnerability list
+//! - [`merge()`](Config::merge) - Combine every discovered document into a single consolidated PDF report
+//! - [`template()`](Config::template) - Use a JSON report template to control the generated section layout
+//! - [`summary_json()`](Config::summary_json) - Write a machine-readable JSON summary of the run
+//! - [`report_format()`](Config::report_format) - Write the run summary as JSON or JUnit
+//! - [`dry_run()`](Config::dry_run) - Skip PDF generation and print a per-file inspection report instead
+//! - [`max_allowed()`](Config::max_allowed) - Cap how many vulnerabilities of a severity band are tolerated before the run fails
+//! - [`fail_on_severity()`](Config::fail_on_severity) - Shorthand for `max_allowed(severity, 0)`
+//! - [`gate_count_analyzed()`](Config::gate_count_analyzed) - Count `not_affected`/`resolved` findings toward the gate too
+//! - [`watch()`](Config::watch) - Keep running after the initial conversion and reconvert BOMs as they change on disk
 //!
 //! # Examples
 //!
@@ -128,14 +206,22 @@ use super::env_vars::EnvVarNames;
 #[cfg(feature = "cli")]
 use super::run_utils::get_version_info;
 #[cfg(feature = "cli")]
-use crate::lib_utils::cli_args::CliArgs;
+use crate::lib_utils::cli_args::{
+    parse_file_mode, parse_severity_threshold, validate_output_dir_permissions, CliArgs,
+};
 #[cfg(feature = "cli")]
 use crate::lib_utils::errors::Vex2PdfError;
 #[cfg(feature = "cli")]
+use crate::lib_utils::fs_context;
+#[cfg(feature = "cli")]
 use clap::Parser;
 #[cfg(feature = "cli")]
 use log::{info, warn};
 
+/// Cheaply cloned so [`crate::run`] can hand [`crate::files_proc::watch`] its own owned copy
+/// to hold onto for the life of the watch loop, alongside the one consumed by the initial
+/// conversion pass.
+#[derive(Clone)]
 pub struct Config {
     pub working_path: PathBuf,
     pub output_dir: PathBuf,
@@ -146,6 +232,568 @@ pub struct Config {
     pub report_title: Option<String>,
     pub pdf_meta_name: Option<String>,
     pub max_jobs: Option<u8>,
+    /// Number of items [`crate::files_proc::processor::ProcessorReady::process`] batches into a
+    /// single job before dispatching it to the pool, trading off channel/lock contention (fewer,
+    /// larger jobs) against load-balancing granularity (many small jobs spread more evenly
+    /// across workers). `None` (the default) uses
+    /// [`crate::lib_utils::concurrency::threadpool::DEFAULT_ITEMS_PER_JOB`].
+    pub items_per_job: Option<usize>,
+    /// Glob patterns (matched against paths relative to `working_path`) a file must match to
+    /// be considered for processing. An empty vector (the default) includes everything.
+    pub include_patterns: Vec<String>,
+    /// Glob patterns (matched against paths relative to `working_path`) that exclude a file
+    /// from processing even if it matches `include_patterns`. Excludes are applied after
+    /// includes, so a file must pass both checks to be processed.
+    pub exclude_patterns: Vec<String>,
+    /// Selects which renderer produces the report. Defaults to [`OutputFormat::Pdf`].
+    pub output_format: OutputFormat,
+    /// Name of the report profile applied by [`Config::apply_profile`], if any. This is
+    /// informational once the profile's field values have been applied; it's kept around so
+    /// the CLI path can report which profile (built-in or config-file-defined) was in effect.
+    pub profile: Option<String>,
+    /// When `true` and `working_path` is a directory, scans the entire directory tree instead
+    /// of just its first level.
+    pub recursive: bool,
+    /// When `true`, and [`Self::output_dir`] is set, reproduces each input file's subdirectory
+    /// (relative to `working_path`) under `output_dir` instead of flattening every generated
+    /// PDF directly into it. Has no visible effect without [`Self::recursive`], since a
+    /// non-recursive scan only ever sees `working_path`'s own top-level files.
+    pub mirror_output_structure: bool,
+    /// Caps the depth of a [`Self::recursive`] scan, counting `working_path`'s own entries as
+    /// depth 1 (so `Some(1)` reads no subdirectories). `None` (the default) descends without limit.
+    pub max_depth: Option<usize>,
+    /// When `true`, a [`Self::recursive`] scan follows symbolic links to directories instead of
+    /// skipping them. Off by default, since a symlink cycle would otherwise hang the walk.
+    pub follow_symlinks: bool,
+    /// When `true` (the default), a [`Self::recursive`] scan honors `.gitignore`/`.ignore`/
+    /// `.vex2pdfignore` files encountered while walking, same as the `ignore` crate's defaults.
+    pub respect_ignore_files: bool,
+    /// When `true`, a [`Self::recursive`] scan also descends into and considers hidden files
+    /// and directories (dotfiles). Off by default.
+    pub include_hidden: bool,
+    /// When `true`, each input is validated against the bundled CycloneDX JSON Schema for its
+    /// effective spec version before conversion; violations are reported and that file's
+    /// conversion is aborted.
+    pub validate_schema: bool,
+    /// Octal file mode (e.g. `0o640`) applied to each generated PDF after it's written.
+    /// `None` leaves the mode as created. No-op (with a warning) on non-Unix platforms.
+    pub file_mode: Option<u32>,
+    /// Unix user name to `chown` each generated PDF to after it's written. No-op (with a
+    /// warning) on non-Unix platforms.
+    pub owner: Option<String>,
+    /// Unix group name to `chown` each generated PDF to after it's written. No-op (with a
+    /// warning) on non-Unix platforms.
+    pub group: Option<String>,
+    /// When `true`, pins the dynamic `CreationDate`/`ModDate`/XMP date fields of generated PDFs
+    /// and derives their trailer `/ID` and XMP ids from the rendered content, so identical
+    /// input yields byte-identical output. See [`Self::source_date_epoch`] for the pinned date.
+    pub reproducible: bool,
+    /// The fixed instant (Unix timestamp) used for generated PDFs' dates when
+    /// [`Self::reproducible`] is on. `None` falls back to the current time, which still makes
+    /// the `/ID`/XMP ids content-derived but not the dates themselves reproducible across runs.
+    pub source_date_epoch: Option<i64>,
+    /// Severity names (e.g. `"critical"`, `"high"`) a vulnerability's rating must match at
+    /// least one of to be included in the report. Matched case- and punctuation-insensitively
+    /// against each rating's severity. An empty vector (the default) includes every severity.
+    pub only_severity: Vec<String>,
+    /// Severity names that exclude a vulnerability from the report if any of its ratings
+    /// match, applied after [`Self::only_severity`].
+    pub skip_severity: Vec<String>,
+    /// Analysis state names (e.g. `"not_affected"`, `"resolved"`) that exclude a vulnerability
+    /// from the report if its `analysis.state` matches one, matched case- and
+    /// punctuation-insensitively. A vulnerability with no analysis is treated as state `"none"`.
+    pub skip_state: Vec<String>,
+    /// When set, writes a BLAKE3 checksum manifest listing every PDF generated by this run to
+    /// this path after conversion completes, so downstream pipelines can verify archived
+    /// reports against what was actually produced. See [`crate::pdf::manifest`].
+    pub manifest_path: Option<PathBuf>,
+    /// When `true`, skips regenerating a PDF whose existing output is already newer than its
+    /// source file, and maintains a checkpoint manifest in `output_dir` so a run interrupted
+    /// partway through can be re-invoked and only reprocess what's unfinished or changed. See
+    /// [`crate::files_proc::checkpoint`].
+    pub resume: bool,
+    /// Regex matched against a candidate file's name (not its full relative path); a match
+    /// excludes the file from processing, same as a hit on `exclude_patterns`. Layered on top
+    /// of the glob-based `include_patterns`/`exclude_patterns` for filters that are awkward to
+    /// express as a glob, e.g. `-draft(-v\d+)?\.`.
+    pub exclude_name_regex: Option<String>,
+    /// The severity→color mapping [`crate::pdf::generator::PdfGenerator`] uses to color-code
+    /// the severity text and ID badge of each rendered vulnerability, and its legend. Defaults
+    /// to a conventional red→green ramp; see [`SeverityPalette::default`].
+    pub severity_palette: SeverityPalette,
+    /// When `true`, vulnerabilities are listed worst-first (Critical → None) using the highest
+    /// rating severity per vulnerability, breaking ties with the higher CVSS v3.1 base score.
+    /// Defaults to `false`, which preserves the document's original order.
+    pub sort_vulns_by_severity: bool,
+    /// Path to a local checkout of a RustSec-style advisory database (one `.toml` file per
+    /// advisory). Loaded once by [`crate::pdf::generator::PdfGenerator`] when
+    /// `enrich_with_advisory_db` is `true`, to render remediation guidance under matching
+    /// vulnerabilities. Enrichment is skipped cleanly when this is `None`.
+    pub advisory_db_path: Option<PathBuf>,
+    /// When `true` (and [`Self::advisory_db_path`] is set), cross-references each vulnerability
+    /// and affected component against the advisory database and renders patched/unaffected
+    /// version guidance alongside the raw VEX entry. Defaults to `false`.
+    pub enrich_with_advisory_db: bool,
+    /// When `true`, components (by name then version) and the tools/services list (by name) are
+    /// ordered with a version-aware natural comparator instead of raw BOM iteration order, so
+    /// e.g. `v1.9.0` sorts before `v1.10.0`. Defaults to `false`, which preserves the document's
+    /// original order.
+    pub natural_sort_lists: bool,
+    /// The color scheme applied to structural (non-severity) report text. Defaults to
+    /// [`Theme::Light`]. See [`Theme`] and [`SeverityPalette`] for the severity-band colors,
+    /// which this setting doesn't affect.
+    pub theme: Theme,
+    /// When `true` (the default), each rendered component's license list (if the BoM states
+    /// one) is shown. Set to `false` to keep large BOMs with many/verbose licenses readable.
+    pub show_component_licenses: bool,
+    /// When `true` (the default), each rendered component's hash list (if the BoM states one)
+    /// is shown. Set to `false` to keep large BOMs with many hash algorithms readable.
+    pub show_component_hashes: bool,
+    /// When `true` (the default), a colored count-by-severity row (Critical/High/Medium/Low/
+    /// None/Unknown) is shown above the detailed vulnerability list, giving readers the risk
+    /// profile at a glance. Set to `false` to omit it.
+    pub show_severity_summary: bool,
+    /// When `true`, combines every discovered document into a single consolidated PDF report
+    /// (deduplicating components and unioning vulnerabilities by id) instead of converting each
+    /// one individually. See [`crate::pdf::merge`]. Defaults to `false`.
+    pub merge: bool,
+    /// Path to a JSON report template describing an ordered section layout (cover/metadata/
+    /// vulnerabilities/components/custom text blocks), loaded once by
+    /// [`crate::pdf::generator::PdfGenerator::new`]. See [`crate::pdf::template`]. `None` (the
+    /// default) keeps the built-in fixed layout driven by `pure_bom_novulns`/`show_components`.
+    pub template: Option<PathBuf>,
+    /// Path to write a machine-readable summary of the run (one record per input file: path,
+    /// detected format, document type, spec version, output path, elapsed time, success/error,
+    /// and severity/analysis-state counts), in the format selected by [`Self::report_format`].
+    /// See [`crate::files_proc::run_summary`]. `None` (the default) disables the emitter.
+    pub summary_json: Option<PathBuf>,
+    /// Selects whether [`Self::summary_json`] is written as a single JSON document or a JUnit
+    /// `<testsuite>` document. Defaults to [`ReportFormat::Json`]; has no effect when
+    /// `summary_json` is `None`.
+    pub report_format: ReportFormat,
+    /// When `true`, discovery and parsing run as usual but PDF generation is skipped entirely;
+    /// each file's resolved document type, spec version, tool metadata, component count, and
+    /// severity breakdown is printed to stdout instead. Modeled on the rust compiler's `--print`
+    /// query options: a cheap way to triage a directory of BOMs before committing to the
+    /// expensive font-embedding render. Defaults to `false`.
+    pub dry_run: bool,
+    /// Maximum number of vulnerabilities permitted per severity band (`"Critical"`, `"High"`,
+    /// `"Medium"`, `"Low"`) before [`crate::run`] fails with
+    /// [`Vex2PdfError::SeverityThresholdExceeded`] after every PDF has already been generated.
+    /// A band with no entry here is left ungated. Empty (the default) disables gating entirely.
+    /// See [`Self::max_allowed`]/[`Self::fail_on_severity`].
+    pub max_allowed: HashMap<String, usize>,
+    /// When `true`, vulnerabilities whose `analysis.state` is `"not_affected"` or `"resolved"`
+    /// still count toward [`Self::max_allowed`]'s gate. Off by default, so a build only breaks
+    /// on findings that haven't been triaged away as non-exploitable.
+    pub gate_count_analyzed: bool,
+    /// When `true`, [`crate::run`] keeps running after its initial conversion pass and
+    /// reconverts any BOM under [`Self::working_path`] whenever it changes on disk, via
+    /// [`crate::files_proc::watch`]. Defaults to `false`.
+    pub watch: bool,
+}
+
+/// An RGB color (0-255 per channel). A plain tuple rather than a renderer-specific color type,
+/// so this module doesn't need to depend on `genpdf`.
+pub type SeverityColor = (u8, u8, u8);
+
+/// The severity→color mapping used to color-code rendered vulnerabilities and their legend in
+/// the Vulnerabilities section. See [`Config::severity_palette`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SeverityPalette {
+    pub critical: SeverityColor,
+    pub high: SeverityColor,
+    pub medium: SeverityColor,
+    pub low: SeverityColor,
+    /// Used for both the CycloneDX `None` and `Info` severities, and as the fallback for any
+    /// value this crate doesn't recognize.
+    pub none: SeverityColor,
+}
+
+impl Default for SeverityPalette {
+    /// A conventional red→green severity ramp: deep red (critical), orange-red (high), amber
+    /// (medium), olive/green (low), gray (none/info) — the same keying RustSec tooling uses for
+    /// `cvss::Severity`.
+    fn default() -> Self {
+        Self {
+            critical: (139, 0, 0),
+            high: (205, 92, 0),
+            medium: (184, 134, 11),
+            low: (85, 107, 47),
+            none: (128, 128, 128),
+        }
+    }
+}
+
+/// Selects the color scheme [`crate::pdf::generator::PdfGenerator`] uses for the structural
+/// (non-severity) text it renders — titles, headers, component names, version tags, CVE id
+/// badges, and the "No Vulnerabilities reported" banner. Orthogonal to [`SeverityPalette`], which
+/// only covers the severity-band colors.
+///
+/// `genpdf` has no page-background-fill primitive, so [`Theme::Dark`] doesn't paint the page
+/// itself; it selects a lighter, higher-contrast palette tuned for PDF viewers' own dark/night
+/// mode instead.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "cli", derive(serde::Deserialize))]
+#[cfg_attr(feature = "cli", serde(rename_all = "lowercase"))]
+pub enum Theme {
+    /// Dark text on a white page — the original, default look.
+    #[default]
+    Light,
+    /// A lighter, higher-contrast palette intended for PDF viewers with a dark/night reading
+    /// mode.
+    Dark,
+}
+
+impl Theme {
+    /// Returns the lowercase name used on the CLI/in config files (`"light"`, `"dark"`).
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Theme::Light => "light",
+            Theme::Dark => "dark",
+        }
+    }
+
+    /// The concrete colors this theme selects for structural report text.
+    pub const fn colors(&self) -> ThemeColors {
+        match self {
+            Theme::Light => ThemeColors {
+                title: (0, 0, 80),
+                header: (0, 0, 80),
+                indent: (40, 40, 40),
+                comp_name: (0, 51, 102),
+                version: (128, 128, 128),
+                cve_id: (139, 0, 0),
+                novulns_banner: (0, 100, 0),
+            },
+            Theme::Dark => ThemeColors {
+                title: (135, 206, 250),
+                header: (135, 206, 250),
+                indent: (220, 220, 220),
+                comp_name: (173, 216, 230),
+                version: (169, 169, 169),
+                cve_id: (255, 99, 71),
+                novulns_banner: (144, 238, 144),
+            },
+        }
+    }
+}
+
+impl std::str::FromStr for Theme {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "light" => Ok(Theme::Light),
+            "dark" => Ok(Theme::Dark),
+            other => Err(format!(
+                "unknown theme `{other}`, expected `light` or `dark`"
+            )),
+        }
+    }
+}
+
+/// The structural text colors selected by a [`Theme`]. See [`Theme::colors`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ThemeColors {
+    pub title: SeverityColor,
+    pub header: SeverityColor,
+    pub indent: SeverityColor,
+    pub comp_name: SeverityColor,
+    pub version: SeverityColor,
+    pub cve_id: SeverityColor,
+    pub novulns_banner: SeverityColor,
+}
+
+/// Selects which renderer [`crate::pdf::generator::PdfGenerator`] and friends use to produce
+/// the report from a parsed BoM/VEX document.
+///
+/// Both variants consume the same report model; only the rendering backend differs. This lets
+/// downstream code branch on a single authoritative setting rather than assuming PDF output
+/// everywhere.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(feature = "cli", derive(serde::Deserialize))]
+#[cfg_attr(feature = "cli", serde(rename_all = "lowercase"))]
+pub enum OutputFormat {
+    /// Render a PDF document (the default, and currently the only implemented renderer).
+    #[default]
+    Pdf,
+    /// Render an HTML document, useful for embedding reports in dashboards or diffing them in
+    /// pull requests where a binary PDF is opaque.
+    Html,
+}
+
+impl OutputFormat {
+    /// Returns the lowercase name used on the CLI/in config files (`"pdf"`, `"html"`).
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            OutputFormat::Pdf => "pdf",
+            OutputFormat::Html => "html",
+        }
+    }
+}
+
+impl std::str::FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "pdf" => Ok(OutputFormat::Pdf),
+            "html" => Ok(OutputFormat::Html),
+            other => Err(format!(
+                "unknown output format `{other}`, expected `pdf` or `html`"
+            )),
+        }
+    }
+}
+
+/// Selects how [`crate::files_proc::run_summary::RunSummary`] serializes the run it accumulated,
+/// when [`Config::summary_json`] is set.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(feature = "cli", derive(serde::Deserialize))]
+#[cfg_attr(feature = "cli", serde(rename_all = "lowercase"))]
+pub enum ReportFormat {
+    /// One JSON document with a `entries` array (the default).
+    #[default]
+    Json,
+    /// A JUnit `<testsuite>` document, one `<testcase>` per converted BoM and a `<failure>` for
+    /// each one that didn't convert, so the report can be ingested by CI systems that already
+    /// understand JUnit (most of them).
+    Junit,
+}
+
+impl ReportFormat {
+    /// Returns the lowercase name used on the CLI/in config files (`"json"`, `"junit"`).
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            ReportFormat::Json => "json",
+            ReportFormat::Junit => "junit",
+        }
+    }
+}
+
+impl std::str::FromStr for ReportFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "json" => Ok(ReportFormat::Json),
+            "junit" => Ok(ReportFormat::Junit),
+            other => Err(format!(
+                "unknown report format `{other}`, expected `json` or `junit`"
+            )),
+        }
+    }
+}
+
+/// The field-level overrides a named report profile applies. Every field is optional so a
+/// profile only needs to state the settings it actually changes.
+///
+/// Profiles come from two sources: a small set of presets this crate ships (see
+/// [`Config::apply_profile`]), and/or a `[profiles.<name>]` table in the optional
+/// [`ConfigFile`], which takes precedence over a built-in preset of the same name.
+#[derive(Debug, Default, Clone)]
+#[cfg_attr(feature = "cli", derive(serde::Deserialize))]
+pub struct ProfileOverrides {
+    pub show_novulns_msg: Option<bool>,
+    pub pure_bom_novulns: Option<bool>,
+    pub show_components: Option<bool>,
+}
+
+/// Looks up one of the built-in report profiles by name. Returns `None` if `name` doesn't
+/// match a preset this crate ships.
+fn builtin_profile(name: &str) -> Option<ProfileOverrides> {
+    match name {
+        "pure-bom" => Some(ProfileOverrides {
+            show_novulns_msg: Some(false),
+            pure_bom_novulns: Some(true),
+            show_components: None,
+        }),
+        "full" => Some(ProfileOverrides {
+            show_novulns_msg: Some(true),
+            pure_bom_novulns: Some(false),
+            show_components: Some(true),
+        }),
+        "minimal" => Some(ProfileOverrides {
+            show_novulns_msg: Some(false),
+            pure_bom_novulns: None,
+            show_components: Some(false),
+        }),
+        _ => None,
+    }
+}
+
+/// The file names, in lookup order, that [`ConfigFile::discover`] looks for while walking
+/// upwards from the working directory. The extension also selects the format [`ConfigFile::load`]
+/// parses a given path with — `.toml` for TOML, `.yaml`/`.yml` for YAML.
+#[cfg(feature = "cli")]
+const CONFIG_FILE_NAMES: &[&str] = &[".vex2pdf.toml", ".vex2pdf.yaml", ".vex2pdf.yml"];
+
+/// An optional, file-based configuration layer for [`Config`], loaded from a `.vex2pdf.toml` or
+/// `.vex2pdf.yaml`/`.vex2pdf.yml` ([`ConfigFile::load`] picks the format by extension).
+///
+/// Every field is optional so a team only needs to commit the settings they care about;
+/// anything left unset falls through to the environment-variable/default resolution already
+/// performed by [`Config::build_with_env_cli`]. A key this version of `ConfigFile` doesn't
+/// recognize is logged as a warning and ignored rather than rejected, so a file written for a
+/// newer `vex2pdf` still loads on an older one.
+///
+/// # Precedence
+///
+/// When building a `Config` for CLI usage the layers are applied, highest precedence first:
+///
+/// 1. CLI arguments
+/// 2. Environment variables
+/// 3. This config file
+/// 4. Built-in defaults
+#[cfg(feature = "cli")]
+#[derive(Debug, Default, serde::Deserialize)]
+pub struct ConfigFile {
+    pub working_path: Option<PathBuf>,
+    pub output_dir: Option<PathBuf>,
+    pub show_novulns_msg: Option<bool>,
+    pub process_json: Option<bool>,
+    pub process_xml: Option<bool>,
+    pub pure_bom_novulns: Option<bool>,
+    pub show_components: Option<bool>,
+    pub report_title: Option<String>,
+    pub pdf_meta_name: Option<String>,
+    pub max_jobs: Option<u8>,
+    /// Chunk size for dispatch batching. See [`Config::items_per_job`].
+    pub items_per_job: Option<usize>,
+    pub include_patterns: Option<Vec<String>>,
+    pub exclude_patterns: Option<Vec<String>>,
+    pub output_format: Option<OutputFormat>,
+    /// Named report profiles, keyed by name, e.g. a `[profiles.ci]` table. A profile defined
+    /// here takes precedence over a built-in preset of the same name (see
+    /// [`Config::apply_profile`]).
+    pub profiles: Option<HashMap<String, ProfileOverrides>>,
+    /// When `true` and `working_path` is a directory, scans the entire directory tree instead
+    /// of just its first level.
+    pub recursive: Option<bool>,
+    /// When `true`, and an output directory is set, mirrors each input file's subdirectory
+    /// under it instead of flattening every generated PDF into it.
+    pub mirror_output_structure: Option<bool>,
+    /// Caps the depth of a recursive scan, counting `working_path`'s own entries as depth 1.
+    pub max_depth: Option<usize>,
+    /// When `true`, a recursive scan follows symbolic links to directories.
+    pub follow_symlinks: Option<bool>,
+    /// When `true`, a recursive scan honors `.gitignore`/`.ignore`/`.vex2pdfignore` files.
+    pub respect_ignore_files: Option<bool>,
+    /// When `true`, a recursive scan also considers hidden files and directories.
+    pub include_hidden: Option<bool>,
+    /// When `true`, validates each input against the bundled CycloneDX JSON Schema before
+    /// conversion.
+    pub validate_schema: Option<bool>,
+    /// Octal file mode (e.g. `"640"`) applied to each generated PDF after it's written.
+    pub file_mode: Option<String>,
+    /// Unix user name to `chown` each generated PDF to after it's written.
+    pub owner: Option<String>,
+    /// Unix group name to `chown` each generated PDF to after it's written.
+    pub group: Option<String>,
+    /// When `true`, pins generated PDFs' dynamic dates and derives their `/ID`/XMP ids from
+    /// content, so identical input yields byte-identical output.
+    pub reproducible: Option<bool>,
+    /// The fixed instant (Unix timestamp) used for generated PDFs' dates when `reproducible`
+    /// is on.
+    pub source_date_epoch: Option<i64>,
+    /// Severity names a vulnerability's rating must match at least one of to be included.
+    pub only_severity: Option<Vec<String>>,
+    /// Severity names that exclude a vulnerability from the report, applied after
+    /// `only_severity`.
+    pub skip_severity: Option<Vec<String>>,
+    /// Analysis state names that exclude a vulnerability from the report.
+    pub skip_state: Option<Vec<String>>,
+    /// Path to write a BLAKE3 checksum manifest listing every generated PDF after conversion.
+    pub manifest_path: Option<PathBuf>,
+    /// Skips regenerating a PDF whose output is already newer than its source, and maintains a
+    /// checkpoint manifest in `output_dir` so an interrupted run can resume.
+    pub resume: Option<bool>,
+    /// Regex matched against a candidate file's name; a match excludes the file from
+    /// processing, same as a hit on `exclude_patterns`.
+    pub exclude_name_regex: Option<String>,
+    /// Combines every discovered document into a single consolidated PDF report instead of
+    /// converting each one individually.
+    pub merge: Option<bool>,
+    /// Path to a JSON report template describing an ordered section layout.
+    pub template: Option<PathBuf>,
+    /// Path to write a machine-readable JSON summary of the run.
+    pub summary_json: Option<PathBuf>,
+    /// Whether `summary_json` is written as JSON or JUnit.
+    pub report_format: Option<ReportFormat>,
+    /// Skips PDF generation and prints a per-file inspection report to stdout instead.
+    pub dry_run: Option<bool>,
+    /// Maximum number of vulnerabilities permitted per severity band before the run fails.
+    pub max_allowed: Option<HashMap<String, usize>>,
+    /// Counts `not_affected`/`resolved` findings toward `max_allowed`'s gate too.
+    pub gate_count_analyzed: Option<bool>,
+    /// Keeps running after the initial conversion and reconverts BOMs as they change on disk.
+    pub watch: Option<bool>,
+}
+
+#[cfg(feature = "cli")]
+impl ConfigFile {
+    /// Parses a config file at the given path, as TOML or YAML depending on its extension
+    /// (`.yaml`/`.yml` for YAML, anything else as TOML).
+    ///
+    /// A key the current binary doesn't recognize is logged as a warning rather than rejected,
+    /// so a config file written for a newer `vex2pdf` still loads on an older one.
+    ///
+    /// The error message names `path` so a malformed explicit `--config` file (or a discovered
+    /// one) is easy to locate, since this may be surfaced far from wherever the path came from.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, Vex2PdfError> {
+        let path = path.as_ref();
+        let content = fs_context::read_to_string(path)?;
+        let warn_unknown = |field: serde_ignored::Path| {
+            log::warn!("{}: ignoring unknown config key `{field}`", path.display());
+        };
+
+        let is_yaml = matches!(
+            path.extension().and_then(|ext| ext.to_str()),
+            Some("yaml") | Some("yml")
+        );
+
+        if is_yaml {
+            let de = serde_yaml::Deserializer::from_str(&content);
+            serde_ignored::deserialize(de, warn_unknown).map_err(|e| {
+                Vex2PdfError::Parse(format!("invalid config file `{}`: {e}", path.display()))
+            })
+        } else {
+            let de = toml::Deserializer::new(&content);
+            serde_ignored::deserialize(de, warn_unknown).map_err(|e| {
+                Vex2PdfError::Parse(format!("invalid config file `{}`: {e}", path.display()))
+            })
+        }
+    }
+
+    /// Walks upwards from `start_dir`, returning the path to the first of [`CONFIG_FILE_NAMES`]
+    /// encountered, or `None` if none of the ancestor directories contain one.
+    pub fn discover(start_dir: &Path) -> Option<PathBuf> {
+        let mut current = Some(start_dir);
+
+        while let Some(dir) = current {
+            for name in CONFIG_FILE_NAMES {
+                let candidate = dir.join(name);
+                if candidate.is_file() {
+                    return Some(candidate);
+                }
+            }
+            current = dir.parent();
+        }
+
+        None
+    }
+
+    /// Discovers and loads a config file starting from `start_dir`.
+    ///
+    /// Returns `Ok(None)` (rather than an error) when no config file is found, since the
+    /// config file layer is entirely optional.
+    pub fn discover_and_load(start_dir: &Path) -> Result<Option<Self>, Vex2PdfError> {
+        match Self::discover(start_dir) {
+            Some(path) => Ok(Some(Self::load(path)?)),
+            None => Ok(None),
+        }
+    }
 }
 
 impl Config {
@@ -163,7 +811,9 @@ impl Config {
     /// Configuration values are resolved with this precedence (highest to lowest):
     /// 1. **CLI arguments** (e.g., `--max-jobs 4`)
     /// 2. **Environment variables** (e.g., `VEX2PDF_MAX_JOBS=4`)
-    /// 3. **Default values**
+    /// 3. **Config file** ([`ConfigFile`], an optional `.vex2pdf.toml`/`.vex2pdf.yaml` discovered
+    ///    by walking up from the input path)
+    /// 4. **Default values**
     ///
     /// # Returns
     ///
@@ -197,40 +847,265 @@ impl Config {
             return Err(Vex2PdfError::VoluntaryLicenseDisplayInterruption);
         }
 
-        // validate potential permissions issues
-        args.validate()?;
-
         // print version info
         info!("{}", get_version_info());
         info!("");
 
-        let working_path = args.input.unwrap_or(std::env::current_dir()?);
-        let output_dir = args.output_dir.unwrap_or(std::env::current_dir()?);
-        let show_novulns_msg = args
-            .show_novulns_msg
-            .unwrap_or(EnvVarNames::NoVulnsMsg.is_on_or_unset());
-        let mut process_json = EnvVarNames::ProcessJson.is_on_or_unset();
-        let process_xml = EnvVarNames::ProcessXml.is_on_or_unset();
-        let show_pure_bom_novulns = args
-            .pure_bom_novulns
-            .unwrap_or(EnvVarNames::PureBomNoVulns.is_on());
-        let show_comps = args
-            .show_components
-            .unwrap_or(EnvVarNames::ShowComponentList.is_on_or_unset());
-        let report_title_override = args
-            .report_title
-            .map(Some)
-            .unwrap_or(EnvVarNames::ReportTitle.get_value());
-        let pdf_meta_name_override = args
-            .meta_name
-            .map(Some)
-            .unwrap_or(EnvVarNames::PdfName.get_value());
+        // validate potential permissions issues up front, before any conversion work
+        args.validate()?;
+
+        // load the optional config-file layer; this sits below env vars and CLI args but above
+        // the built-in defaults in the precedence chain. An explicit `--config`/env path is
+        // loaded as-is (and errors if it doesn't exist or fails to parse); otherwise we discover
+        // a `.vex2pdf.toml`/`.vex2pdf.yaml` by walking up from the input path, entirely optional.
+        let file_config = match args.config_file.clone() {
+            Some(path) => ConfigFile::load(&path)?,
+            None => {
+                let discovery_start = args.input.clone().unwrap_or(std::env::current_dir()?);
+                ConfigFile::discover_and_load(&discovery_start)?.unwrap_or_default()
+            }
+        };
+
+        // resolve the named report profile (if any) into its field overrides before the
+        // individual fields below are resolved, so a profile acts as a preset base that
+        // explicit CLI/env/config-file values still take precedence over
+        let profile_name = args.profile.clone().or(EnvVarNames::Profile.get_value());
+        let profile_overrides = match profile_name.as_deref() {
+            Some(name) => file_config
+                .profiles
+                .as_ref()
+                .and_then(|profiles| profiles.get(name))
+                .cloned()
+                .or_else(|| builtin_profile(name))
+                .unwrap_or_else(|| {
+                    warn!("Unknown report profile `{name}`, ignoring");
+                    ProfileOverrides::default()
+                }),
+            None => ProfileOverrides::default(),
+        };
+
+        let working_path = args
+            .input
+            .or(file_config.working_path)
+            .unwrap_or(std::env::current_dir()?);
+        // Resolved before defaulting so the permission check below applies regardless of
+        // whether the directory came from a CLI flag, an env var (already folded into
+        // `args.output_dir` by clap's `env` attribute), or the config-file layer.
+        let output_dir_override = args.output_dir.or(file_config.output_dir);
+        if let Some(dir) = output_dir_override.as_ref() {
+            validate_output_dir_permissions(dir)?;
+        }
+        let output_dir = output_dir_override.unwrap_or(std::env::current_dir()?);
+        let show_novulns_msg = args.show_novulns_msg.unwrap_or(
+            EnvVarNames::NoVulnsMsg
+                .get_bool()
+                .or(file_config.show_novulns_msg)
+                .unwrap_or(profile_overrides.show_novulns_msg.unwrap_or(true)),
+        );
+        let mut process_json = EnvVarNames::ProcessJson
+            .get_bool()
+            .or(file_config.process_json)
+            .unwrap_or(true);
+        let process_xml = EnvVarNames::ProcessXml
+            .get_bool()
+            .or(file_config.process_xml)
+            .unwrap_or(true);
+        let show_pure_bom_novulns = args.pure_bom_novulns.unwrap_or(
+            EnvVarNames::PureBomNoVulns
+                .get_bool()
+                .or(file_config.pure_bom_novulns)
+                .unwrap_or(profile_overrides.pure_bom_novulns.unwrap_or(false)),
+        );
+        let show_comps = args.show_components.unwrap_or(
+            EnvVarNames::ShowComponentList
+                .get_bool()
+                .or(file_config.show_components)
+                .unwrap_or(profile_overrides.show_components.unwrap_or(true)),
+        );
+        let report_title_override = args.report_title.map(Some).unwrap_or(
+            EnvVarNames::ReportTitle
+                .get_value()
+                .or(file_config.report_title),
+        );
+        let pdf_meta_name_override = args.meta_name.map(Some).unwrap_or(
+            EnvVarNames::PdfName
+                .get_value()
+                .or(file_config.pdf_meta_name),
+        );
         // set number of jobs
         #[cfg(feature = "concurrency")]
-        let max_jobs = args.max_jobs;
+        let max_jobs = args.max_jobs.or(file_config.max_jobs);
         #[cfg(not(feature = "concurrency"))]
         let max_jobs = None;
 
+        // set dispatch chunk size; irrelevant without the "concurrency" feature, where
+        // batches always run sequentially one item at a time
+        #[cfg(feature = "concurrency")]
+        let items_per_job = args.items_per_job.or(file_config.items_per_job);
+        #[cfg(not(feature = "concurrency"))]
+        let items_per_job = None;
+
+        // `args.include_patterns`/`args.exclude_patterns` already merge CLI flags with their
+        // env-var equivalent via clap's `env` attribute, so only the config-file layer needs
+        // to be folded in here. Excludes from both sources are unioned; includes fall back to
+        // the file layer only when the CLI/env layer provided none.
+        let include_patterns = args
+            .include_patterns
+            .or(file_config.include_patterns)
+            .unwrap_or_default();
+        let mut exclude_patterns = file_config.exclude_patterns.unwrap_or_default();
+        exclude_patterns.extend(args.exclude_patterns.unwrap_or_default());
+
+        let output_format = args
+            .output_format
+            .or(file_config.output_format)
+            .unwrap_or_default();
+        let recursive = args.recursive.unwrap_or(
+            EnvVarNames::Recursive
+                .get_bool()
+                .or(file_config.recursive)
+                .unwrap_or(false),
+        );
+        let mirror_output_structure = args.mirror_output_structure.unwrap_or(
+            EnvVarNames::MirrorOutputStructure
+                .get_bool()
+                .or(file_config.mirror_output_structure)
+                .unwrap_or(false),
+        );
+        let max_depth = args
+            .max_depth
+            .map(|d| d as usize)
+            .or(EnvVarNames::MaxDepth
+                .get_value()
+                .and_then(|s| s.parse().ok()))
+            .or(file_config.max_depth);
+        let follow_symlinks = args.follow_symlinks.unwrap_or(
+            EnvVarNames::FollowSymlinks
+                .get_bool()
+                .or(file_config.follow_symlinks)
+                .unwrap_or(false),
+        );
+        let respect_ignore_files = args.respect_ignore_files.unwrap_or(
+            EnvVarNames::RespectIgnoreFiles
+                .get_bool()
+                .or(file_config.respect_ignore_files)
+                .unwrap_or(true),
+        );
+        let include_hidden = args.include_hidden.unwrap_or(
+            EnvVarNames::IncludeHidden
+                .get_bool()
+                .or(file_config.include_hidden)
+                .unwrap_or(false),
+        );
+        let validate_schema = args.validate_schema.unwrap_or(
+            EnvVarNames::ValidateSchema
+                .get_bool()
+                .or(file_config.validate_schema)
+                .unwrap_or(false),
+        );
+        let file_mode_str = args
+            .file_mode
+            .clone()
+            .or(EnvVarNames::FileMode.get_value())
+            .or(file_config.file_mode);
+        let file_mode = file_mode_str.map(|s| parse_file_mode(&s)).transpose()?;
+        let owner = args
+            .owner
+            .clone()
+            .or(EnvVarNames::Owner.get_value())
+            .or(file_config.owner);
+        let group = args
+            .group
+            .clone()
+            .or(EnvVarNames::Group.get_value())
+            .or(file_config.group);
+        let reproducible = args.reproducible.unwrap_or(
+            EnvVarNames::Reproducible
+                .get_bool()
+                .or(file_config.reproducible)
+                .unwrap_or(false),
+        );
+        // Deliberately not folded into a CLI/clap field: `SOURCE_DATE_EPOCH` is an ecosystem
+        // convention read by many tools directly, not a vex2pdf-specific setting a user would
+        // expect to also pass as a flag.
+        let source_date_epoch = EnvVarNames::SourceDateEpoch
+            .get_value()
+            .and_then(|s| s.parse().ok())
+            .or(file_config.source_date_epoch);
+
+        // `args.only_severity`/`args.skip_severity`/`args.skip_state` already merge CLI flags
+        // with their env-var equivalent via clap's `env` attribute; only the config-file layer
+        // needs to be folded in here. Like `include_patterns`, `only_severity` falls back to
+        // the file layer only when the CLI/env layer provided none; the skip lists, like
+        // `exclude_patterns`, are unioned across both sources.
+        let only_severity = args
+            .only_severity
+            .or(file_config.only_severity)
+            .unwrap_or_default();
+        let mut skip_severity = file_config.skip_severity.unwrap_or_default();
+        skip_severity.extend(args.skip_severity.unwrap_or_default());
+        let mut skip_state = file_config.skip_state.unwrap_or_default();
+        skip_state.extend(args.skip_state.unwrap_or_default());
+        let manifest_path = args.manifest.or(file_config.manifest_path);
+        let template = args.template.or(file_config.template);
+        let summary_json = args.summary_json.or(file_config.summary_json);
+        let report_format = args
+            .report_format
+            .or(file_config.report_format)
+            .unwrap_or_default();
+        let dry_run = args.dry_run.unwrap_or(
+            EnvVarNames::DryRun
+                .get_bool()
+                .or(file_config.dry_run)
+                .unwrap_or(false),
+        );
+        // `args.max_allowed`/`args.fail_on_severity` already merge CLI flags with their env-var
+        // equivalent via clap's `env` attribute; only the config-file layer needs to be folded
+        // in here, same as `skip_severity`. `--fail-on-severity` is pure sugar for a `0`
+        // threshold, applied after the explicit pairs so it can't be silently overridden by a
+        // looser `--max-allowed` for the same severity.
+        let mut max_allowed = file_config.max_allowed.unwrap_or_default();
+        for token in args.max_allowed.unwrap_or_default() {
+            let (severity, count) = parse_severity_threshold(&token)?;
+            max_allowed.insert(severity, count);
+        }
+        for severity in args.fail_on_severity.unwrap_or_default() {
+            max_allowed.insert(severity, 0);
+        }
+        let gate_count_analyzed = args.gate_count_analyzed.unwrap_or(
+            EnvVarNames::GateCountAnalyzed
+                .get_bool()
+                .or(file_config.gate_count_analyzed)
+                .unwrap_or(false),
+        );
+        let watch = args.watch.unwrap_or(
+            EnvVarNames::Watch
+                .get_bool()
+                .or(file_config.watch)
+                .unwrap_or(false),
+        );
+        let resume = args.resume.unwrap_or(
+            EnvVarNames::Resume
+                .get_bool()
+                .or(file_config.resume)
+                .unwrap_or(false),
+        );
+        let exclude_name_regex = args
+            .exclude_name_regex
+            .clone()
+            .or(EnvVarNames::ExcludeNameRegex.get_value())
+            .or(file_config.exclude_name_regex);
+        let merge = args.merge.unwrap_or(
+            EnvVarNames::Merge
+                .get_bool()
+                .or(file_config.merge)
+                .unwrap_or(false),
+        );
+        if merge && resume {
+            warn!("**** WARNING: --merge and --resume are incompatible; ignoring --resume");
+        }
+        let resume = resume && !merge;
+
         // print init information
         FontsDir::print_fonts_info();
         // print default titles details
@@ -257,6 +1132,46 @@ impl Config {
             report_title: report_title_override,
             pdf_meta_name: pdf_meta_name_override,
             max_jobs,
+            items_per_job,
+            include_patterns,
+            exclude_patterns,
+            output_format,
+            profile: profile_name,
+            recursive,
+            mirror_output_structure,
+            max_depth,
+            follow_symlinks,
+            respect_ignore_files,
+            include_hidden,
+            validate_schema,
+            file_mode,
+            owner,
+            group,
+            reproducible,
+            source_date_epoch,
+            only_severity,
+            skip_severity,
+            skip_state,
+            manifest_path,
+            resume,
+            exclude_name_regex,
+            severity_palette: SeverityPalette::default(),
+            sort_vulns_by_severity: false,
+            advisory_db_path: None,
+            enrich_with_advisory_db: false,
+            natural_sort_lists: false,
+            theme: Theme::default(),
+            show_component_licenses: true,
+            show_component_hashes: true,
+            show_severity_summary: true,
+            merge,
+            template,
+            summary_json,
+            report_format,
+            dry_run,
+            max_allowed,
+            gate_count_analyzed,
+            watch,
         };
 
         Ok(config)
@@ -418,114 +1333,844 @@ impl Config {
         self.max_jobs = jobs;
         self
     }
-}
 
-impl Default for Config {
-    /// Creates a `Config` instance with default values for all configuration options.
+    /// Sets the number of items [`crate::files_proc::processor::ProcessorReady::process`] batches
+    /// into a single job before dispatching it to the pool.
     ///
-    /// This implementation provides sensible defaults that match the application's
-    /// standard behavior when no environment variables are set. This does not process
-    /// any environment variables, if you need to process environment variables use `Config::build()`
-    /// instead.
+    /// - `Some(n)` - Batch n items per job
+    /// - `None` - Use [`crate::lib_utils::concurrency::threadpool::DEFAULT_ITEMS_PER_JOB`]
     ///
-    /// # Default Values
+    /// # Examples
     ///
-    /// - **working_dir**: Current working directory
-    /// - **show_novulns_msg**: `true` - Display "No Vulnerabilities" message when applicable
-    /// - **file_types_to_process**: Both JSON and XML processing enabled (`true`)
-    /// - **show_oss_licenses**: `true` - Display open source license information
-    /// - **show_components**: `true` - Include component information in reports
-    /// - **report_title**: Default report title from `get_default_report_title()`
-    /// - **pdf_meta_name**: Default PDF metadata name from `get_default_pdf_meta_name()`
+    /// ```rust
+    /// use vex2pdf::lib_utils::config::Config;
     ///
-    /// # Behavior
+    /// let config = Config::default()
+    ///     .items_per_job(Some(50));  // Batch 50 items per job
+    /// ```
+    pub fn items_per_job(mut self, n: Option<usize>) -> Self {
+        self.items_per_job = n;
+        self
+    }
+
+    /// Sets the glob patterns a file must match (relative to `working_path`) to be processed.
     ///
-    /// These defaults represent the "out-of-the-box" configuration that provides
-    /// the most comprehensive reporting. Users can override these values through
-    /// environment variables or by using `Config::build()` which respects
-    /// environment variable settings.
+    /// An empty list (the default) matches every file.
     ///
-    /// # Panics
+    /// # Examples
     ///
-    /// Panics if the current working directory cannot be determined.
+    /// ```rust
+    /// use vex2pdf::lib_utils::config::Config;
+    ///
+    /// let config = Config::default()
+    ///     .include_patterns(["*.cdx.json", "vex-*.xml"]);
+    /// ```
+    pub fn include_patterns<I, S>(mut self, patterns: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.include_patterns = patterns.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the glob patterns that exclude a file from processing, applied after
+    /// `include_patterns`.
     ///
     /// # Examples
     ///
     /// ```rust
     /// use vex2pdf::lib_utils::config::Config;
-    /// use std::default::Default;
-    ///
-    /// // Create config with all default values
-    /// let config = Config::default();
     ///
-    /// // All processing options are set to defaults according to the most common, perceived, use
-    /// // This can be overridden using the respective environment variables check
-    /// assert_eq!(config.pure_bom_novulns,false);
-    /// assert_eq!(config.show_novulns_msg,true);
-    /// assert_eq!(config.show_components,true);
+    /// let config = Config::default()
+    ///     .exclude_patterns(["*-draft.*"]);
     /// ```
+    pub fn exclude_patterns<I, S>(mut self, patterns: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.exclude_patterns = patterns.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets which renderer produces the report.
     ///
-    /// # See Also
+    /// # Examples
     ///
-    /// - `Config::build()` for environment-variable-aware configuration
-    /// - README.md for detailed environment variable documentation
-    fn default() -> Self {
-        let mut file_types_to_process: HashMap<InputFileType, bool> = HashMap::new();
-        file_types_to_process.insert(InputFileType::JSON, true);
-        file_types_to_process.insert(InputFileType::XML, true);
-        let working_path = std::env::current_dir().expect("Failed to get current directory");
-        let output_dir = working_path.clone();
-        Self {
-            working_path,
-            output_dir,
-            show_novulns_msg: true,
-            file_types_to_process: Some(file_types_to_process),
-            pure_bom_novulns: false,
-            show_components: true,
-            report_title: Some(Self::get_default_report_title().to_string()),
-            pdf_meta_name: Some(Self::get_default_pdf_meta_name().to_string()),
-            max_jobs: None,
-        }
+    /// ```rust
+    /// use vex2pdf::lib_utils::config::{Config, OutputFormat};
+    ///
+    /// let config = Config::default()
+    ///     .output_format(OutputFormat::Html);
+    /// ```
+    pub fn output_format(mut self, format: OutputFormat) -> Self {
+        self.output_format = format;
+        self
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// Applies a named report profile's field values, then records the name.
+    ///
+    /// This crate ships three built-in presets:
+    /// - `"pure-bom"` - treats the input as a pure BoM: hides the "no vulnerabilities" message
+    ///   and enables `pure_bom_novulns`
+    /// - `"full"` - shows everything (vulnerabilities message, components); the out-of-the-box
+    ///   shape
+    /// - `"minimal"` - hides the components list
+    ///
+    /// A name that matches neither a built-in preset nor a `[profiles.<name>]` table in an
+    /// optional config file (handled separately by [`Self::build_with_env_cli`]) is a no-op
+    /// here aside from being recorded on [`Self::profile`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vex2pdf::lib_utils::config::Config;
+    ///
+    /// let config = Config::default().apply_profile("pure-bom");
+    /// assert!(config.pure_bom_novulns);
+    /// assert!(!config.show_novulns_msg);
+    /// ```
+    pub fn apply_profile(mut self, name: impl Into<String>) -> Self {
+        let name = name.into();
 
-    #[test]
-    fn test_builder_working_path() {
-        let config = Config::default().working_path("/tmp/test");
-        assert_eq!(config.working_path, PathBuf::from("/tmp/test"));
-    }
+        if let Some(overrides) = builtin_profile(&name) {
+            self = self.apply_profile_overrides(&overrides);
+        }
 
-    #[test]
-    fn test_builder_output_dir() {
-        let config = Config::default().output_dir("/tmp/output");
-        assert_eq!(config.output_dir, PathBuf::from("/tmp/output"));
+        self.profile = Some(name);
+        self
     }
 
-    #[test]
-    fn test_builder_show_novulns_msg() {
-        let config = Config::default().show_novulns_msg(false);
-        assert_eq!(config.show_novulns_msg, false);
+    /// Controls whether `working_path` is scanned recursively when it's a directory.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vex2pdf::lib_utils::config::Config;
+    ///
+    /// let config = Config::default().recursive(true);
+    /// ```
+    pub fn recursive(mut self, recursive: bool) -> Self {
+        self.recursive = recursive;
+        self
     }
 
-    #[test]
-    fn test_builder_pure_bom_novulns() {
-        let config = Config::default().pure_bom_novulns(true);
-        assert_eq!(config.pure_bom_novulns, true);
+    /// Controls whether each generated PDF's subdirectory (relative to `working_path`) is
+    /// reproduced under `output_dir` instead of flattening every output into it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vex2pdf::lib_utils::config::Config;
+    ///
+    /// let config = Config::default().recursive(true).mirror_output_structure(true);
+    /// ```
+    pub fn mirror_output_structure(mut self, mirror_output_structure: bool) -> Self {
+        self.mirror_output_structure = mirror_output_structure;
+        self
     }
 
-    #[test]
-    fn test_builder_show_components() {
-        let config = Config::default().show_components(false);
-        assert_eq!(config.show_components, false);
+    /// Caps the depth of a [`Self::recursive`] scan, counting `working_path`'s own entries as
+    /// depth 1 (so `Some(1)` reads no subdirectories). `None` (the default) descends without limit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vex2pdf::lib_utils::config::Config;
+    ///
+    /// let config = Config::default().recursive(true).max_depth(Some(3));
+    /// ```
+    pub fn max_depth(mut self, max_depth: Option<usize>) -> Self {
+        self.max_depth = max_depth;
+        self
     }
 
-    #[test]
-    fn test_builder_report_title() {
-        let config = Config::default().report_title("Custom Title");
+    /// Controls whether a [`Self::recursive`] scan follows symbolic links to directories.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vex2pdf::lib_utils::config::Config;
+    ///
+    /// let config = Config::default().recursive(true).follow_symlinks(true);
+    /// ```
+    pub fn follow_symlinks(mut self, follow_symlinks: bool) -> Self {
+        self.follow_symlinks = follow_symlinks;
+        self
+    }
+
+    /// Controls whether a [`Self::recursive`] scan honors `.gitignore`/`.ignore`/
+    /// `.vex2pdfignore` files encountered while walking. On by default.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vex2pdf::lib_utils::config::Config;
+    ///
+    /// let config = Config::default().recursive(true).respect_ignore_files(false);
+    /// ```
+    pub fn respect_ignore_files(mut self, respect_ignore_files: bool) -> Self {
+        self.respect_ignore_files = respect_ignore_files;
+        self
+    }
+
+    /// Controls whether a [`Self::recursive`] scan also considers hidden files and directories
+    /// (dotfiles). Off by default.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vex2pdf::lib_utils::config::Config;
+    ///
+    /// let config = Config::default().recursive(true).include_hidden(true);
+    /// ```
+    pub fn include_hidden(mut self, include_hidden: bool) -> Self {
+        self.include_hidden = include_hidden;
+        self
+    }
+
+    /// Controls whether each input is validated against the bundled CycloneDX JSON Schema for
+    /// its effective spec version before conversion.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vex2pdf::lib_utils::config::Config;
+    ///
+    /// let config = Config::default().validate_schema(true);
+    /// ```
+    pub fn validate_schema(mut self, validate: bool) -> Self {
+        self.validate_schema = validate;
+        self
+    }
+
+    /// Sets the POSIX permission mode applied to each generated PDF after it's written.
+    ///
+    /// No-op (with a warning, at processing time) on non-Unix platforms.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vex2pdf::lib_utils::config::Config;
+    ///
+    /// let config = Config::default().file_mode(Some(0o640));
+    /// ```
+    pub fn file_mode(mut self, mode: Option<u32>) -> Self {
+        self.file_mode = mode;
+        self
+    }
+
+    /// Sets the Unix user `chown`'d to each generated PDF after it's written.
+    ///
+    /// No-op (with a warning, at processing time) on non-Unix platforms.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vex2pdf::lib_utils::config::Config;
+    ///
+    /// let config = Config::default().owner("svc-reports");
+    /// ```
+    pub fn owner(mut self, owner: impl Into<String>) -> Self {
+        self.owner = Some(owner.into());
+        self
+    }
+
+    /// Sets the Unix group `chown`'d to each generated PDF after it's written.
+    ///
+    /// No-op (with a warning, at processing time) on non-Unix platforms.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vex2pdf::lib_utils::config::Config;
+    ///
+    /// let config = Config::default().group("svc-reports");
+    /// ```
+    pub fn group(mut self, group: impl Into<String>) -> Self {
+        self.group = Some(group.into());
+        self
+    }
+
+    /// Controls whether generated PDFs pin their dynamic dates and derive their `/ID`/XMP ids
+    /// from content, so identical input yields byte-identical output.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vex2pdf::lib_utils::config::Config;
+    ///
+    /// let config = Config::default().reproducible(true);
+    /// ```
+    pub fn reproducible(mut self, reproducible: bool) -> Self {
+        self.reproducible = reproducible;
+        self
+    }
+
+    /// Sets the fixed instant (Unix timestamp) used for generated PDFs' dates when
+    /// [`Self::reproducible`] is on. `None` falls back to the current time.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vex2pdf::lib_utils::config::Config;
+    ///
+    /// let config = Config::default()
+    ///     .reproducible(true)
+    ///     .source_date_epoch(Some(1_704_067_200));
+    /// ```
+    pub fn source_date_epoch(mut self, epoch: Option<i64>) -> Self {
+        self.source_date_epoch = epoch;
+        self
+    }
+
+    /// Sets the severity names a vulnerability's rating must match at least one of to be
+    /// included in the report. An empty list (the default) includes every severity.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vex2pdf::lib_utils::config::Config;
+    ///
+    /// let config = Config::default().only_severity(["critical", "high"]);
+    /// ```
+    pub fn only_severity<I, S>(mut self, severities: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.only_severity = severities.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the severity names that exclude a vulnerability from the report, applied after
+    /// [`Self::only_severity`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vex2pdf::lib_utils::config::Config;
+    ///
+    /// let config = Config::default().skip_severity(["none", "info"]);
+    /// ```
+    pub fn skip_severity<I, S>(mut self, severities: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.skip_severity = severities.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the analysis state names that exclude a vulnerability from the report. A
+    /// vulnerability with no analysis is treated as state `"none"`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vex2pdf::lib_utils::config::Config;
+    ///
+    /// let config = Config::default().skip_state(["not_affected", "resolved"]);
+    /// ```
+    pub fn skip_state<I, S>(mut self, states: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.skip_state = states.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the path to write a BLAKE3 checksum manifest listing every generated PDF after
+    /// conversion completes. `None` (the default) skips writing a manifest.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vex2pdf::lib_utils::config::Config;
+    ///
+    /// let config = Config::default().manifest_path(Some("./output/manifest.txt"));
+    /// ```
+    pub fn manifest_path(mut self, path: Option<impl AsRef<Path>>) -> Self {
+        self.manifest_path = path.map(|p| p.as_ref().to_path_buf());
+        self
+    }
+
+    /// Skips regenerating a PDF whose output is already newer than its source, and maintains a
+    /// checkpoint manifest in `output_dir` so a run interrupted partway through can be
+    /// re-invoked and only reprocess what's unfinished or changed. Off by default.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vex2pdf::lib_utils::config::Config;
+    ///
+    /// let config = Config::default().resume(true);
+    /// ```
+    pub fn resume(mut self, resume: bool) -> Self {
+        self.resume = resume;
+        self
+    }
+
+    /// Sets a regex matched against a candidate file's name; a match excludes the file from
+    /// processing, same as a hit on `exclude_patterns`. `None` (the default) applies no regex
+    /// filter.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vex2pdf::lib_utils::config::Config;
+    ///
+    /// let config = Config::default().exclude_name_regex(Some(r"-draft(-v\d+)?\."));
+    /// ```
+    pub fn exclude_name_regex(mut self, pattern: Option<impl Into<String>>) -> Self {
+        self.exclude_name_regex = pattern.map(Into::into);
+        self
+    }
+
+    /// Overrides the severity→color mapping used to color-code rendered vulnerabilities and
+    /// their legend.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vex2pdf::lib_utils::config::{Config, SeverityPalette};
+    ///
+    /// let config = Config::default().severity_palette(SeverityPalette {
+    ///     critical: (200, 0, 0),
+    ///     ..SeverityPalette::default()
+    /// });
+    /// ```
+    pub fn severity_palette(mut self, palette: SeverityPalette) -> Self {
+        self.severity_palette = palette;
+        self
+    }
+
+    /// When `true`, lists vulnerabilities worst-first (Critical → None) by highest rating
+    /// severity, breaking ties by CVSS v3.1 base score. When `false` (the default), keeps the
+    /// document's original order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vex2pdf::lib_utils::config::Config;
+    ///
+    /// let config = Config::default().sort_vulns_by_severity(true);
+    /// ```
+    pub fn sort_vulns_by_severity(mut self, sort: bool) -> Self {
+        self.sort_vulns_by_severity = sort;
+        self
+    }
+
+    /// Sets the path to a local checkout of a RustSec-style advisory database, used to enrich
+    /// rendered vulnerabilities when [`Self::enrich_with_advisory_db`] is also enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vex2pdf::lib_utils::config::Config;
+    ///
+    /// let config = Config::default().advisory_db_path(Some("./advisory-db"));
+    /// ```
+    pub fn advisory_db_path(mut self, path: Option<impl Into<PathBuf>>) -> Self {
+        self.advisory_db_path = path.map(Into::into);
+        self
+    }
+
+    /// Enables cross-referencing each vulnerability and affected component against
+    /// [`Self::advisory_db_path`] and rendering patched/unaffected version guidance alongside
+    /// the raw VEX entry. Has no effect while `advisory_db_path` is `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vex2pdf::lib_utils::config::Config;
+    ///
+    /// let config = Config::default()
+    ///     .advisory_db_path(Some("./advisory-db"))
+    ///     .enrich_with_advisory_db(true);
+    /// ```
+    pub fn enrich_with_advisory_db(mut self, enable: bool) -> Self {
+        self.enrich_with_advisory_db = enable;
+        self
+    }
+
+    /// When `true`, orders components (by name then version) and the tools/services list (by
+    /// name) with a version-aware natural comparator instead of raw BOM iteration order, so e.g.
+    /// `v1.9.0` sorts before `v1.10.0`. Defaults to `false`, which preserves the document's
+    /// original order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vex2pdf::lib_utils::config::Config;
+    ///
+    /// let config = Config::default().natural_sort_lists(true);
+    /// ```
+    pub fn natural_sort_lists(mut self, sort: bool) -> Self {
+        self.natural_sort_lists = sort;
+        self
+    }
+
+    /// Selects the color scheme used for structural (non-severity) report text. Defaults to
+    /// [`Theme::Light`]. See [`SeverityPalette`] to override the severity-band colors instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vex2pdf::lib_utils::config::{Config, Theme};
+    ///
+    /// let config = Config::default().theme(Theme::Dark);
+    /// ```
+    pub fn theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    /// Toggles whether each rendered component's license list is shown. Defaults to `true`;
+    /// set to `false` to keep large BOMs with many/verbose licenses readable.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vex2pdf::lib_utils::config::Config;
+    ///
+    /// let config = Config::default().show_component_licenses(false);
+    /// ```
+    pub fn show_component_licenses(mut self, show: bool) -> Self {
+        self.show_component_licenses = show;
+        self
+    }
+
+    /// Toggles whether each rendered component's hash list is shown. Defaults to `true`;
+    /// set to `false` to keep large BOMs with many hash algorithms readable.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vex2pdf::lib_utils::config::Config;
+    ///
+    /// let config = Config::default().show_component_hashes(false);
+    /// ```
+    pub fn show_component_hashes(mut self, show: bool) -> Self {
+        self.show_component_hashes = show;
+        self
+    }
+
+    /// Toggles the colored count-by-severity summary row shown above the detailed
+    /// vulnerability list. Defaults to `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vex2pdf::lib_utils::config::Config;
+    ///
+    /// let config = Config::default().show_severity_summary(false);
+    /// ```
+    pub fn show_severity_summary(mut self, show: bool) -> Self {
+        self.show_severity_summary = show;
+        self
+    }
+
+    /// Combines every discovered document into a single consolidated PDF report instead of
+    /// converting each one individually. See [`crate::pdf::merge`]. Off by default.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vex2pdf::lib_utils::config::Config;
+    ///
+    /// let config = Config::default().merge(true);
+    /// ```
+    pub fn merge(mut self, merge: bool) -> Self {
+        self.merge = merge;
+        self
+    }
+
+    /// Sets a JSON report template describing an ordered section layout. See
+    /// [`crate::pdf::template`]. `None` (the default) keeps the built-in fixed layout.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vex2pdf::lib_utils::config::Config;
+    ///
+    /// let config = Config::default().template(Some("./report-template.json"));
+    /// ```
+    pub fn template(mut self, path: Option<impl AsRef<Path>>) -> Self {
+        self.template = path.map(|p| p.as_ref().to_path_buf());
+        self
+    }
+
+    /// Writes a machine-readable JSON summary of the run to `path`. See
+    /// [`crate::files_proc::run_summary`]. `None` (the default) disables the emitter.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vex2pdf::lib_utils::config::Config;
+    ///
+    /// let config = Config::default().summary_json(Some("./run-summary.json"));
+    /// ```
+    pub fn summary_json(mut self, path: Option<impl AsRef<Path>>) -> Self {
+        self.summary_json = path.map(|p| p.as_ref().to_path_buf());
+        self
+    }
+
+    /// Selects whether `summary_json` is written as a single JSON document or a JUnit
+    /// `<testsuite>` document. Defaults to [`ReportFormat::Json`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vex2pdf::lib_utils::config::{Config, ReportFormat};
+    ///
+    /// let config = Config::default().report_format(ReportFormat::Junit);
+    /// ```
+    pub fn report_format(mut self, format: ReportFormat) -> Self {
+        self.report_format = format;
+        self
+    }
+
+    /// Skips PDF generation entirely and instead prints a per-file inspection report (document
+    /// type, spec version, tool metadata, component count, severity breakdown) to stdout.
+    /// Modeled on the rust compiler's `--print` query options. Off by default.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vex2pdf::lib_utils::config::Config;
+    ///
+    /// let config = Config::default().dry_run(true);
+    /// ```
+    pub fn dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Caps how many vulnerabilities of `severity` (e.g. `"critical"`, `"high"`) are tolerated
+    /// before [`crate::run`] fails with [`Vex2PdfError::SeverityThresholdExceeded`], once every
+    /// PDF has already been generated. Calling this again for the same severity overwrites its
+    /// previous threshold.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vex2pdf::lib_utils::config::Config;
+    ///
+    /// let config = Config::default().max_allowed("critical", 0).max_allowed("high", 2);
+    /// ```
+    pub fn max_allowed(mut self, severity: impl Into<String>, count: usize) -> Self {
+        self.max_allowed.insert(severity.into(), count);
+        self
+    }
+
+    /// Shorthand for [`Self::max_allowed`]`(severity, 0)`: the run fails if any vulnerability of
+    /// `severity` is found.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vex2pdf::lib_utils::config::Config;
+    ///
+    /// let config = Config::default().fail_on_severity("critical");
+    /// ```
+    pub fn fail_on_severity(self, severity: impl Into<String>) -> Self {
+        self.max_allowed(severity, 0)
+    }
+
+    /// When `true`, vulnerabilities analyzed as `not_affected`/`resolved` still count toward
+    /// [`Self::max_allowed`]'s gate. Off by default.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vex2pdf::lib_utils::config::Config;
+    ///
+    /// let config = Config::default().gate_count_analyzed(true);
+    /// ```
+    pub fn gate_count_analyzed(mut self, count_analyzed: bool) -> Self {
+        self.gate_count_analyzed = count_analyzed;
+        self
+    }
+
+    /// When `true`, [`crate::run`] keeps running after its initial conversion pass and
+    /// reconverts any BOM under [`Self::working_path`] whenever it changes on disk. Off by
+    /// default.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vex2pdf::lib_utils::config::Config;
+    ///
+    /// let config = Config::default().watch(true);
+    /// ```
+    pub fn watch(mut self, watch: bool) -> Self {
+        self.watch = watch;
+        self
+    }
+
+    fn apply_profile_overrides(mut self, overrides: &ProfileOverrides) -> Self {
+        if let Some(show_novulns_msg) = overrides.show_novulns_msg {
+            self.show_novulns_msg = show_novulns_msg;
+        }
+        if let Some(pure_bom_novulns) = overrides.pure_bom_novulns {
+            self.pure_bom_novulns = pure_bom_novulns;
+        }
+        if let Some(show_components) = overrides.show_components {
+            self.show_components = show_components;
+        }
+        self
+    }
+}
+
+impl Default for Config {
+    /// Creates a `Config` instance with default values for all configuration options.
+    ///
+    /// This implementation provides sensible defaults that match the application's
+    /// standard behavior when no environment variables are set. This does not process
+    /// any environment variables, if you need to process environment variables use `Config::build()`
+    /// instead.
+    ///
+    /// # Default Values
+    ///
+    /// - **working_path**: Current working directory
+    /// - **show_novulns_msg**: `true` - Display "No Vulnerabilities" message when applicable
+    /// - **file_types_to_process**: Both JSON and XML processing enabled (`true`)
+    /// - **show_component_licenses**: `true` - Display each component's license list
+    /// - **show_components**: `true` - Include component information in reports
+    /// - **report_title**: Default report title from `get_default_report_title()`
+    /// - **pdf_meta_name**: Default PDF metadata name from `get_default_pdf_meta_name()`
+    ///
+    /// # Behavior
+    ///
+    /// These defaults represent the "out-of-the-box" configuration that provides
+    /// the most comprehensive reporting. Users can override these values through
+    /// environment variables or by using `Config::build()` which respects
+    /// environment variable settings.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the current working directory cannot be determined.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vex2pdf::lib_utils::config::Config;
+    /// use std::default::Default;
+    ///
+    /// // Create config with all default values
+    /// let config = Config::default();
+    ///
+    /// // All processing options are set to defaults according to the most common, perceived, use
+    /// // This can be overridden using the respective environment variables check
+    /// assert_eq!(config.pure_bom_novulns,false);
+    /// assert_eq!(config.show_novulns_msg,true);
+    /// assert_eq!(config.show_components,true);
+    /// ```
+    ///
+    /// # See Also
+    ///
+    /// - `Config::build()` for environment-variable-aware configuration
+    /// - README.md for detailed environment variable documentation
+    fn default() -> Self {
+        let mut file_types_to_process: HashMap<InputFileType, bool> = HashMap::new();
+        file_types_to_process.insert(InputFileType::JSON, true);
+        file_types_to_process.insert(InputFileType::XML, true);
+        let working_path = std::env::current_dir().expect("Failed to get current directory");
+        let output_dir = working_path.clone();
+        Self {
+            working_path,
+            output_dir,
+            show_novulns_msg: true,
+            file_types_to_process: Some(file_types_to_process),
+            pure_bom_novulns: false,
+            show_components: true,
+            report_title: Some(Self::get_default_report_title().to_string()),
+            pdf_meta_name: Some(Self::get_default_pdf_meta_name().to_string()),
+            max_jobs: None,
+            items_per_job: None,
+            include_patterns: Vec::new(),
+            exclude_patterns: Vec::new(),
+            output_format: OutputFormat::default(),
+            profile: None,
+            recursive: false,
+            mirror_output_structure: false,
+            max_depth: None,
+            follow_symlinks: false,
+            respect_ignore_files: true,
+            include_hidden: false,
+            validate_schema: false,
+            file_mode: None,
+            owner: None,
+            group: None,
+            reproducible: false,
+            source_date_epoch: None,
+            only_severity: Vec::new(),
+            skip_severity: Vec::new(),
+            skip_state: Vec::new(),
+            manifest_path: None,
+            resume: false,
+            exclude_name_regex: None,
+            severity_palette: SeverityPalette::default(),
+            sort_vulns_by_severity: false,
+            advisory_db_path: None,
+            enrich_with_advisory_db: false,
+            natural_sort_lists: false,
+            theme: Theme::default(),
+            show_component_licenses: true,
+            show_component_hashes: true,
+            show_severity_summary: true,
+            merge: false,
+            template: None,
+            summary_json: None,
+            report_format: ReportFormat::default(),
+            dry_run: false,
+            max_allowed: HashMap::new(),
+            gate_count_analyzed: false,
+            watch: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builder_working_path() {
+        let config = Config::default().working_path("/tmp/test");
+        assert_eq!(config.working_path, PathBuf::from("/tmp/test"));
+    }
+
+    #[test]
+    fn test_builder_output_dir() {
+        let config = Config::default().output_dir("/tmp/output");
+        assert_eq!(config.output_dir, PathBuf::from("/tmp/output"));
+    }
+
+    #[test]
+    fn test_builder_show_novulns_msg() {
+        let config = Config::default().show_novulns_msg(false);
+        assert_eq!(config.show_novulns_msg, false);
+    }
+
+    #[test]
+    fn test_builder_pure_bom_novulns() {
+        let config = Config::default().pure_bom_novulns(true);
+        assert_eq!(config.pure_bom_novulns, true);
+    }
+
+    #[test]
+    fn test_builder_show_components() {
+        let config = Config::default().show_components(false);
+        assert_eq!(config.show_components, false);
+    }
+
+    #[test]
+    fn test_builder_report_title() {
+        let config = Config::default().report_title("Custom Title");
         assert_eq!(config.report_title, Some("Custom Title".to_string()));
     }
 
@@ -541,6 +2186,17 @@ mod tests {
         assert_eq!(config.max_jobs, Some(4));
     }
 
+    #[test]
+    fn test_builder_items_per_job() {
+        let config = Config::default().items_per_job(Some(50));
+        assert_eq!(config.items_per_job, Some(50));
+    }
+
+    #[test]
+    fn test_items_per_job_defaults_to_none() {
+        assert_eq!(Config::default().items_per_job, None);
+    }
+
     #[test]
     fn test_builder_chaining() {
         let config = Config::default()
@@ -566,6 +2222,360 @@ mod tests {
         assert!(config.working_path.exists());
     }
 
+    #[test]
+    fn test_builder_output_format() {
+        let config = Config::default().output_format(OutputFormat::Html);
+        assert_eq!(config.output_format, OutputFormat::Html);
+    }
+
+    #[test]
+    fn test_output_format_default_is_pdf() {
+        assert_eq!(Config::default().output_format, OutputFormat::Pdf);
+    }
+
+    #[test]
+    fn test_output_format_from_str() {
+        use std::str::FromStr;
+
+        assert_eq!(OutputFormat::from_str("pdf").unwrap(), OutputFormat::Pdf);
+        assert_eq!(OutputFormat::from_str("HTML").unwrap(), OutputFormat::Html);
+        assert!(OutputFormat::from_str("docx").is_err());
+    }
+
+    #[test]
+    fn test_apply_profile_pure_bom() {
+        let config = Config::default().apply_profile("pure-bom");
+        assert_eq!(config.pure_bom_novulns, true);
+        assert_eq!(config.show_novulns_msg, false);
+        assert_eq!(config.profile, Some("pure-bom".to_string()));
+    }
+
+    #[test]
+    fn test_apply_profile_minimal() {
+        let config = Config::default().apply_profile("minimal");
+        assert_eq!(config.show_components, false);
+        assert_eq!(config.show_novulns_msg, false);
+    }
+
+    #[test]
+    fn test_apply_profile_full() {
+        let config = Config::default()
+            .pure_bom_novulns(true)
+            .show_components(false)
+            .apply_profile("full");
+        assert_eq!(config.pure_bom_novulns, false);
+        assert_eq!(config.show_components, true);
+        assert_eq!(config.show_novulns_msg, true);
+    }
+
+    #[test]
+    fn test_apply_profile_unknown_name_is_recorded_but_not_applied() {
+        let config = Config::default().apply_profile("does-not-exist");
+        assert_eq!(config.profile, Some("does-not-exist".to_string()));
+        assert_eq!(config.show_novulns_msg, true); // unchanged from default
+    }
+
+    #[test]
+    fn test_builder_recursive() {
+        let config = Config::default().recursive(true);
+        assert_eq!(config.recursive, true);
+    }
+
+    #[test]
+    fn test_recursive_default_is_false() {
+        assert_eq!(Config::default().recursive, false);
+    }
+
+    #[test]
+    fn test_builder_mirror_output_structure() {
+        let config = Config::default().mirror_output_structure(true);
+        assert_eq!(config.mirror_output_structure, true);
+    }
+
+    #[test]
+    fn test_mirror_output_structure_default_is_false() {
+        assert_eq!(Config::default().mirror_output_structure, false);
+    }
+
+    #[test]
+    fn test_builder_validate_schema() {
+        let config = Config::default().validate_schema(true);
+        assert_eq!(config.validate_schema, true);
+    }
+
+    #[test]
+    fn test_validate_schema_default_is_false() {
+        assert_eq!(Config::default().validate_schema, false);
+    }
+
+    #[test]
+    fn test_builder_file_mode() {
+        let config = Config::default().file_mode(Some(0o640));
+        assert_eq!(config.file_mode, Some(0o640));
+    }
+
+    #[test]
+    fn test_builder_owner_and_group() {
+        let config = Config::default().owner("svc-reports").group("svc-reports");
+        assert_eq!(config.owner, Some("svc-reports".to_string()));
+        assert_eq!(config.group, Some("svc-reports".to_string()));
+    }
+
+    #[test]
+    fn test_file_mode_owner_group_default_to_none() {
+        let config = Config::default();
+        assert_eq!(config.file_mode, None);
+        assert_eq!(config.owner, None);
+        assert_eq!(config.group, None);
+    }
+
+    #[test]
+    fn test_builder_reproducible() {
+        let config = Config::default().reproducible(true);
+        assert_eq!(config.reproducible, true);
+    }
+
+    #[test]
+    fn test_reproducible_default_is_false() {
+        assert_eq!(Config::default().reproducible, false);
+    }
+
+    #[test]
+    fn test_builder_source_date_epoch() {
+        let config = Config::default().source_date_epoch(Some(1_704_067_200));
+        assert_eq!(config.source_date_epoch, Some(1_704_067_200));
+    }
+
+    #[test]
+    fn test_builder_severity_palette() {
+        let palette = SeverityPalette {
+            critical: (1, 2, 3),
+            ..SeverityPalette::default()
+        };
+        let config = Config::default().severity_palette(palette);
+        assert_eq!(config.severity_palette.critical, (1, 2, 3));
+    }
+
+    #[test]
+    fn test_severity_palette_default_is_used_by_default() {
+        assert_eq!(
+            Config::default().severity_palette,
+            SeverityPalette::default()
+        );
+    }
+
+    #[test]
+    fn test_builder_sort_vulns_by_severity() {
+        let config = Config::default().sort_vulns_by_severity(true);
+        assert!(config.sort_vulns_by_severity);
+    }
+
+    #[test]
+    fn test_sort_vulns_by_severity_defaults_to_false() {
+        assert!(!Config::default().sort_vulns_by_severity);
+    }
+
+    #[test]
+    fn test_builder_advisory_db_path() {
+        let config = Config::default().advisory_db_path(Some("./advisory-db"));
+        assert_eq!(
+            config.advisory_db_path,
+            Some(PathBuf::from("./advisory-db"))
+        );
+    }
+
+    #[test]
+    fn test_builder_enrich_with_advisory_db() {
+        let config = Config::default().enrich_with_advisory_db(true);
+        assert!(config.enrich_with_advisory_db);
+    }
+
+    #[test]
+    fn test_advisory_enrichment_disabled_by_default() {
+        let config = Config::default();
+        assert_eq!(config.advisory_db_path, None);
+        assert!(!config.enrich_with_advisory_db);
+    }
+
+    #[test]
+    fn test_builder_natural_sort_lists() {
+        let config = Config::default().natural_sort_lists(true);
+        assert!(config.natural_sort_lists);
+    }
+
+    #[test]
+    fn test_natural_sort_lists_defaults_to_false() {
+        assert!(!Config::default().natural_sort_lists);
+    }
+
+    #[test]
+    fn test_builder_theme() {
+        let config = Config::default().theme(Theme::Dark);
+        assert_eq!(config.theme, Theme::Dark);
+    }
+
+    #[test]
+    fn test_theme_defaults_to_light() {
+        assert_eq!(Config::default().theme, Theme::Light);
+    }
+
+    #[test]
+    fn test_theme_from_str() {
+        use std::str::FromStr;
+
+        assert_eq!(Theme::from_str("light").unwrap(), Theme::Light);
+        assert_eq!(Theme::from_str("DARK").unwrap(), Theme::Dark);
+        assert!(Theme::from_str("neon").is_err());
+    }
+
+    #[test]
+    fn test_builder_show_component_licenses() {
+        let config = Config::default().show_component_licenses(false);
+        assert!(!config.show_component_licenses);
+    }
+
+    #[test]
+    fn test_show_component_licenses_defaults_to_true() {
+        assert!(Config::default().show_component_licenses);
+    }
+
+    #[test]
+    fn test_builder_show_component_hashes() {
+        let config = Config::default().show_component_hashes(false);
+        assert!(!config.show_component_hashes);
+    }
+
+    #[test]
+    fn test_show_component_hashes_defaults_to_true() {
+        assert!(Config::default().show_component_hashes);
+    }
+
+    #[test]
+    fn test_builder_show_severity_summary() {
+        let config = Config::default().show_severity_summary(false);
+        assert!(!config.show_severity_summary);
+    }
+
+    #[test]
+    fn test_show_severity_summary_defaults_to_true() {
+        assert!(Config::default().show_severity_summary);
+    }
+
+    #[test]
+    fn test_builder_merge() {
+        let config = Config::default().merge(true);
+        assert!(config.merge);
+    }
+
+    #[test]
+    fn test_merge_defaults_to_false() {
+        assert!(!Config::default().merge);
+    }
+
+    #[test]
+    fn test_builder_template() {
+        let config = Config::default().template(Some("./report-template.json"));
+        assert_eq!(
+            config.template,
+            Some(PathBuf::from("./report-template.json"))
+        );
+    }
+
+    #[test]
+    fn test_template_defaults_to_none() {
+        assert_eq!(Config::default().template, None);
+    }
+
+    #[test]
+    fn test_builder_summary_json() {
+        let config = Config::default().summary_json(Some("./run-summary.json"));
+        assert_eq!(
+            config.summary_json,
+            Some(PathBuf::from("./run-summary.json"))
+        );
+    }
+
+    #[test]
+    fn test_summary_json_defaults_to_none() {
+        assert_eq!(Config::default().summary_json, None);
+    }
+
+    #[test]
+    fn test_builder_report_format() {
+        let config = Config::default().report_format(ReportFormat::Junit);
+        assert_eq!(config.report_format, ReportFormat::Junit);
+    }
+
+    #[test]
+    fn test_report_format_defaults_to_json() {
+        assert_eq!(Config::default().report_format, ReportFormat::Json);
+    }
+
+    #[test]
+    fn test_report_format_from_str() {
+        use std::str::FromStr;
+
+        assert_eq!(ReportFormat::from_str("json").unwrap(), ReportFormat::Json);
+        assert_eq!(
+            ReportFormat::from_str("JUNIT").unwrap(),
+            ReportFormat::Junit
+        );
+        assert!(ReportFormat::from_str("tap").is_err());
+    }
+
+    #[test]
+    fn test_builder_dry_run() {
+        let config = Config::default().dry_run(true);
+        assert!(config.dry_run);
+    }
+
+    #[test]
+    fn test_dry_run_defaults_to_false() {
+        assert!(!Config::default().dry_run);
+    }
+
+    #[test]
+    fn test_builder_max_allowed() {
+        let config = Config::default()
+            .max_allowed("critical", 0)
+            .max_allowed("high", 2);
+        assert_eq!(config.max_allowed.get("critical"), Some(&0));
+        assert_eq!(config.max_allowed.get("high"), Some(&2));
+    }
+
+    #[test]
+    fn test_builder_fail_on_severity_is_zero_threshold() {
+        let config = Config::default().fail_on_severity("critical");
+        assert_eq!(config.max_allowed.get("critical"), Some(&0));
+    }
+
+    #[test]
+    fn test_max_allowed_defaults_to_empty() {
+        assert!(Config::default().max_allowed.is_empty());
+    }
+
+    #[test]
+    fn test_builder_gate_count_analyzed() {
+        let config = Config::default().gate_count_analyzed(true);
+        assert!(config.gate_count_analyzed);
+    }
+
+    #[test]
+    fn test_gate_count_analyzed_defaults_to_false() {
+        assert!(!Config::default().gate_count_analyzed);
+    }
+
+    #[test]
+    fn test_builder_watch() {
+        let config = Config::default().watch(true);
+        assert!(config.watch);
+    }
+
+    #[test]
+    fn test_watch_defaults_to_false() {
+        assert!(!Config::default().watch);
+    }
+
     #[test]
     fn test_get_default_titles() {
         assert_eq!(
@@ -577,4 +2587,120 @@ mod tests {
             "VEX Vulnerability Report"
         );
     }
+
+    #[cfg(feature = "cli")]
+    #[test]
+    fn test_config_file_load_parses_toml() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let file_path = temp_dir.path().join(".vex2pdf.toml");
+        std::fs::write(
+            &file_path,
+            r#"
+            report_title = "From File"
+            max_jobs = 2
+            show_components = false
+            "#,
+        )
+        .unwrap();
+
+        let parsed = ConfigFile::load(&file_path).unwrap();
+        assert_eq!(parsed.report_title, Some("From File".to_string()));
+        assert_eq!(parsed.max_jobs, Some(2));
+        assert_eq!(parsed.show_components, Some(false));
+        assert_eq!(parsed.working_path, None);
+    }
+
+    #[cfg(feature = "cli")]
+    #[test]
+    fn test_config_file_load_parses_yaml() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let file_path = temp_dir.path().join(".vex2pdf.yaml");
+        std::fs::write(
+            &file_path,
+            "report_title: From File\nmax_jobs: 2\nshow_components: false\n",
+        )
+        .unwrap();
+
+        let parsed = ConfigFile::load(&file_path).unwrap();
+        assert_eq!(parsed.report_title, Some("From File".to_string()));
+        assert_eq!(parsed.max_jobs, Some(2));
+        assert_eq!(parsed.show_components, Some(false));
+    }
+
+    #[cfg(feature = "cli")]
+    #[test]
+    fn test_config_file_load_ignores_unknown_key() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let file_path = temp_dir.path().join(".vex2pdf.toml");
+        std::fs::write(&file_path, "max_jobs = 2\nnot_a_real_option = true\n").unwrap();
+
+        let parsed = ConfigFile::load(&file_path).unwrap();
+        assert_eq!(parsed.max_jobs, Some(2));
+    }
+
+    #[cfg(feature = "cli")]
+    #[test]
+    fn test_config_file_discover_prefers_toml_over_yaml() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(temp_dir.path().join(".vex2pdf.toml"), "max_jobs = 4").unwrap();
+        std::fs::write(temp_dir.path().join(".vex2pdf.yaml"), "max_jobs: 8").unwrap();
+
+        let found = ConfigFile::discover(temp_dir.path()).unwrap();
+        assert_eq!(found, temp_dir.path().join(".vex2pdf.toml"));
+    }
+
+    #[cfg(feature = "cli")]
+    #[test]
+    fn test_config_file_discover_walks_up_ancestors() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let nested = temp_dir.path().join("a").join("b");
+        std::fs::create_dir_all(&nested).unwrap();
+        std::fs::write(temp_dir.path().join(".vex2pdf.toml"), "max_jobs = 4").unwrap();
+
+        let found = ConfigFile::discover(&nested).expect("should find ancestor config file");
+        assert_eq!(found, temp_dir.path().join(".vex2pdf.toml"));
+    }
+
+    #[cfg(feature = "cli")]
+    #[test]
+    fn test_config_file_discover_returns_none_when_absent() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        assert!(ConfigFile::discover(temp_dir.path()).is_none());
+    }
+
+    #[cfg(feature = "cli")]
+    #[test]
+    fn test_config_file_load_names_path_on_parse_error() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let file_path = temp_dir.path().join("broken.toml");
+        std::fs::write(&file_path, "max_jobs = [not valid toml").unwrap();
+
+        let err = ConfigFile::load(&file_path).unwrap_err();
+        assert!(err.to_string().contains(&file_path.display().to_string()));
+    }
+
+    #[cfg(feature = "cli")]
+    #[test]
+    fn test_config_file_parses_custom_profiles_table() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let file_path = temp_dir.path().join(".vex2pdf.toml");
+        std::fs::write(
+            &file_path,
+            r#"
+            [profiles.ci]
+            show_components = false
+            pure_bom_novulns = true
+            "#,
+        )
+        .unwrap();
+
+        let parsed = ConfigFile::load(&file_path).unwrap();
+        let ci_profile = parsed
+            .profiles
+            .expect("profiles table should be present")
+            .remove("ci")
+            .expect("ci profile should be present");
+        assert_eq!(ci_profile.show_components, Some(false));
+        assert_eq!(ci_profile.pure_bom_novulns, Some(true));
+    }
 }
@@ -45,6 +45,102 @@ pub enum EnvVarNames {
     /// - 1 runs in single-threaded mode which means no threads are spawned and the jobs are run in the main thread
     /// - Any integer `N` would be the number of threads the tool runs with, this saturates at [`std::thread::available_parallelism`] which is the default number of jobs if no Job number is passed or set
     MaxJobs,
+    /// Number of items [`crate::files_proc::processor::ProcessorReady::process`] batches into a
+    /// single job before dispatching it to a worker. Higher values reduce per-item channel/lock
+    /// overhead at the cost of coarser load balancing. Defaults to
+    /// [`crate::lib_utils::concurrency::threadpool::DEFAULT_ITEMS_PER_JOB`] when unset.
+    ItemsPerJob,
+    /// Comma-separated list of glob patterns; a file must match at least one to be processed.
+    /// When unset, every discovered file is a candidate.
+    IncludePatterns,
+    /// Comma-separated list of glob patterns; a file matching any of these is skipped even if
+    /// it matches [`Self::IncludePatterns`].
+    ExcludePatterns,
+    /// Selects the report renderer (`"pdf"` or `"html"`). Defaults to `pdf` when unset.
+    OutputFormat,
+    /// Selects a named report profile, either a built-in preset or one defined in the
+    /// `.vex2pdf.toml` config file.
+    Profile,
+    /// When set, walks the working directory tree instead of scanning just its first level.
+    Recursive,
+    /// When set alongside recursive scanning and an output directory, reproduces each input
+    /// file's subdirectory under the output directory instead of flattening every generated PDF
+    /// into it.
+    MirrorOutputStructure,
+    /// Caps how many directory levels a recursive scan descends below the working path.
+    MaxDepth,
+    /// When set, a recursive scan follows symbolic links to directories instead of skipping
+    /// them.
+    FollowSymlinks,
+    /// When set, a recursive scan honors `.gitignore`/`.ignore`/`.vex2pdfignore` files
+    /// encountered while walking. On by default.
+    RespectIgnoreFiles,
+    /// When set, a recursive scan also considers hidden files and directories (dotfiles).
+    IncludeHidden,
+    /// When set, validates each input document against the bundled CycloneDX JSON Schema for
+    /// its effective spec version before conversion.
+    ValidateSchema,
+    /// Path to a config file to load directly, bypassing the usual `.vex2pdf.toml` discovery.
+    ConfigFilePath,
+    /// Octal file mode (e.g. `640`) applied to each generated PDF after it's written.
+    FileMode,
+    /// Unix user name to `chown` each generated PDF to after it's written.
+    Owner,
+    /// Unix group name to `chown` each generated PDF to after it's written.
+    Group,
+    /// When set, pins the dynamic `CreationDate`/`ModDate`/XMP date fields of generated PDFs and
+    /// derives their trailer `/ID` and XMP ids from the content, so identical input yields
+    /// byte-identical output.
+    Reproducible,
+    /// The reproducible-builds standard variable: a Unix timestamp used as the fixed instant
+    /// for generated PDFs' `CreationDate`/`ModDate` when [`Self::Reproducible`] is on. Unlike
+    /// every other variable here, this is read under its own name rather than a `VEX2PDF_`
+    /// prefix, since that's the name tooling across the ecosystem already agrees on.
+    SourceDateEpoch,
+    /// Comma-separated list of severity names; a vulnerability must match at least one to be
+    /// included in the report.
+    OnlySeverity,
+    /// Comma-separated list of severity names that exclude a vulnerability from the report.
+    SkipSeverity,
+    /// Comma-separated list of analysis state names that exclude a vulnerability from the
+    /// report.
+    SkipState,
+    /// Path to write a BLAKE3 checksum manifest listing every generated PDF after conversion.
+    Manifest,
+    /// Skips regenerating a PDF whose output is already newer than its source, and maintains a
+    /// checkpoint manifest in `output_dir` so an interrupted run can resume where it left off.
+    Resume,
+    /// Regex matched against a candidate file's name; a match excludes the file from
+    /// processing, same as a hit on [`Self::ExcludePatterns`].
+    ExcludeNameRegex,
+    /// Combines every discovered document into a single consolidated PDF report instead of
+    /// converting each one individually.
+    Merge,
+    /// Path to a JSON report template describing an ordered section layout. See
+    /// [`crate::pdf::template`].
+    Template,
+    /// Path to write a machine-readable JSON summary of the run. See
+    /// [`crate::files_proc::run_summary`].
+    SummaryJson,
+    /// Selects whether `SummaryJson` is written as JSON or JUnit. See
+    /// [`crate::lib_utils::config::ReportFormat`].
+    ReportFormat,
+    /// When set, skips PDF generation and prints a per-file inspection report to stdout instead.
+    DryRun,
+    /// Comma-separated list of severity names that must have zero findings after processing, or
+    /// the run fails once every PDF has been generated. Shorthand for setting [`Self::MaxAllowed`]
+    /// to `0` for each named severity.
+    FailOnSeverity,
+    /// Comma-separated list of `severity=count` pairs (e.g. `critical=0,high=2`) capping how many
+    /// vulnerabilities of each severity band are tolerated before the run fails, once every PDF
+    /// has been generated.
+    MaxAllowed,
+    /// When set, vulnerabilities analyzed as `not_affected`/`resolved` still count toward
+    /// [`Self::MaxAllowed`]'s gate. Off by default.
+    GateCountAnalyzed,
+    /// When set, keeps running after the initial conversion and reconverts any BOM under
+    /// [`Self::WorkingPath`] whenever it changes on disk, via [`crate::files_proc::watch`].
+    Watch,
 }
 
 #[allow(deprecated)]
@@ -64,8 +160,54 @@ impl EnvVarNames {
             EnvVarNames::OutputDir => "VEX2PDF_OUTPUT_DIR",
             EnvVarNames::WorkingPath => "VEX2PDF_WORKING_PATH",
             EnvVarNames::MaxJobs => "VEX2PDF_MAX_JOBS",
+            EnvVarNames::ItemsPerJob => "VEX2PDF_ITEMS_PER_JOB",
+            EnvVarNames::IncludePatterns => "VEX2PDF_INCLUDE",
+            EnvVarNames::ExcludePatterns => "VEX2PDF_EXCLUDE",
+            EnvVarNames::OutputFormat => "VEX2PDF_FORMAT",
+            EnvVarNames::Profile => "VEX2PDF_PROFILE",
+            EnvVarNames::Recursive => "VEX2PDF_RECURSIVE",
+            EnvVarNames::MirrorOutputStructure => "VEX2PDF_MIRROR_OUTPUT_STRUCTURE",
+            EnvVarNames::MaxDepth => "VEX2PDF_MAX_DEPTH",
+            EnvVarNames::FollowSymlinks => "VEX2PDF_FOLLOW_SYMLINKS",
+            EnvVarNames::RespectIgnoreFiles => "VEX2PDF_RESPECT_IGNORE_FILES",
+            EnvVarNames::IncludeHidden => "VEX2PDF_INCLUDE_HIDDEN",
+            EnvVarNames::ValidateSchema => "VEX2PDF_VALIDATE_SCHEMA",
+            EnvVarNames::ConfigFilePath => "VEX2PDF_CONFIG_FILE",
+            EnvVarNames::FileMode => "VEX2PDF_FILE_MODE",
+            EnvVarNames::Owner => "VEX2PDF_OWNER",
+            EnvVarNames::Group => "VEX2PDF_GROUP",
+            EnvVarNames::Reproducible => "VEX2PDF_REPRODUCIBLE",
+            EnvVarNames::SourceDateEpoch => "SOURCE_DATE_EPOCH",
+            EnvVarNames::OnlySeverity => "VEX2PDF_ONLY_SEVERITY",
+            EnvVarNames::SkipSeverity => "VEX2PDF_SKIP_SEVERITY",
+            EnvVarNames::SkipState => "VEX2PDF_SKIP_STATE",
+            EnvVarNames::Manifest => "VEX2PDF_MANIFEST",
+            EnvVarNames::Resume => "VEX2PDF_RESUME",
+            EnvVarNames::ExcludeNameRegex => "VEX2PDF_EXCLUDE_NAME_REGEX",
+            EnvVarNames::Merge => "VEX2PDF_MERGE",
+            EnvVarNames::Template => "VEX2PDF_TEMPLATE",
+            EnvVarNames::SummaryJson => "VEX2PDF_SUMMARY_JSON",
+            EnvVarNames::ReportFormat => "VEX2PDF_REPORT_FORMAT",
+            EnvVarNames::DryRun => "VEX2PDF_DRY_RUN",
+            EnvVarNames::FailOnSeverity => "VEX2PDF_FAIL_ON_SEVERITY",
+            EnvVarNames::MaxAllowed => "VEX2PDF_MAX_ALLOWED",
+            EnvVarNames::GateCountAnalyzed => "VEX2PDF_GATE_COUNT_ANALYZED",
+            EnvVarNames::Watch => "VEX2PDF_WATCH",
         }
     }
+
+    /// Parses the variable as a comma-separated list of patterns, trimming whitespace around
+    /// each entry and dropping empty entries. Returns `None` when the variable isn't set.
+    pub fn get_list(&self) -> Option<Vec<String>> {
+        self.get_value().map(|value| {
+            value
+                .split(',')
+                .map(str::trim)
+                .filter(|s| !s.is_empty())
+                .map(str::to_string)
+                .collect()
+        })
+    }
     /// this is useful for environment variables which should be on by default
     pub fn is_on_or_unset(&self) -> bool {
         match std::env::var(self.as_str()) {
@@ -81,6 +223,16 @@ impl EnvVarNames {
         }
     }
 
+    /// Returns the variable's boolean value, or `None` if it isn't set.
+    ///
+    /// Unlike [`Self::is_on`]/[`Self::is_on_or_unset`], this does not bake in a default for the
+    /// unset case, which lets callers layer a config-file or built-in default underneath it.
+    pub fn get_bool(&self) -> Option<bool> {
+        std::env::var(self.as_str())
+            .ok()
+            .map(|value| self.is_value_on(&value))
+    }
+
     /// Prints information about currently used pdf titles
     pub fn print_report_titles_info() {
         info!("");
@@ -168,4 +320,40 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_get_bool() {
+        let var = EnvVarNames::ShowComponentList; // must be different than other tests to avoid race conditions
+
+        std::env::remove_var(var.as_str());
+        assert_eq!(var.get_bool(), None, "should be None when unset");
+
+        std::env::set_var(var.as_str(), "false");
+        assert_eq!(var.get_bool(), Some(false));
+
+        std::env::set_var(var.as_str(), "true");
+        assert_eq!(var.get_bool(), Some(true));
+
+        std::env::remove_var(var.as_str());
+    }
+
+    #[test]
+    fn test_get_list() {
+        let var = EnvVarNames::IncludePatterns;
+
+        std::env::remove_var(var.as_str());
+        assert_eq!(var.get_list(), None);
+
+        std::env::set_var(var.as_str(), "*.json, *.xml ,, bom-*.json");
+        assert_eq!(
+            var.get_list(),
+            Some(vec![
+                "*.json".to_string(),
+                "*.xml".to_string(),
+                "bom-*.json".to_string(),
+            ])
+        );
+
+        std::env::remove_var(var.as_str());
+    }
 }
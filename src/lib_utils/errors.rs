@@ -6,7 +6,9 @@ use std::sync::mpsc;
 /// This enum encapsulates all errors that might be thrown in this crate
 #[derive(Debug)]
 pub enum Vex2PdfError {
-    /// Good old IO errors
+    /// Good old IO errors. Anywhere this originates from [`crate::lib_utils::fs_context`]'s
+    /// wrappers, the inner error's `Display` already names the offending path and operation
+    /// (e.g. "failed to write `out/foo.pdf`: permission denied") rather than a bare OS message.
     Io(io::Error),
     /// Invalid output path. This usually triggers when a file is given instead of a path for the output dir parameter
     InvalidOutputDir(PathBuf),
@@ -20,6 +22,43 @@ pub enum Vex2PdfError {
     IgnoredByUser,
     /// Concurrency error
     ConcurrencyError(String),
+    /// Requested output format has no renderer implemented yet
+    UnsupportedOutputFormat(String),
+    /// The `upgrade` subcommand failed to check or install a new release
+    Upgrade(String),
+    /// Input document failed CycloneDX JSON Schema validation; carries every violation found,
+    /// not just the first
+    SchemaInvalid(Vec<String>),
+    /// The `--verify` subcommand found a PDF that failed to parse or whose page tree couldn't
+    /// be walked; carries every failing file's error, not just the first
+    PdfValidation(String),
+    /// The `check-manifest` subcommand found an entry whose recomputed checksum no longer
+    /// matches, or whose file is missing; carries every failing entry, not just the first
+    ManifestMismatch(String),
+    /// Processing this file was abandoned partway through because a Ctrl-C cancellation was
+    /// requested; not a failure in the usual sense, so callers report it separately
+    Cancelled,
+    /// The aggregated vulnerability findings from this run exceeded a configured
+    /// [`crate::lib_utils::config::Config::max_allowed`] threshold; carries one formatted line
+    /// per offending severity band, not just the first. Returned only after every PDF has
+    /// already been generated, so the report is still available as a CI artifact.
+    SeverityThresholdExceeded(Vec<String>),
+    /// `--watch` failed to start or lost its filesystem notifier mid-run; see
+    /// [`crate::files_proc::watch`].
+    Watch(String),
+    /// [`crate::pdf::snapshot::run_snapshot`] found a PDF whose normalized checksum no longer
+    /// matches the one recorded for it, or that has no recorded checksum at all; carries one
+    /// formatted line per offending file, not just the first.
+    SnapshotMismatch(Vec<String>),
+    /// Processing a single file panicked (e.g. a malformed BOM tripping an `unwrap()` deep in a
+    /// parser), caught right around that file in
+    /// [`crate::files_proc::processor`] so the rest of its chunk keeps going rather than being
+    /// dropped along with it; `worker_id` is `0` since it isn't tied to a specific worker there.
+    /// A panic that somehow escapes that catch and unwinds into
+    /// [`crate::lib_utils::concurrency::worker::Worker`]'s own loop is caught there instead, with
+    /// a real `worker_id`, so it's still reported like any other per-file failure instead of
+    /// aborting the whole process during teardown.
+    WorkerPanicked { worker_id: u8, message: String },
 }
 
 impl Display for Vex2PdfError {
@@ -38,6 +77,62 @@ impl Display for Vex2PdfError {
             Vex2PdfError::UnsupportedFileType => write!(f, "Unsupported file type for parsing"),
             Vex2PdfError::IgnoredByUser => write!(f, "file ignored explicitly by user"),
             Vex2PdfError::ConcurrencyError(s) => write!(f, "Concurrency error : {s}"),
+            Vex2PdfError::UnsupportedOutputFormat(s) => {
+                write!(f, "output format `{s}` is not supported yet")
+            }
+            Vex2PdfError::Upgrade(s) => write!(f, "self-update failed: {s}"),
+            Vex2PdfError::SchemaInvalid(violations) => {
+                writeln!(
+                    f,
+                    "document failed CycloneDX schema validation ({} violation{}):",
+                    violations.len(),
+                    if violations.len() == 1 { "" } else { "s" }
+                )?;
+                for (i, violation) in violations.iter().enumerate() {
+                    if i > 0 {
+                        writeln!(f)?;
+                    }
+                    write!(f, "  - {violation}")?;
+                }
+                Ok(())
+            }
+            Vex2PdfError::PdfValidation(message) => write!(f, "{}", message),
+            Vex2PdfError::ManifestMismatch(message) => write!(f, "{}", message),
+            Vex2PdfError::Cancelled => write!(f, "processing cancelled by user request"),
+            Vex2PdfError::SeverityThresholdExceeded(violations) => {
+                writeln!(
+                    f,
+                    "severity threshold exceeded ({} violation{}):",
+                    violations.len(),
+                    if violations.len() == 1 { "" } else { "s" }
+                )?;
+                for (i, violation) in violations.iter().enumerate() {
+                    if i > 0 {
+                        writeln!(f)?;
+                    }
+                    write!(f, "  - {violation}")?;
+                }
+                Ok(())
+            }
+            Vex2PdfError::Watch(s) => write!(f, "watch mode failed: {s}"),
+            Vex2PdfError::SnapshotMismatch(violations) => {
+                writeln!(
+                    f,
+                    "PDF checksum snapshot mismatch ({} violation{}):",
+                    violations.len(),
+                    if violations.len() == 1 { "" } else { "s" }
+                )?;
+                for (i, violation) in violations.iter().enumerate() {
+                    if i > 0 {
+                        writeln!(f)?;
+                    }
+                    write!(f, "  - {violation}")?;
+                }
+                Ok(())
+            }
+            Vex2PdfError::WorkerPanicked { worker_id, message } => {
+                write!(f, "worker {worker_id} panicked: {message}")
+            }
         }
     }
 }
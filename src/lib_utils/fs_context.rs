@@ -0,0 +1,111 @@
+//! Thin wrappers around [`std::fs`] (and [`std::fs::File`]) that annotate any [`io::Error`]
+//! they produce with the path and the attempted operation (open/read/create/write), in the
+//! spirit of the `fs-err` crate.
+//!
+//! A bare [`std::io::Error`] renders as something like "The system cannot find the file
+//! specified (os error 2)", with no indication of which path or operation failed. Since
+//! [`Vex2PdfError::Io`](crate::lib_utils::errors::Vex2PdfError::Io) wraps an `io::Error`
+//! unchanged, call these instead of `std::fs`/`std::fs::File` directly anywhere a failure should
+//! end up readable in batch diagnostics, e.g. "failed to write `out/foo.pdf`: permission denied"
+//! rather than just "permission denied".
+
+use std::fmt::{Display, Formatter};
+use std::fs::{self, File};
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// An [`io::Error`] annotated with the path and operation that produced it.
+///
+/// Never surfaced as its own type: [`wrap`] embeds it as the custom payload of an `io::Error`,
+/// whose `Display` forwards to this one, so every existing `io::Error`/`Vex2PdfError::Io` call
+/// site picks up the richer message for free.
+#[derive(Debug)]
+struct PathContext {
+    path: PathBuf,
+    operation: &'static str,
+    source: io::Error,
+}
+
+impl Display for PathContext {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "failed to {} `{}`: {}",
+            self.operation,
+            self.path.display(),
+            self.source
+        )
+    }
+}
+
+impl std::error::Error for PathContext {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+fn wrap(path: &Path, operation: &'static str, source: io::Error) -> io::Error {
+    io::Error::new(
+        source.kind(),
+        PathContext {
+            path: path.to_path_buf(),
+            operation,
+            source,
+        },
+    )
+}
+
+/// Wraps [`std::fs::read`].
+pub(crate) fn read(path: impl AsRef<Path>) -> io::Result<Vec<u8>> {
+    let path = path.as_ref();
+    fs::read(path).map_err(|e| wrap(path, "read", e))
+}
+
+/// Wraps [`std::fs::read_to_string`].
+pub(crate) fn read_to_string(path: impl AsRef<Path>) -> io::Result<String> {
+    let path = path.as_ref();
+    fs::read_to_string(path).map_err(|e| wrap(path, "read", e))
+}
+
+/// Wraps [`std::fs::write`].
+pub(crate) fn write(path: impl AsRef<Path>, contents: impl AsRef<[u8]>) -> io::Result<()> {
+    let path = path.as_ref();
+    fs::write(path, contents).map_err(|e| wrap(path, "write", e))
+}
+
+/// Wraps [`std::fs::read_dir`].
+pub(crate) fn read_dir(path: impl AsRef<Path>) -> io::Result<fs::ReadDir> {
+    let path = path.as_ref();
+    fs::read_dir(path).map_err(|e| wrap(path, "open", e))
+}
+
+/// Wraps [`std::fs::metadata`].
+pub(crate) fn metadata(path: impl AsRef<Path>) -> io::Result<fs::Metadata> {
+    let path = path.as_ref();
+    fs::metadata(path).map_err(|e| wrap(path, "open", e))
+}
+
+/// Wraps [`std::fs::File::create`].
+pub(crate) fn create(path: impl AsRef<Path>) -> io::Result<File> {
+    let path = path.as_ref();
+    File::create(path).map_err(|e| wrap(path, "create", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_missing_file_names_path_and_operation() {
+        let err = read("/nonexistent/path/for/vex2pdf-fs-context-test").unwrap_err();
+        let message = err.to_string();
+        assert!(message.contains("failed to read"));
+        assert!(message.contains("/nonexistent/path/for/vex2pdf-fs-context-test"));
+    }
+
+    #[test]
+    fn test_write_preserves_error_kind() {
+        let err = write("/nonexistent/dir/for/vex2pdf-fs-context-test/file", b"x").unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::NotFound);
+    }
+}
@@ -1,9 +1,13 @@
 use crate::lib_utils::errors::Vex2PdfError;
+use crate::lib_utils::fs_context;
+use crate::lib_utils::schema_validation::{self, SchemaVersion};
 use cyclonedx_bom::errors::{BomError, JsonReadError, XmlReadError};
 use cyclonedx_bom::prelude::Bom;
 use log::warn;
+use serde_json::Value;
 use std::error::Error;
 use std::fs;
+use std::io;
 use std::path::{Path, PathBuf};
 
 /// Returns the application version and copyright text.
@@ -12,7 +16,9 @@ use std::path::{Path, PathBuf};
 /// version information at startup.
 pub const fn get_version_info() -> &'static str {
     concat!(
-        "vex2pdf ", env!("CARGO_PKG_VERSION"), " - CycloneDX (VEX) to PDF Converter\n",
+        "vex2pdf ",
+        env!("CARGO_PKG_VERSION"),
+        " - CycloneDX (VEX) to PDF Converter\n",
         "Copyright (c) 2025 Salem B. - MIT Or Apache 2.0 License"
     )
 }
@@ -25,13 +31,21 @@ pub const fn get_version_info() -> &'static str {
 ///
 /// Note: The downgrade from 1.6 to 1.5 is a compatibility feature and may not work
 /// if the document uses 1.6-specific fields.
-pub(crate) fn parse_vex_xml<P: AsRef<Path>>(path: P) -> Result<Bom, Box<dyn Error>> {
+///
+/// When `validate_schema` is set, the document (converted to its JSON representation, since
+/// CycloneDX publishes a JSON Schema but no XSD-equivalent tooling here) is checked against the
+/// bundled 1.5 schema before returning; a schema-invalid document errors with every violation
+/// collected rather than just the first.
+pub(crate) fn parse_vex_xml<P: AsRef<Path>>(
+    path: P,
+    validate_schema: bool,
+) -> Result<Bom, Box<dyn Error>> {
     // First, read the entire file content
-    let content = fs::read(path)?;
+    let content = fs_context::read(path)?;
 
     // try to parse xml bom
-    match Bom::parse_from_xml_v1_5(&content[..]) {
-        Ok(bom) => Ok(bom),
+    let bom = match Bom::parse_from_xml_v1_5(&content[..]) {
+        Ok(bom) => bom,
         Err(err) => match &err {
             XmlReadError::InvalidNamespaceError {
                 expected_namespace,
@@ -49,16 +63,27 @@ pub(crate) fn parse_vex_xml<P: AsRef<Path>>(path: P) -> Result<Bom, Box<dyn Erro
                         let modified_xml = xml_str.replace(actual, expected_namespace);
 
                         // Try parsing with the modified XML
-                        return Ok(Bom::parse_from_xml_v1_5(modified_xml.as_bytes())?);
+                        Bom::parse_from_xml_v1_5(modified_xml.as_bytes())?
+                    } else {
+                        // if we get here we couldn't handle the namespace error
+                        return Err(Box::new(err));
                     }
+                } else {
+                    return Err(Box::new(err));
                 }
-
-                // if we get here we couldn't handle the namespace error
-                Err(Box::new(err))
             }
-            _ => Err(Box::new(err)),
+            _ => return Err(Box::new(err)),
         },
+    };
+
+    if validate_schema {
+        let mut json_bytes = Vec::new();
+        bom.clone().output_as_json_v1_5(&mut json_bytes)?;
+        let json_value: Value = serde_json::from_slice(&json_bytes)?;
+        validate_against_schema(&json_value, SchemaVersion::V1_5)?;
     }
+
+    Ok(bom)
 }
 
 /// Parses a JSON file into a CycloneDX Bom object.
@@ -72,22 +97,41 @@ pub(crate) fn parse_vex_xml<P: AsRef<Path>>(path: P) -> Result<Bom, Box<dyn Erro
 ///
 /// Note: The downgrade from 1.6 to 1.5 is a compatibility feature and may not work
 /// if the document uses 1.6-specific fields.
-pub(crate) fn parse_vex_json<P: AsRef<Path>>(path: P) -> Result<Bom, Box<dyn Error>> {
+///
+/// When `validate_schema` is set, the `serde_json::Value` is checked against the bundled schema
+/// for its *effective* spec version before parsing into a [`Bom`] - for a document downgraded
+/// from 1.6 to 1.5, that's the 1.5 schema, so any 1.6-only field still present surfaces as a
+/// violation rather than silently passing. A schema-invalid document errors with every
+/// violation collected rather than just the first.
+pub(crate) fn parse_vex_json<P: AsRef<Path>>(
+    path: P,
+    validate_schema: bool,
+) -> Result<Bom, Box<dyn Error>> {
     // First, read the entire file content
-    let content = fs::read(path)?;
+    let content = fs_context::read(path)?;
     // Try to parse normally first
     match Bom::parse_from_json(&content[..]) {
-        Ok(bom) => Ok(bom),
+        Ok(bom) => {
+            if validate_schema {
+                let json_value: Value = serde_json::from_slice(&content)?;
+                validate_against_schema(&json_value, effective_schema_version(&json_value))?;
+            }
+            Ok(bom)
+        }
         Err(err) => match err {
             JsonReadError::BomError { error } => {
                 match error {
                     BomError::UnsupportedSpecVersion(version) if version == "1.6" => {
                         // Parse to JSON Value
-                        let mut json_value: serde_json::Value = serde_json::from_slice(&content)?;
+                        let mut json_value: Value = serde_json::from_slice(&content)?;
 
                         print_downgrade_warning();
 
-                        json_value["specVersion"] = serde_json::Value::String("1.5".to_string());
+                        json_value["specVersion"] = Value::String("1.5".to_string());
+
+                        if validate_schema {
+                            validate_against_schema(&json_value, SchemaVersion::V1_5)?;
+                        }
 
                         // Try parsing with modified JSON
                         Ok(Bom::parse_json_value(json_value)?)
@@ -100,6 +144,50 @@ pub(crate) fn parse_vex_json<P: AsRef<Path>>(path: P) -> Result<Bom, Box<dyn Err
     }
 }
 
+/// Recognizes a CycloneDX document encoded as binary Protocol Buffers (`.bin`/`.cdx.pb`) and
+/// rejects it with a clear, specific error — it never actually decodes one into a [`Bom`].
+///
+/// Unlike [`parse_vex_xml`]/[`parse_vex_json`], this never actually succeeds: the `cyclonedx_bom`
+/// crate this project depends on only implements the JSON and XML encodings, with no protobuf
+/// decoder to call into. It exists so a `.bin`/`.pb` input is reported this way instead of
+/// silently falling through to [`Vex2PdfError::UnsupportedFileType`]. Real protobuf *decoding*
+/// (i.e. actually producing a `Bom` from one, not just recognizing the extension) is not
+/// implemented anywhere in this crate; replace this body once `cyclonedx_bom` (or a companion
+/// crate) gains protobuf support.
+pub(crate) fn parse_vex_protobuf<P: AsRef<Path>>(
+    path: P,
+    _validate_schema: bool,
+) -> Result<Bom, Box<dyn Error>> {
+    // Touching the file first means a missing/unreadable path is still reported as an IO error
+    // rather than being masked by the "not implemented" message below.
+    fs_context::metadata(path.as_ref())?;
+
+    Err(Box::new(Vex2PdfError::Parse(format!(
+        "`{}`: CycloneDX protobuf decoding is not supported (the bundled CycloneDX parser only reads JSON and XML)",
+        path.as_ref().display()
+    ))))
+}
+
+/// Picks the schema to validate a JSON document against based on its own `specVersion` field,
+/// defaulting to 1.5 for anything that isn't explicitly 1.6 (including documents missing the
+/// field entirely, which the schema itself flags as a violation).
+fn effective_schema_version(value: &Value) -> SchemaVersion {
+    match value.get("specVersion").and_then(Value::as_str) {
+        Some("1.6") => SchemaVersion::V1_6,
+        _ => SchemaVersion::V1_5,
+    }
+}
+
+/// Validates `value` against the bundled CycloneDX schema for `version`, converting every
+/// collected violation into a single [`Vex2PdfError::SchemaInvalid`] so callers can distinguish
+/// schema-invalid input from other conversion errors.
+fn validate_against_schema(value: &Value, version: SchemaVersion) -> Result<(), Box<dyn Error>> {
+    schema_validation::validate(value, version).map_err(|violations| {
+        let messages = violations.iter().map(ToString::to_string).collect();
+        Box::new(Vex2PdfError::SchemaInvalid(messages)) as Box<dyn Error>
+    })
+}
+
 /// Prints a warning message about downgrading from CycloneDX 1.6 to 1.5.
 ///
 /// Called when the parser encounters a 1.6 document and attempts to process it
@@ -121,12 +209,16 @@ fn print_downgrade_warning() {
 /// ## Arguments
 /// - dest_dir : Path to build from **Optional**
 /// - file_path : file path to convert from
+/// - mirror_root : when `Some`, and `file_path` lives under this root, reproduces `file_path`'s
+///   subdirectory relative to `mirror_root` under `dest_dir` instead of flattening into it
 ///
 /// ## Behavior
 ///
 /// - If `dest_dir` is `None`, replaces the input file's extension with `.pdf` in the same directory
 /// - If `dest_dir` is `Some(dir)` and is a directory, creates the PDF in that directory with the input file's base name
 /// - If `dest_dir` is `Some(file)` and is a file, returns an error
+/// - If `mirror_root` is also `Some` and `file_path`'s parent is under it, the PDF is nested under
+///   `dest_dir` at that same relative subdirectory rather than placed directly in `dest_dir`
 ///
 /// ## Examples
 ///
@@ -138,19 +230,20 @@ fn print_downgrade_warning() {
 ///
 /// // No dest_dir: PDF goes in same directory as input
 /// assert_eq!(
-///     run_utils::get_output_pdf_path(None, &path).unwrap(),
+///     run_utils::get_output_pdf_path(None, &path, None).unwrap(),
 ///     PathBuf::from("/tmp/file.pdf")
 /// );
 ///
 /// // dest_dir is a directory: PDF goes in that directory
 /// assert_eq!(
-///     run_utils::get_output_pdf_path(Some(PathBuf::from("/tmp/output").as_path()), &path).unwrap(),
+///     run_utils::get_output_pdf_path(Some(PathBuf::from("/tmp/output").as_path()), &path, None).unwrap(),
 ///     PathBuf::from("/tmp/output/file.pdf")
 /// );
 /// ```
 pub fn get_output_pdf_path(
     dest_dir: Option<&Path>,
     file_path: &Path,
+    mirror_root: Option<&Path>,
 ) -> Result<PathBuf, Vex2PdfError> {
     let file_stem = file_path
         .file_stem()
@@ -163,8 +256,109 @@ pub fn get_output_pdf_path(
         Some(out_dir) if out_dir.is_file() => {
             Err(Vex2PdfError::InvalidOutputDir(out_dir.to_path_buf()))
         }
-        Some(out_dir) => Ok(out_dir.join(&pdf_name)),
+        Some(out_dir) => {
+            let relative_dir = mirror_root.and_then(|root| {
+                file_path
+                    .parent()
+                    .and_then(|parent| parent.strip_prefix(root).ok())
+                    .filter(|rel| !rel.as_os_str().is_empty())
+            });
+
+            match relative_dir {
+                Some(rel) => Ok(out_dir.join(rel).join(&pdf_name)),
+                None => Ok(out_dir.join(&pdf_name)),
+            }
+        }
+    }
+}
+
+/// Asserts that `path` is a structurally valid PDF: it exists, and parsing it with the `pdf`
+/// crate and walking its page tree succeeds.
+///
+/// This goes beyond a bare `%PDF-` header check, so a truncated or structurally broken document
+/// fails the assertion instead of passing it. Exposed for integration tests to verify generated
+/// output; see [`crate::pdf::validate::verify_pdf`] for the underlying check, also reachable
+/// directly via `vex2pdf --verify`.
+///
+/// # Panics
+///
+/// Panics with the parse/page-tree error if `path` isn't a valid PDF.
+pub fn assert_pdf_valid(path: &Path) {
+    crate::pdf::validate::verify_pdf(path)
+        .unwrap_or_else(|e| panic!("`{}` is not a valid PDF: {e}", path.display()));
+}
+
+/// Looks up a Unix user by name, returning its uid.
+///
+/// Shared by [`crate::lib_utils::cli_args::CliArgs::validate`] (to fail fast on an unknown
+/// `--owner` before any conversion work begins) and [`apply_output_permissions`] (which
+/// re-resolves it right before `chown`, since [`crate::lib_utils::config::Config`] only carries
+/// the name, not a resolved id, so a config built directly through the library builder works
+/// too).
+#[cfg(unix)]
+pub(crate) fn resolve_uid(name: &str) -> Result<nix::unistd::Uid, io::Error> {
+    nix::unistd::User::from_name(name)
+        .map_err(|e| io::Error::other(format!("failed to look up user `{name}`: {e}")))?
+        .map(|user| user.uid)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, format!("no such user `{name}`")))
+}
+
+/// Looks up a Unix group by name, returning its gid. See [`resolve_uid`].
+#[cfg(unix)]
+pub(crate) fn resolve_gid(name: &str) -> Result<nix::unistd::Gid, io::Error> {
+    nix::unistd::Group::from_name(name)
+        .map_err(|e| io::Error::other(format!("failed to look up group `{name}`: {e}")))?
+        .map(|group| group.gid)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, format!("no such group `{name}`")))
+}
+
+/// Applies the configured file mode and, on Unix, owner/group to a freshly written output file.
+///
+/// `file_mode` is interpreted as POSIX permission bits (e.g. `0o640`); `owner`/`group` are Unix
+/// user/group names, resolved to a uid/gid here. All three are no-ops when `None`. On non-Unix
+/// targets, where there's no portable equivalent of permission bits or `chown`, every option is
+/// a no-op that logs a warning instead of erroring.
+#[cfg(unix)]
+pub fn apply_output_permissions(
+    path: &Path,
+    file_mode: Option<u32>,
+    owner: Option<&str>,
+    group: Option<&str>,
+) -> Result<(), Vex2PdfError> {
+    use std::os::unix::fs::PermissionsExt;
+
+    if let Some(mode) = file_mode {
+        fs::set_permissions(path, fs::Permissions::from_mode(mode))?;
+    }
+
+    if owner.is_some() || group.is_some() {
+        let uid = owner.map(resolve_uid).transpose()?;
+        let gid = group.map(resolve_gid).transpose()?;
+        nix::unistd::chown(path, uid, gid).map_err(|e| {
+            Vex2PdfError::Parse(format!("failed to chown `{}`: {e}", path.display()))
+        })?;
+    }
+
+    Ok(())
+}
+
+/// Non-Unix counterpart of [`apply_output_permissions`]: there's no portable equivalent of
+/// POSIX permission bits or `chown`, so this just warns and leaves the file untouched.
+#[cfg(not(unix))]
+pub fn apply_output_permissions(
+    path: &Path,
+    file_mode: Option<u32>,
+    owner: Option<&str>,
+    group: Option<&str>,
+) -> Result<(), Vex2PdfError> {
+    if file_mode.is_some() || owner.is_some() || group.is_some() {
+        warn!(
+            "--file-mode/--owner/--group have no effect on this platform; leaving `{}` unchanged",
+            path.display()
+        );
     }
+
+    Ok(())
 }
 
 #[cfg(test)]
@@ -180,7 +374,7 @@ mod tests {
 
         // No dest_dir: PDF should be in same directory as input
         assert_eq!(
-            get_output_pdf_path(None, path.as_path()).unwrap(),
+            get_output_pdf_path(None, path.as_path(), None).unwrap(),
             PathBuf::from("/tmp/file.pdf")
         );
     }
@@ -193,15 +387,20 @@ mod tests {
         assert_eq!(
             get_output_pdf_path(
                 Some(PathBuf::from("/tmp/test_path").as_path()),
-                path.as_path()
+                path.as_path(),
+                None
             )
             .unwrap(),
             PathBuf::from("/tmp/test_path/file.pdf")
         );
 
         assert_eq!(
-            get_output_pdf_path(Some(PathBuf::from("/output/dir").as_path()), path.as_path())
-                .unwrap(),
+            get_output_pdf_path(
+                Some(PathBuf::from("/output/dir").as_path()),
+                path.as_path(),
+                None
+            )
+            .unwrap(),
             PathBuf::from("/output/dir/file.pdf")
         );
     }
@@ -213,11 +412,11 @@ mod tests {
 
         // Real directory: PDF should be created inside it
         assert_eq!(
-            get_output_pdf_path(None, path.as_path()).unwrap(),
+            get_output_pdf_path(None, path.as_path(), None).unwrap(),
             PathBuf::from("/tmp/file.pdf")
         );
         assert_eq!(
-            get_output_pdf_path(Some(new_dest_path.as_path()), path.as_path()).unwrap(),
+            get_output_pdf_path(Some(new_dest_path.as_path()), path.as_path(), None).unwrap(),
             new_dest_path.join("file.pdf")
         );
     }
@@ -228,7 +427,39 @@ mod tests {
         let fake_file = NamedTempFile::new().unwrap();
 
         // Passing a file as dest_dir should return an error
-        let result = get_output_pdf_path(Some(fake_file.path()), path.as_path());
+        let result = get_output_pdf_path(Some(fake_file.path()), path.as_path(), None);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_path_build_mirrors_source_subdirectory() {
+        let root = PathBuf::from("/bom-tree");
+        let path = root.join("services").join("auth").join("bom.json");
+
+        assert_eq!(
+            get_output_pdf_path(
+                Some(PathBuf::from("/out").as_path()),
+                path.as_path(),
+                Some(root.as_path())
+            )
+            .unwrap(),
+            PathBuf::from("/out/services/auth/bom.pdf")
+        );
+    }
+
+    #[test]
+    fn test_path_build_mirror_root_ignored_when_file_is_not_under_it() {
+        let root = PathBuf::from("/unrelated-tree");
+        let path = PathBuf::from("/bom-tree/bom.json");
+
+        assert_eq!(
+            get_output_pdf_path(
+                Some(PathBuf::from("/out").as_path()),
+                path.as_path(),
+                Some(root.as_path())
+            )
+            .unwrap(),
+            PathBuf::from("/out/bom.pdf")
+        );
+    }
 }
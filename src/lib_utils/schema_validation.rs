@@ -0,0 +1,171 @@
+//! JSON Schema validation for CycloneDX input documents.
+//!
+//! When [`Config::validate_schema`](super::config::Config::validate_schema) is enabled,
+//! [`validate`] checks a parsed document against the bundled CycloneDX JSON Schema for its
+//! *effective* spec version before [`PdfGenerator`](crate::pdf::generator::PdfGenerator) ever
+//! sees it. "Effective" matters for 1.6 documents that [`run_utils`](super::run_utils)
+//! downgrades to 1.5: those are validated against the 1.5 schema, so any 1.6-only field still
+//! present in the source document surfaces here as a violation rather than silently passing.
+//!
+//! The schemas themselves are embedded at compile time (see `assets/schemas/`) so validation
+//! works fully offline, and each is compiled once per process via [`OnceLock`] since schema
+//! compilation isn't free and every file in a batch run validates against the same one.
+
+use jsonschema::JSONSchema;
+use serde_json::Value;
+use std::sync::OnceLock;
+
+const SCHEMA_1_5_SRC: &str = include_str!("../../assets/schemas/bom-1.5.schema.json");
+const SCHEMA_1_6_SRC: &str = include_str!("../../assets/schemas/bom-1.6.schema.json");
+
+static COMPILED_1_5: OnceLock<JSONSchema> = OnceLock::new();
+static COMPILED_1_6: OnceLock<JSONSchema> = OnceLock::new();
+
+/// The CycloneDX spec version to validate against. Mirrors the two versions
+/// [`run_utils`](super::run_utils) knows how to parse/downgrade.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum SchemaVersion {
+    V1_5,
+    V1_6,
+}
+
+impl SchemaVersion {
+    fn source(self) -> &'static str {
+        match self {
+            SchemaVersion::V1_5 => SCHEMA_1_5_SRC,
+            SchemaVersion::V1_6 => SCHEMA_1_6_SRC,
+        }
+    }
+
+    fn cell(self) -> &'static OnceLock<JSONSchema> {
+        match self {
+            SchemaVersion::V1_5 => &COMPILED_1_5,
+            SchemaVersion::V1_6 => &COMPILED_1_6,
+        }
+    }
+
+    /// Compiles (once) and returns the schema for this version.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the bundled schema asset is malformed. This would be a packaging bug in this
+    /// crate, not something a caller can recover from, so it panics the same way the embedded
+    /// font assets do on a similar class of failure.
+    fn compiled(self) -> &'static JSONSchema {
+        self.cell().get_or_init(|| {
+            let schema_value: Value = serde_json::from_str(self.source())
+                .expect("bundled CycloneDX schema asset is valid JSON");
+            JSONSchema::compile(&schema_value)
+                .expect("bundled CycloneDX schema asset is a valid JSON Schema")
+        })
+    }
+}
+
+/// A single schema violation, reported with the JSON pointer path to the offending value so a
+/// user can find it in the source document.
+#[derive(Debug, Clone)]
+pub struct SchemaViolation {
+    /// JSON pointer (e.g. `/components/0/type`) to the value that failed validation.
+    pub instance_path: String,
+    pub message: String,
+}
+
+impl std::fmt::Display for SchemaViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.instance_path.is_empty() {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, "{}: {}", self.instance_path, self.message)
+        }
+    }
+}
+
+/// Validates `value` against the bundled CycloneDX JSON Schema for `version`, collecting every
+/// violation rather than stopping at the first one.
+///
+/// Returns `Ok(())` when the document is schema-valid, or every violation found otherwise.
+pub fn validate(value: &Value, version: SchemaVersion) -> Result<(), Vec<SchemaViolation>> {
+    let schema = version.compiled();
+
+    let violations: Vec<SchemaViolation> = match schema.validate(value) {
+        Ok(()) => return Ok(()),
+        Err(errors) => errors
+            .map(|e| SchemaViolation {
+                instance_path: e.instance_path.to_string(),
+                message: e.to_string(),
+            })
+            .collect(),
+    };
+
+    Err(violations)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_validate_accepts_minimal_valid_1_5_document() {
+        let doc = json!({
+            "bomFormat": "CycloneDX",
+            "specVersion": "1.5",
+            "version": 1,
+        });
+
+        assert!(validate(&doc, SchemaVersion::V1_5).is_ok());
+    }
+
+    #[test]
+    fn test_validate_rejects_wrong_spec_version() {
+        let doc = json!({
+            "bomFormat": "CycloneDX",
+            "specVersion": "1.6",
+            "version": 1,
+        });
+
+        let violations = validate(&doc, SchemaVersion::V1_5).unwrap_err();
+        assert!(!violations.is_empty());
+    }
+
+    #[test]
+    fn test_validate_collects_every_violation_not_just_first() {
+        let doc = json!({
+            "bomFormat": "NotCycloneDX",
+            "specVersion": "2.0",
+            "components": [
+                { "name": "missing-type" }
+            ],
+        });
+
+        let violations = validate(&doc, SchemaVersion::V1_5).unwrap_err();
+        // bomFormat, specVersion and the component's missing `type` should all be reported
+        assert!(violations.len() >= 3);
+    }
+
+    #[test]
+    fn test_validate_1_6_document_against_1_6_schema() {
+        let doc = json!({
+            "bomFormat": "CycloneDX",
+            "specVersion": "1.6",
+            "components": [
+                { "type": "cryptographic-asset", "name": "example" }
+            ],
+        });
+
+        assert!(validate(&doc, SchemaVersion::V1_6).is_ok());
+    }
+
+    #[test]
+    fn test_schema_violation_display_includes_instance_path() {
+        let violation = SchemaViolation {
+            instance_path: "/specVersion".to_string(),
+            message: "is not one of [\"1.5\"]".to_string(),
+        };
+
+        assert_eq!(
+            violation.to_string(),
+            "/specVersion: is not one of [\"1.5\"]"
+        );
+    }
+}
@@ -0,0 +1,52 @@
+//! Self-update support for the `vex2pdf upgrade` subcommand.
+//!
+//! This checks the project's GitLab release channel for a version newer than the one
+//! currently running and, unless asked for a dry run, replaces the current binary in place.
+
+use crate::lib_utils::errors::Vex2PdfError;
+use log::info;
+use self_update::backends::gitlab::Update;
+use self_update::cargo_crate_version;
+
+/// Checks for a newer release than the one currently running and installs it unless
+/// `dry_run` is set.
+///
+/// With `force`, the latest release is reinstalled even if it matches the running version.
+/// Otherwise, an already-current binary is left untouched and this returns `Ok(())` without
+/// downloading anything.
+pub fn run(dry_run: bool, force: bool) -> Result<(), Vex2PdfError> {
+    let current_version = cargo_crate_version!();
+
+    let updater = Update::configure()
+        .repo_owner("jurassicLizard")
+        .repo_name("vex2pdf")
+        .bin_name("vex2pdf")
+        .show_download_progress(true)
+        .current_version(current_version)
+        .no_confirm(true)
+        .build()
+        .map_err(|e| Vex2PdfError::Upgrade(e.to_string()))?;
+
+    let latest = updater
+        .get_latest_release()
+        .map_err(|e| Vex2PdfError::Upgrade(e.to_string()))?;
+
+    if !force && latest.version == current_version {
+        info!("Already running the latest version ({current_version})");
+        return Ok(());
+    }
+
+    if dry_run {
+        info!("Update available: {current_version} -> {}", latest.version);
+        info!("Re-run without --dry-run to install it");
+        return Ok(());
+    }
+
+    info!("Updating {current_version} -> {}", latest.version);
+    updater
+        .update_extended()
+        .map_err(|e| Vex2PdfError::Upgrade(e.to_string()))?;
+    info!("Successfully updated to {}", latest.version);
+
+    Ok(())
+}
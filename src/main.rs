@@ -4,9 +4,13 @@
 //!
 //! ## CycloneDX Compatibility
 //!
-//! This tool fully supports CycloneDX schema version 1.5 and provides compatibility
-//! for version 1.6 documents that only use 1.5 fields. Documents using 1.6-specific
-//! fields may not process correctly.
+//! This tool parses both CycloneDX schema versions 1.5 and 1.6, auto-detecting the
+//! document's spec version, and `Config::validate_schema` can check a document against
+//! the matching bundled JSON Schema before rendering. The renderer surfaces 1.6's richer
+//! vulnerability analysis detail (state, justification, response, detail) alongside the
+//! rest of the report — but not the rest of 1.6's expanded object model (lifecycles,
+//! formulation, external reference hashes, or the `affects` structure naming impacted
+//! components/versions), which parses without error but isn't rendered yet.
 //!
 //! ## Usage
 //!
@@ -25,9 +29,11 @@
 //! No extra configuration is required
 //! See the README for more details.
 
+use clap::Parser;
 use log::error;
 use std::io::Write;
 use std::process;
+use vex2pdf::lib_utils::cli_args::{CliArgs, Command};
 use vex2pdf::lib_utils::config::Config;
 
 fn main() {
@@ -58,14 +64,49 @@ fn main() {
         .target(env_logger::Target::Stdout)
         .init();
 
+    match CliArgs::parse().command {
+        Some(Command::Upgrade { dry_run, force }) => {
+            if let Err(e) = vex2pdf::lib_utils::upgrade::run(dry_run, force) {
+                error!("{e}");
+                process::exit(1);
+            }
+            return;
+        }
+        Some(Command::Verify { path }) => {
+            if let Err(e) = vex2pdf::pdf::validate::run_verify(&path) {
+                error!("{e}");
+                process::exit(1);
+            }
+            return;
+        }
+        Some(Command::CheckManifest { path }) => {
+            if let Err(e) = vex2pdf::pdf::manifest::run_check_manifest(&path) {
+                error!("{e}");
+                process::exit(1);
+            }
+            return;
+        }
+        None => {}
+    }
+
     let config = Config::build().unwrap_or_else(|err| {
         error!("Problem setting up working environment:");
         error!("{}", { err });
         process::exit(1);
     });
 
-    if let Err(e) = vex2pdf::run(config) {
-        error!("Application error: {e}");
-        process::exit(1);
+    match vex2pdf::run(config) {
+        Ok(summary) if !summary.is_ok() => {
+            error!(
+                "{} of {} file(s) failed to convert",
+                summary.failed, summary.total
+            );
+            process::exit(1);
+        }
+        Ok(_) => {}
+        Err(e) => {
+            error!("Application error: {e}");
+            process::exit(1);
+        }
     }
 }
@@ -0,0 +1,273 @@
+//! Offline advisory database for vulnerability enrichment.
+//!
+//! Loads a local checkout laid out like the [RustSec advisory-db](https://github.com/RustSec/advisory-db)
+//! repository — one `.toml` file per advisory, each with an `[advisory]` metadata table and a
+//! `[versions]` table of `patched`/`unaffected` semver ranges — and indexes it by advisory id
+//! (and alias) and by affected package name. [`crate::pdf::generator::PdfGenerator`] uses this to
+//! show remediation guidance (patched versions, categories, whether the component in hand is
+//! actually affected) under a matching vulnerability instead of just its raw VEX entry.
+
+use crate::lib_utils::errors::Vex2PdfError;
+use crate::lib_utils::fs_context;
+use semver::{Version, VersionReq};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+/// The `[advisory]` table of an advisory `.toml` file.
+#[derive(Debug, Clone, serde::Deserialize)]
+struct AdvisoryMetadata {
+    id: String,
+    package: String,
+    #[serde(default)]
+    aliases: Vec<String>,
+    date: Option<String>,
+    #[serde(default)]
+    categories: Vec<String>,
+    #[serde(default)]
+    keywords: Vec<String>,
+    #[serde(default)]
+    informational: bool,
+}
+
+/// The `[versions]` table of an advisory `.toml` file.
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+struct AdvisoryVersions {
+    #[serde(default)]
+    patched: Vec<String>,
+    #[serde(default)]
+    unaffected: Vec<String>,
+}
+
+/// The on-disk shape of a single advisory `.toml` file.
+#[derive(Debug, Clone, serde::Deserialize)]
+struct AdvisoryFile {
+    advisory: AdvisoryMetadata,
+    #[serde(default)]
+    versions: AdvisoryVersions,
+}
+
+/// A single loaded advisory, with its version ranges parsed into semver requirements so they can
+/// be matched against a component's version.
+#[derive(Debug, Clone)]
+pub struct Advisory {
+    pub id: String,
+    pub aliases: Vec<String>,
+    pub package: String,
+    pub date: Option<String>,
+    pub categories: Vec<String>,
+    pub keywords: Vec<String>,
+    pub informational: bool,
+    pub patched: Vec<VersionReq>,
+    pub unaffected: Vec<VersionReq>,
+}
+
+impl Advisory {
+    /// Whether `version` falls in an affected range, judging by this advisory's `patched` and
+    /// `unaffected` ranges. Returns `None` when `version` doesn't parse as semver, or the
+    /// advisory states no ranges at all, since neither case lets us say either way.
+    pub fn affects_version(&self, version: &str) -> Option<bool> {
+        let version = Version::parse(version.trim_start_matches('v')).ok()?;
+
+        if self.patched.is_empty() && self.unaffected.is_empty() {
+            return None;
+        }
+
+        let is_cleared = self
+            .patched
+            .iter()
+            .chain(&self.unaffected)
+            .any(|req| req.matches(&version));
+
+        Some(!is_cleared)
+    }
+}
+
+/// Parses the `patched`/`unaffected` range strings of `file.versions`, skipping (rather than
+/// failing the whole advisory on) any individual range that isn't valid semver, since a single
+/// malformed range shouldn't hide an otherwise-usable advisory.
+fn parse_ranges(raw: &[String]) -> Vec<VersionReq> {
+    raw.iter()
+        .filter_map(|req| VersionReq::parse(req).ok())
+        .collect()
+}
+
+impl From<AdvisoryFile> for Advisory {
+    fn from(file: AdvisoryFile) -> Self {
+        Advisory {
+            id: file.advisory.id,
+            aliases: file.advisory.aliases,
+            package: file.advisory.package,
+            date: file.advisory.date,
+            categories: file.advisory.categories,
+            keywords: file.advisory.keywords,
+            informational: file.advisory.informational,
+            patched: parse_ranges(&file.versions.patched),
+            unaffected: parse_ranges(&file.versions.unaffected),
+        }
+    }
+}
+
+/// An offline advisory database, indexed by advisory id/alias and by affected package name for
+/// lookup from either direction — a vulnerability's `id`, or a component's name.
+#[derive(Debug, Clone, Default)]
+pub struct AdvisoryDatabase {
+    by_id: HashMap<String, Advisory>,
+    by_package: HashMap<String, Vec<String>>,
+}
+
+impl AdvisoryDatabase {
+    /// Recursively loads every `.toml` file under `root` as an advisory, matching the nested
+    /// `crates/<package>/RUSTSEC-....toml` layout of a real advisory-db checkout (though any
+    /// directory structure works, since the path itself isn't significant).
+    ///
+    /// An individual file that isn't valid advisory TOML is skipped with a warning rather than
+    /// aborting the whole load, since one bad file shouldn't make the rest of the database
+    /// unusable.
+    pub fn load(root: &Path) -> Result<Self, Vex2PdfError> {
+        let mut db = AdvisoryDatabase::default();
+
+        for path in collect_toml_files(root)? {
+            let contents = fs_context::read_to_string(&path)?;
+            match toml::from_str::<AdvisoryFile>(&contents) {
+                Ok(file) => db.insert(Advisory::from(file)),
+                Err(e) => log::warn!("Skipping invalid advisory file {}: {e}", path.display()),
+            }
+        }
+
+        Ok(db)
+    }
+
+    fn insert(&mut self, advisory: Advisory) {
+        self.by_package
+            .entry(advisory.package.clone())
+            .or_default()
+            .push(advisory.id.clone());
+        self.by_id.insert(advisory.id.clone(), advisory);
+    }
+
+    /// Looks an advisory up by its own id or by one of its aliases (e.g. a CVE id referenced
+    /// from a VEX document's `vulnerability.id`).
+    pub fn lookup_by_id(&self, id: &str) -> Option<&Advisory> {
+        self.by_id.get(id).or_else(|| {
+            self.by_id
+                .values()
+                .find(|advisory| advisory.aliases.iter().any(|alias| alias == id))
+        })
+    }
+
+    /// All advisories affecting `package`, in the order they were loaded.
+    pub fn lookup_by_package(&self, package: &str) -> Vec<&Advisory> {
+        self.by_package
+            .get(package)
+            .into_iter()
+            .flatten()
+            .filter_map(|id| self.by_id.get(id))
+            .collect()
+    }
+}
+
+/// Recursively collects every `.toml` file under `dir`.
+fn collect_toml_files(dir: &Path) -> Result<Vec<std::path::PathBuf>, Vex2PdfError> {
+    let mut files = Vec::new();
+    collect_toml_files_into(dir, &mut files)?;
+    Ok(files)
+}
+
+fn collect_toml_files_into(
+    dir: &Path,
+    files: &mut Vec<std::path::PathBuf>,
+) -> Result<(), Vex2PdfError> {
+    for entry in fs_context::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_toml_files_into(&path, files)?;
+        } else if path.extension().is_some_and(|ext| ext == "toml") {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_advisory(dir: &Path, file_name: &str, contents: &str) {
+        fs::write(dir.join(file_name), contents).unwrap();
+    }
+
+    #[test]
+    fn test_load_indexes_by_id_and_package() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        write_advisory(
+            temp_dir.path(),
+            "RUSTSEC-2023-0001.toml",
+            r#"
+            [advisory]
+            id = "RUSTSEC-2023-0001"
+            package = "vulnerable-crate"
+            aliases = ["CVE-2023-0001"]
+            date = "2023-01-01"
+            categories = ["denial-of-service"]
+            keywords = ["dos"]
+
+            [versions]
+            patched = [">=1.2.3"]
+            unaffected = ["<1.0.0"]
+            "#,
+        );
+
+        let db = AdvisoryDatabase::load(temp_dir.path()).unwrap();
+
+        assert!(db.lookup_by_id("RUSTSEC-2023-0001").is_some());
+        assert!(db.lookup_by_id("CVE-2023-0001").is_some());
+        assert_eq!(db.lookup_by_package("vulnerable-crate").len(), 1);
+    }
+
+    #[test]
+    fn test_load_skips_invalid_file_without_failing() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        write_advisory(temp_dir.path(), "broken.toml", "not = [valid");
+
+        let db = AdvisoryDatabase::load(temp_dir.path()).unwrap();
+        assert!(db.lookup_by_id("anything").is_none());
+    }
+
+    #[test]
+    fn test_affects_version_true_when_outside_ranges() {
+        let advisory = Advisory {
+            id: "RUSTSEC-2023-0001".to_string(),
+            aliases: Vec::new(),
+            package: "vulnerable-crate".to_string(),
+            date: None,
+            categories: Vec::new(),
+            keywords: Vec::new(),
+            informational: false,
+            patched: parse_ranges(&[">=1.2.3".to_string()]),
+            unaffected: parse_ranges(&["<1.0.0".to_string()]),
+        };
+
+        assert_eq!(advisory.affects_version("1.1.0"), Some(true));
+        assert_eq!(advisory.affects_version("1.2.3"), Some(false));
+        assert_eq!(advisory.affects_version("0.9.0"), Some(false));
+    }
+
+    #[test]
+    fn test_affects_version_none_for_unparsable_version_or_no_ranges() {
+        let advisory = Advisory {
+            id: "RUSTSEC-2023-0002".to_string(),
+            aliases: Vec::new(),
+            package: "other-crate".to_string(),
+            date: None,
+            categories: Vec::new(),
+            keywords: Vec::new(),
+            informational: true,
+            patched: Vec::new(),
+            unaffected: Vec::new(),
+        };
+
+        assert_eq!(advisory.affects_version("not-a-version"), None);
+        assert_eq!(advisory.affects_version("1.0.0"), None);
+    }
+}
@@ -0,0 +1,238 @@
+//! Self-contained CVSS v3.1 base score calculator.
+//!
+//! CycloneDX ratings may carry a `vector` (e.g. `CVSS:3.1/AV:N/AC:L/PR:N/UI:R/S:U/C:H/I:H/A:H`)
+//! without also stating the numeric `score`/`severity`, or the two may simply be absent from an
+//! otherwise well-formed document. [`parse_v31`] derives the base score (and a severity band)
+//! straight from the vector string per the published CVSS v3.1 specification, so the generator
+//! can still render a meaningful score instead of leaving the reader to compute it by hand.
+
+/// The result of scoring a `CVSS:3.x/...` vector: the numeric base score, its severity band, and
+/// the eight base metric values it was derived from (for the per-metric breakdown line).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct CvssV31 {
+    pub base_score: f64,
+    pub severity: &'static str,
+    pub av: char,
+    pub ac: char,
+    pub pr: char,
+    pub ui: char,
+    pub scope: char,
+    pub c: char,
+    pub i: char,
+    pub a: char,
+}
+
+impl CvssV31 {
+    /// Formats the eight base metrics back into `AV:.../AC:.../PR:.../UI:.../S:.../C:.../I:.../A:...`
+    /// order, regardless of the order they appeared in the source vector.
+    pub fn breakdown(&self) -> String {
+        format!(
+            "AV:{}/AC:{}/PR:{}/UI:{}/S:{}/C:{}/I:{}/A:{}",
+            self.av, self.ac, self.pr, self.ui, self.scope, self.c, self.i, self.a
+        )
+    }
+}
+
+/// Parses a `CVSS:3.0/...` or `CVSS:3.1/...` vector string and computes its base score.
+///
+/// Returns `None` if the vector doesn't start with a recognized CVSS v3 prefix, or if any of the
+/// eight base metrics (AV/AC/PR/UI/S/C/I/A) is missing or carries an unrecognized value, so a
+/// malformed or partial (temporal/environmental-only) vector falls back gracefully rather than
+/// producing a misleading score.
+pub(crate) fn parse_v31(vector: &str) -> Option<CvssV31> {
+    let rest = vector
+        .strip_prefix("CVSS:3.0/")
+        .or_else(|| vector.strip_prefix("CVSS:3.1/"))?;
+
+    let (mut av, mut ac, mut pr, mut ui, mut scope, mut c, mut i, mut a) =
+        (None, None, None, None, None, None, None, None);
+
+    for token in rest.split('/') {
+        let mut parts = token.splitn(2, ':');
+        let key = parts.next()?;
+        let value = parts.next()?;
+        let letter = value.chars().next()?;
+
+        match key {
+            "AV" => av = Some(letter),
+            "AC" => ac = Some(letter),
+            "PR" => pr = Some(letter),
+            "UI" => ui = Some(letter),
+            "S" => scope = Some(letter),
+            "C" => c = Some(letter),
+            "I" => i = Some(letter),
+            "A" => a = Some(letter),
+            // Temporal/environmental metrics (E, RL, RC, CR, ...) don't affect the base score.
+            _ => {}
+        }
+    }
+
+    let (av, ac, pr, ui, scope, c, i, a) = (av?, ac?, pr?, ui?, scope?, c?, i?, a?);
+
+    let av_weight = match av {
+        'N' => 0.85,
+        'A' => 0.62,
+        'L' => 0.55,
+        'P' => 0.2,
+        _ => return None,
+    };
+    let ac_weight = match ac {
+        'L' => 0.77,
+        'H' => 0.44,
+        _ => return None,
+    };
+    let scope_changed = match scope {
+        'U' => false,
+        'C' => true,
+        _ => return None,
+    };
+    let pr_weight = match (pr, scope_changed) {
+        ('N', _) => 0.85,
+        ('L', false) => 0.62,
+        ('L', true) => 0.68,
+        ('H', false) => 0.27,
+        ('H', true) => 0.5,
+        _ => return None,
+    };
+    let ui_weight = match ui {
+        'N' => 0.85,
+        'R' => 0.62,
+        _ => return None,
+    };
+    let cia_weight = |letter: char| match letter {
+        'N' => Some(0.0),
+        'L' => Some(0.22),
+        'H' => Some(0.56),
+        _ => None,
+    };
+    let (c_weight, i_weight, a_weight) = (cia_weight(c)?, cia_weight(i)?, cia_weight(a)?);
+
+    let exploitability = 8.22 * av_weight * ac_weight * pr_weight * ui_weight;
+    let iss = 1.0 - ((1.0 - c_weight) * (1.0 - i_weight) * (1.0 - a_weight));
+    let impact = if scope_changed {
+        7.52 * (iss - 0.029) - 3.25 * (iss - 0.02).powf(15.0)
+    } else {
+        6.42 * iss
+    };
+
+    let base_score = if impact <= 0.0 {
+        0.0
+    } else if scope_changed {
+        roundup((1.08 * (impact + exploitability)).min(10.0))
+    } else {
+        roundup((impact + exploitability).min(10.0))
+    };
+
+    Some(CvssV31 {
+        base_score,
+        severity: severity_band(base_score),
+        av,
+        ac,
+        pr,
+        ui,
+        scope,
+        c,
+        i,
+        a,
+    })
+}
+
+/// Rounds `input` up to the nearest 0.1, per the CVSS v3.1 specification's `Roundup` function.
+/// Plain float comparison would misround values like `4.02` due to binary floating-point
+/// representation, so this works in scaled integers instead.
+fn roundup(input: f64) -> f64 {
+    let scaled = (input * 100_000.0).round() as i64;
+    if scaled % 10_000 == 0 {
+        scaled as f64 / 100_000.0
+    } else {
+        ((scaled / 10_000) + 1) as f64 / 10.0
+    }
+}
+
+/// Maps a base score to its CVSS v3.1 qualitative severity band.
+fn severity_band(score: f64) -> &'static str {
+    match score {
+        s if s <= 0.0 => "None",
+        s if s < 4.0 => "Low",
+        s if s < 7.0 => "Medium",
+        s if s < 9.0 => "High",
+        _ => "Critical",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_v31_critical_vector() {
+        let cvss = parse_v31("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H").unwrap();
+        assert_eq!(cvss.base_score, 9.8);
+        assert_eq!(cvss.severity, "Critical");
+    }
+
+    #[test]
+    fn test_parse_v31_high_vector_with_user_interaction() {
+        let cvss = parse_v31("CVSS:3.1/AV:N/AC:L/PR:N/UI:R/S:U/C:H/I:H/A:H").unwrap();
+        assert_eq!(cvss.base_score, 8.8);
+        assert_eq!(cvss.severity, "High");
+    }
+
+    #[test]
+    fn test_parse_v31_scope_changed_vector() {
+        let cvss = parse_v31("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:C/C:H/I:H/A:H").unwrap();
+        assert_eq!(cvss.base_score, 10.0);
+        assert_eq!(cvss.severity, "Critical");
+    }
+
+    #[test]
+    fn test_parse_v31_low_severity_vector() {
+        let cvss = parse_v31("CVSS:3.1/AV:L/AC:H/PR:H/UI:R/S:U/C:L/I:N/A:N").unwrap();
+        assert_eq!(cvss.severity, "Low");
+    }
+
+    #[test]
+    fn test_parse_v31_no_impact_scores_zero() {
+        let cvss = parse_v31("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:N/I:N/A:N").unwrap();
+        assert_eq!(cvss.base_score, 0.0);
+        assert_eq!(cvss.severity, "None");
+    }
+
+    #[test]
+    fn test_parse_v31_accepts_cvss_30_prefix() {
+        assert!(parse_v31("CVSS:3.0/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H").is_some());
+    }
+
+    #[test]
+    fn test_parse_v31_ignores_temporal_metrics() {
+        let cvss = parse_v31("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/E:F/RL:O/RC:C").unwrap();
+        assert_eq!(cvss.base_score, 9.8);
+    }
+
+    #[test]
+    fn test_parse_v31_rejects_unknown_prefix() {
+        assert!(parse_v31("CVSS:2.0/AV:N/AC:L/Au:N/C:C/I:C/A:C").is_none());
+    }
+
+    #[test]
+    fn test_parse_v31_rejects_malformed_vector() {
+        assert!(parse_v31("CVSS:3.1/AV:N/AC:L").is_none());
+        assert!(parse_v31("not a vector").is_none());
+    }
+
+    #[test]
+    fn test_breakdown_format() {
+        let cvss = parse_v31("CVSS:3.1/AV:N/AC:L/PR:N/UI:R/S:U/C:H/I:H/A:H").unwrap();
+        assert_eq!(cvss.breakdown(), "AV:N/AC:L/PR:N/UI:R/S:U/C:H/I:H/A:H");
+    }
+
+    #[test]
+    fn test_roundup_exact_tenth_is_unchanged() {
+        assert_eq!(roundup(7.0), 7.0);
+    }
+
+    #[test]
+    fn test_roundup_rounds_up_to_next_tenth() {
+        assert_eq!(roundup(4.02), 4.1);
+    }
+}
@@ -8,13 +8,24 @@
 //!
 
 use crate::lib_utils::config::Config;
+use crate::lib_utils::fs_context;
+use crate::pdf::advisory::AdvisoryDatabase;
+use crate::pdf::cvss;
 use crate::pdf::font_config::FontsDir;
+use crate::pdf::merge::MergedBom;
+use crate::pdf::natural_sort::natural_cmp;
+use crate::pdf::reproducible;
+use crate::pdf::template::{ReportTemplate, TemplateSection};
+use cyclonedx_bom::models::component::Component;
+use cyclonedx_bom::models::license::{LicenseChoice, LicenseIdentifier};
 use cyclonedx_bom::models::tool::Tools;
+use cyclonedx_bom::models::vulnerability::Vulnerability;
 use cyclonedx_bom::prelude::Bom;
 use genpdf::elements::Paragraph;
 use genpdf::style::{Color, Style, StyledString};
 use genpdf::{Alignment, Document, Element};
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::io;
 use std::path::Path;
 use std::sync::Arc;
@@ -43,6 +54,15 @@ pub struct PdfGenerator<'a> {
     comp_name_style: Style,
     version_style: Style,
     cve_id_style: Style,
+    /// Base (unbolded) styles for the Critical/High/Medium/Low/None severity bands, built from
+    /// `config.severity_palette`. Used to color-code both the rating severity text and the
+    /// vulnerability ID badge (see [`Self::severity_style_for`]) so a reader can visually triage
+    /// without reading every entry.
+    severity_critical_style: Style,
+    severity_high_style: Style,
+    severity_medium_style: Style,
+    severity_low_style: Style,
+    severity_none_style: Style,
     /// This is the title of the report; which is the first heading
     /// in the first page if no value is given a default title is used.
     #[deprecated(
@@ -85,6 +105,13 @@ pub struct PdfGenerator<'a> {
     /// this is an Arc for cases where the pdfgenerator is used by multiple threads to avoid copying
     /// around the entire configuration on every thread's stack
     config: Arc<Config>,
+    /// The offline advisory database loaded from `config.advisory_db_path`, if enrichment is
+    /// enabled and the database loaded successfully. `None` disables enrichment entirely so
+    /// `render_vulns` doesn't need to re-check the config flag on every vulnerability.
+    advisory_db: Option<AdvisoryDatabase>,
+    /// The report layout loaded from `config.template`, if given and parsed successfully. `None`
+    /// falls back to the built-in fixed cover/metadata/vulnerabilities/components layout.
+    report_template: Option<ReportTemplate>,
 }
 
 impl Default for PdfGenerator<'_> {
@@ -153,34 +180,76 @@ impl<'a, 'b> PdfGenerator<'a> {
     /// let generator = PdfGenerator::new(Arc::new(config));
     /// ```
     pub fn new(config: Arc<Config>) -> Self {
-        // Initialize with default styles
+        // Initialize with default styles, colored consistently per the configured theme
+        let theme_colors = config.theme.colors();
+        let rgb = |color: (u8, u8, u8)| Color::Rgb(color.0, color.1, color.2);
+
         let title_style = Style::new()
             .with_font_size(18)
-            .with_color(Color::Rgb(0, 0, 80));
+            .with_color(rgb(theme_colors.title));
 
         let header_style = Style::new()
             .with_font_size(14)
-            .with_color(Color::Rgb(0, 0, 80));
+            .with_color(rgb(theme_colors.header));
 
         let normal_style = Style::new().with_font_size(11);
 
         let indent_style = Style::new()
             .with_font_size(10)
-            .with_color(Color::Rgb(40, 40, 40));
+            .with_color(rgb(theme_colors.indent));
 
         let comp_name_style = Style::new()
             .with_font_size(10)
-            .with_color(Color::Rgb(0, 51, 102))
+            .with_color(rgb(theme_colors.comp_name))
             .italic();
 
         let version_style = Style::new()
             .with_font_size(10)
-            .with_color(Color::Rgb(128, 128, 128));
+            .with_color(rgb(theme_colors.version));
 
         let cve_id_style = Style::new()
             .with_font_size(11)
-            .with_color(Color::Rgb(139, 0, 0))
+            .with_color(rgb(theme_colors.cve_id))
             .bold();
+
+        let palette = &config.severity_palette;
+        let severity_style_from = |color: (u8, u8, u8)| {
+            Style::new()
+                .with_font_size(10)
+                .with_color(Color::Rgb(color.0, color.1, color.2))
+        };
+        let severity_critical_style = severity_style_from(palette.critical);
+        let severity_high_style = severity_style_from(palette.high);
+        let severity_medium_style = severity_style_from(palette.medium);
+        let severity_low_style = severity_style_from(palette.low);
+        let severity_none_style = severity_style_from(palette.none);
+
+        let advisory_db = if config.enrich_with_advisory_db {
+            config.advisory_db_path.as_deref().and_then(|path| {
+                AdvisoryDatabase::load(path)
+                    .inspect_err(|e| {
+                        log::warn!(
+                            "Disabling advisory enrichment: failed to load {}: {e}",
+                            path.display()
+                        );
+                    })
+                    .ok()
+            })
+        } else {
+            None
+        };
+
+        let report_template = config.template.as_deref().and_then(|path| {
+            ReportTemplate::load(path)
+                .inspect_err(|e| {
+                    log::warn!(
+                        "Ignoring report template: failed to load {}: {e}",
+                        path.display()
+                    );
+                })
+                .ok()
+        });
+
         #[allow(deprecated)]
         Self {
             title_style,
@@ -190,12 +259,19 @@ impl<'a, 'b> PdfGenerator<'a> {
             comp_name_style,
             version_style,
             cve_id_style,
+            severity_critical_style,
+            severity_high_style,
+            severity_medium_style,
+            severity_low_style,
+            severity_none_style,
             _report_title: Some(Self::get_default_report_title()),
             _pdf_meta_name: Some(Self::get_default_pdf_meta_name()),
             _show_novulns_msg: config.show_novulns_msg,
             _show_components: config.show_components,
             _pure_bom_novulns: config.pure_bom_novulns,
             config,
+            advisory_db,
+            report_template,
         }
     }
 
@@ -235,6 +311,88 @@ impl<'a, 'b> PdfGenerator<'a> {
         vex: &'b Bom,
         output_path: P,
     ) -> Result<(), io::Error> {
+        let doc = self.build_document(vex);
+        self.save_document(doc, output_path)
+    }
+
+    /// Generates a single consolidated PDF report from `merged` (built by
+    /// [`crate::pdf::merge::merge_sources`]), backing `--merge` mode. Renders the same sections
+    /// as [`Self::generate_pdf`] for the merged document, followed by a "Merged Report Sources"
+    /// appendix (every source filename and serial number) and, if any vulnerability id had
+    /// conflicting `analysis` data across sources, a "Merge Conflicts" appendix listing which
+    /// entry won and which were dropped.
+    pub fn generate_merged_pdf<P: AsRef<Path>>(
+        &self,
+        merged: &'b MergedBom,
+        output_path: P,
+    ) -> Result<(), io::Error> {
+        let mut doc = self.build_document(&merged.bom);
+
+        doc.push(genpdf::elements::Break::new(1.0));
+        doc.push(Paragraph::default().styled_string("Merged Report Sources", self.header_style));
+        doc.push(genpdf::elements::Break::new(0.5));
+
+        for source in &merged.sources {
+            let mut para = Paragraph::default()
+                .styled_string("- ", self.indent_style)
+                .styled_string(source.filename.clone(), self.comp_name_style);
+            if let Some(serial) = &source.serial_number {
+                para.push_styled(format!(" ({serial})"), self.version_style);
+            }
+            doc.push(para);
+        }
+
+        if !merged.conflicts.is_empty() {
+            doc.push(genpdf::elements::Break::new(1.0));
+            doc.push(Paragraph::default().styled_string("Merge Conflicts", self.header_style));
+            doc.push(genpdf::elements::Break::new(0.5));
+
+            for conflict in &merged.conflicts {
+                doc.push(
+                    Paragraph::default()
+                        .styled_string("ID: ", self.indent_style.bold())
+                        .styled_string(conflict.vulnerability_id.clone(), self.cve_id_style),
+                );
+                doc.push(
+                    Paragraph::default()
+                        .styled_string("Kept: ", self.indent_style)
+                        .styled_string(
+                            format!(
+                                "{} ({})",
+                                conflict.kept_state.as_deref().unwrap_or("none"),
+                                conflict.kept_source_filename
+                            ),
+                            self.version_style,
+                        ),
+                );
+
+                for losing in &conflict.losing {
+                    doc.push(
+                        Paragraph::default()
+                            .styled_string("Dropped: ", self.indent_style)
+                            .styled_string(
+                                format!(
+                                    "{} ({})",
+                                    losing.state.as_deref().unwrap_or("none"),
+                                    losing.source_filename
+                                ),
+                                self.indent_style,
+                            ),
+                    );
+                }
+
+                doc.push(genpdf::elements::Break::new(0.5));
+            }
+        }
+
+        self.save_document(doc, output_path)
+    }
+
+    /// Builds the full report [`Document`] for `vex` (title, metadata, vulnerabilities,
+    /// components, dependency graph), without rendering or saving it. Split out from
+    /// [`Self::generate_pdf`] so [`Self::generate_merged_pdf`] can append its own appendix
+    /// sections (sources, merge conflicts) before the document is saved.
+    fn build_document(&self, vex: &'b Bom) -> Document {
         // Extract component list if available this will later be used to extract affected components
 
         let mut comp_ref_map = HashMap::<&'b str, ComponentTuple>::new();
@@ -266,7 +424,12 @@ impl<'a, 'b> PdfGenerator<'a> {
         doc.set_title(pdf_title);
         let mut decorator = genpdf::SimplePageDecorator::new();
         decorator.set_margins(10);
-        let header_title = document_title.to_string();
+        let header_title = self
+            .report_template
+            .as_ref()
+            .and_then(|template| template.header.clone())
+            .unwrap_or_else(|| document_title.to_string());
+        let header_color = self.config.theme.colors().header;
         decorator.set_header(move |page| {
             let mut layout = genpdf::elements::LinearLayout::vertical();
             if page > 1 {
@@ -275,19 +438,59 @@ impl<'a, 'b> PdfGenerator<'a> {
                 layout.push(Paragraph::new(format!("Page {page}")).aligned(Alignment::Center));
                 layout.push(genpdf::elements::Break::new(2));
             }
-            layout.styled(
-                Style::new()
-                    .with_font_size(10)
-                    .with_color(Color::Rgb(0, 0, 80)),
-            )
+            layout.styled(Style::new().with_font_size(10).with_color(Color::Rgb(
+                header_color.0,
+                header_color.1,
+                header_color.2,
+            )))
         });
 
         doc.set_page_decorator(decorator);
 
-        // Add title and basic information
+        if let Some(template) = &self.report_template {
+            for section in &template.sections {
+                doc = match section {
+                    TemplateSection::Cover => self.render_cover(doc, document_title),
+                    TemplateSection::Metadata => self.render_metadata(doc, vex),
+                    TemplateSection::Vulnerabilities => self.render_vulns(doc, vex, &comp_ref_map),
+                    TemplateSection::Components => {
+                        let doc = self.render_components(doc, vex);
+                        self.render_dependencies(doc, vex)
+                    }
+                    TemplateSection::Custom { title, text } => self.render_custom(doc, title, text),
+                };
+            }
+
+            return doc;
+        }
+
+        doc = self.render_cover(doc, document_title);
+        doc = self.render_metadata(doc, vex);
+
+        // Add a Vulnerabilities section or a components list or both depending on user options
+
+        if !self.config.pure_bom_novulns {
+            doc = self.render_vulns(doc, vex, &comp_ref_map);
+        }
+
+        if self.config.pure_bom_novulns || self.config.show_components {
+            doc = self.render_components(doc, vex);
+            doc = self.render_dependencies(doc, vex);
+        }
+
+        doc
+    }
+
+    /// Renders the report title as the first block on the first page.
+    fn render_cover(&self, mut doc: Document, document_title: &str) -> Document {
         doc.push(Paragraph::default().styled_string(document_title, self.title_style));
         doc.push(genpdf::elements::Break::new(1.0));
+        doc
+    }
 
+    /// Renders the "Document Information" block (timestamp, tools, the BOM's own component) and
+    /// the basic BOM format/spec version/serial number fields.
+    fn render_metadata(&self, mut doc: Document, vex: &Bom) -> Document {
         // Add metadata if available
         if let Some(metadata) = &vex.metadata {
             doc.push(Paragraph::default().styled_string("Document Information", self.header_style));
@@ -311,6 +514,14 @@ impl<'a, 'b> PdfGenerator<'a> {
 
                 match tools {
                     Tools::List(tools_list) => {
+                        let mut tools_list: Vec<_> = tools_list.iter().collect();
+                        self.sort_by_name_if_enabled(&mut tools_list, |tool| {
+                            tool.name
+                                .as_ref()
+                                .map(|name| name.to_string())
+                                .unwrap_or_default()
+                        });
+
                         for tool in tools_list {
                             if let Some(tool_name) = &tool.name {
                                 let meta_tool_para = create_versioned_comp_styled!(
@@ -329,7 +540,12 @@ impl<'a, 'b> PdfGenerator<'a> {
                     } => {
                         // Handle components used as tools
                         if let Some(components) = &components_obj {
-                            for component in &components.0 {
+                            let mut components: Vec<_> = components.0.iter().collect();
+                            self.sort_by_name_if_enabled(&mut components, |component| {
+                                component.name.to_string()
+                            });
+
+                            for component in components {
                                 let styled_comp = create_versioned_comp_styled!(
                                     &component.name,
                                     &component.version,
@@ -342,7 +558,12 @@ impl<'a, 'b> PdfGenerator<'a> {
 
                         // Handle services used as tools
                         if let Some(services) = &services_obj {
-                            for service in &services.0 {
+                            let mut services: Vec<_> = services.0.iter().collect();
+                            self.sort_by_name_if_enabled(&mut services, |service| {
+                                service.name.to_string()
+                            });
+
+                            for service in services {
                                 let styled_service = create_versioned_comp_styled!(
                                     &service.name,
                                     &service.version,
@@ -403,20 +624,48 @@ impl<'a, 'b> PdfGenerator<'a> {
 
         doc.push(genpdf::elements::Break::new(2.0));
 
-        // Add a Vulnerabilities section or a components list or both depending on user options
+        doc
+    }
 
-        if !self.config.pure_bom_novulns {
-            doc = self.render_vulns(doc, vex, &comp_ref_map);
-        }
+    /// Renders a free-text block (e.g. a disclosure policy paragraph) named by a
+    /// [`TemplateSection::Custom`] entry.
+    fn render_custom(&self, mut doc: Document, title: &str, text: &str) -> Document {
+        doc.push(Paragraph::default().styled_string(title, self.header_style));
+        doc.push(genpdf::elements::Break::new(1));
+        doc.push(Paragraph::default().styled_string(text, self.normal_style));
+        doc.push(genpdf::elements::Break::new(2.0));
+        doc
+    }
 
-        if self.config.pure_bom_novulns || self.config.show_components {
-            doc = self.render_components(doc, vex);
+    /// Renders `doc` to `output_path`, honoring [`Config::reproducible`].
+    fn save_document<P: AsRef<Path>>(
+        &self,
+        doc: Document,
+        output_path: P,
+    ) -> Result<(), io::Error> {
+        if self.config.reproducible {
+            let mut rendered = Vec::new();
+            doc.render(&mut rendered).map_err(|e| {
+                io::Error::new(io::ErrorKind::Other, format!("failed to render PDF: {e}"))
+            })?;
+
+            let epoch_seconds = self.config.source_date_epoch.unwrap_or_else(|| {
+                std::time::SystemTime::now()
+                    .duration_since(std::time::UNIX_EPOCH)
+                    .map(|d| d.as_secs() as i64)
+                    .unwrap_or(0)
+            });
+
+            let reproducible_bytes = reproducible::make_reproducible(&rendered, epoch_seconds)?;
+            fs_context::write(output_path, reproducible_bytes)?;
+        } else {
+            let mut rendered = Vec::new();
+            doc.render(&mut rendered).map_err(|e| {
+                io::Error::new(io::ErrorKind::Other, format!("failed to render PDF: {e}"))
+            })?;
+            fs_context::write(output_path, rendered)?;
         }
 
-        // Render the document
-        doc.render_to_file(output_path)
-            .expect("failed to write file");
-
         Ok(())
     }
 
@@ -462,17 +711,69 @@ impl<'a, 'b> PdfGenerator<'a> {
             doc.push(genpdf::elements::Break::new(1.0));
         }
 
+        if vulns_available {
+            doc.push(self.severity_legend());
+            doc.push(genpdf::elements::Break::new(0.5));
+        }
+
         if let Some(vulnerabilities) = &vex.vulnerabilities {
+            let total_count = vulnerabilities.0.len();
+            let mut kept_vulns: Vec<&Vulnerability> = vulnerabilities
+                .0
+                .iter()
+                .filter(|vuln| self.vulnerability_passes_filters(vuln))
+                .collect();
+            let filtered_out = total_count - kept_vulns.len();
+
+            if self.config.sort_vulns_by_severity {
+                kept_vulns.sort_by(|a, b| {
+                    let rank_a = Self::highest_severity(a)
+                        .as_deref()
+                        .map(Self::severity_rank)
+                        .unwrap_or(0);
+                    let rank_b = Self::highest_severity(b)
+                        .as_deref()
+                        .map(Self::severity_rank)
+                        .unwrap_or(0);
+                    rank_b.cmp(&rank_a).then_with(|| {
+                        Self::highest_cvss_score(b)
+                            .partial_cmp(&Self::highest_cvss_score(a))
+                            .unwrap_or(std::cmp::Ordering::Equal)
+                    })
+                });
+            }
+
+            if self.severity_state_filters_active() && total_count > 0 {
+                doc.push(Paragraph::default().styled_string(
+                    format!(
+                        "Filtered out {filtered_out} of {total_count} vulnerabilit{} by the configured severity/state filters",
+                        if total_count == 1 { "y" } else { "ies" }
+                    ),
+                    self.indent_style,
+                ));
+                doc.push(genpdf::elements::Break::new(0.5));
+            }
+
+            if self.config.show_severity_summary && !kept_vulns.is_empty() {
+                doc.push(self.severity_summary_row(&kept_vulns));
+                doc.push(genpdf::elements::Break::new(0.5));
+            }
+
             let mut ordered_list = genpdf::elements::OrderedList::new();
 
             // Add each vulnerability
-            for vuln in &vulnerabilities.0 {
+            for vuln in kept_vulns {
                 let mut vuln_layout = genpdf::elements::LinearLayout::vertical();
 
+                let id_badge_style = self
+                    .severity_style_for(Self::highest_severity(vuln).as_deref())
+                    .map(|style| style.bold())
+                    .unwrap_or(self.cve_id_style);
+
                 let id_paragraph = if let Some(vuln_id) = &vuln.id {
                     Paragraph::default()
                         .styled_string("ID: ", self.normal_style)
-                        .styled_string(format!("{vuln_id}"), self.cve_id_style)
+                        .styled_string(format!("{vuln_id}"), id_badge_style)
                 } else {
                     Paragraph::default().styled_string("ID: N/A", self.normal_style)
                 };
@@ -527,12 +828,15 @@ impl<'a, 'b> PdfGenerator<'a> {
 
                         if let Some(severity) = &rating.severity {
                             // add Severity ratings and sources
+                            let severity_text_style = self
+                                .severity_style_for(Some(&severity.to_string()))
+                                .unwrap_or(self.indent_style);
 
                             let mut severity_par = Paragraph::default()
                                 .styled_string("Severity: ", self.indent_style.bold())
                                 .styled_string(
                                     format!("{severity} ({rating_method}"),
-                                    self.indent_style,
+                                    severity_text_style,
                                 );
 
                             if let Some(source_name) = source_str {
@@ -544,6 +848,31 @@ impl<'a, 'b> PdfGenerator<'a> {
                             severity_par = severity_par.styled_string(")", self.indent_style);
                             ratings_list.push(severity_par);
                         }
+
+                        if let Some(vector) = &rating.vector {
+                            if let Some(cvss) = cvss::parse_v31(&vector.to_string()) {
+                                let score_style = self
+                                    .severity_style_for(Some(cvss.severity))
+                                    .unwrap_or(self.indent_style);
+
+                                ratings_list.push(
+                                    Paragraph::default()
+                                        .styled_string(
+                                            "CVSS Base Score: ",
+                                            self.indent_style.bold(),
+                                        )
+                                        .styled_string(
+                                            format!("{:.1} ({})", cvss.base_score, cvss.severity),
+                                            score_style,
+                                        ),
+                                );
+                                ratings_list.push(
+                                    Paragraph::default()
+                                        .styled_string("Metrics: ", self.indent_style.bold())
+                                        .styled_string(cvss.breakdown(), self.indent_style),
+                                );
+                            }
+                        }
                     }
                 }
 
@@ -583,8 +912,21 @@ impl<'a, 'b> PdfGenerator<'a> {
                         // add our affected components to the vulnerability layout
                         vuln_layout.push(genpdf::elements::Break::new(0.5));
                         vuln_layout.push(affected_comp_para);
+
+                        if let Some(advisory_para) =
+                            self.advisory_guidance(vuln, &affected_comps_detailed)
+                        {
+                            vuln_layout.push(genpdf::elements::Break::new(0.5));
+                            vuln_layout.push(advisory_para);
+                        }
                     }
                 }
+
+                if let Some(analysis_para) = self.analysis_paragraph(vuln) {
+                    vuln_layout.push(genpdf::elements::Break::new(0.5));
+                    vuln_layout.push(analysis_para);
+                }
+
                 vuln_layout.push(genpdf::elements::Break::new(1));
                 ordered_list.push(vuln_layout);
             }
@@ -596,10 +938,11 @@ impl<'a, 'b> PdfGenerator<'a> {
 
         //Add message if vulns are not available
         if !vulns_available && self.config.show_novulns_msg {
+            let banner_color = self.config.theme.colors().novulns_banner;
             let vulns_style = Style::new()
                 .bold()
                 .with_font_size(16)
-                .with_color(Color::Rgb(0, 100, 0));
+                .with_color(Color::Rgb(banner_color.0, banner_color.1, banner_color.2));
 
             doc.push(
                 Paragraph::new("No Vulnerabilities reported")
@@ -614,12 +957,381 @@ impl<'a, 'b> PdfGenerator<'a> {
         doc
     }
 
+    /// Looks `vuln` and each of `affected_comps` up in the loaded advisory database (if
+    /// enrichment is enabled) and renders a paragraph of remediation guidance, or `None` if
+    /// enrichment is disabled, no advisory matched, or none of the matches had anything to say.
+    fn advisory_guidance(
+        &self,
+        vuln: &Vulnerability,
+        affected_comps: &[&ComponentTuple],
+    ) -> Option<Paragraph> {
+        let db = self.advisory_db.as_ref()?;
+
+        let mut advisories: Vec<&crate::pdf::advisory::Advisory> = vuln
+            .id
+            .as_ref()
+            .and_then(|id| db.lookup_by_id(&id.to_string()))
+            .into_iter()
+            .collect();
+
+        for affected_comp in affected_comps {
+            for advisory in db.lookup_by_package(affected_comp.0) {
+                if !advisories.iter().any(|a| a.id == advisory.id) {
+                    advisories.push(advisory);
+                }
+            }
+        }
+
+        if advisories.is_empty() {
+            return None;
+        }
+
+        let mut para =
+            Paragraph::default().styled_string("Advisory Guidance: ", self.indent_style.bold());
+
+        for (i, advisory) in advisories.iter().enumerate() {
+            if i > 0 {
+                para.push("; ");
+            }
+            para.push_styled(advisory.id.as_str(), self.cve_id_style);
+
+            if !advisory.patched.is_empty() {
+                para.push(" — patched in ");
+                for (j, req) in advisory.patched.iter().enumerate() {
+                    if j > 0 {
+                        para.push(", ");
+                    }
+                    para.push(req.to_string());
+                }
+            } else if advisory.informational {
+                para.push(" — informational, no fixed version published");
+            } else {
+                para.push(" — no patched version published");
+            }
+
+            let affected_states: Vec<String> = affected_comps
+                .iter()
+                .filter(|affected_comp| affected_comp.0 == advisory.package)
+                .filter_map(|affected_comp| {
+                    let version = affected_comp.1;
+                    advisory.affects_version(version).map(|affected| {
+                        format!(
+                            "{version}: {}",
+                            if affected { "affected" } else { "unaffected" }
+                        )
+                    })
+                })
+                .collect();
+
+            if !affected_states.is_empty() {
+                para.push(format!(" ({})", affected_states.join(", ")));
+            }
+        }
+
+        Some(para)
+    }
+
+    /// The style used to color-code `severity`, or `None` if it doesn't match one of the
+    /// Critical/High/Medium/Low/None-Info bands (e.g. an unexpected or empty value), in which
+    /// case the caller falls back to a style of its own choosing.
+    fn severity_style_for(&self, severity: Option<&str>) -> Option<Style> {
+        match severity.map(Self::normalize_filter_token).as_deref() {
+            Some("critical") => Some(self.severity_critical_style),
+            Some("high") => Some(self.severity_high_style),
+            Some("medium") | Some("moderate") => Some(self.severity_medium_style),
+            Some("low") => Some(self.severity_low_style),
+            Some("none") | Some("info") | Some("informational") | Some("unknown") => {
+                Some(self.severity_none_style)
+            }
+            _ => None,
+        }
+    }
+
+    /// Ranks a severity name for [`Self::highest_severity`], highest first. Anything that isn't
+    /// a recognized band (including no severity at all) ranks lowest, same as the None/Info
+    /// color band.
+    fn severity_rank(severity: &str) -> u8 {
+        match Self::normalize_filter_token(severity).as_str() {
+            "critical" => 4,
+            "high" => 3,
+            "medium" | "moderate" => 2,
+            "low" => 1,
+            _ => 0,
+        }
+    }
+
+    /// The worst severity across all of `vuln`'s ratings, or `None` if it has no ratings with a
+    /// severity set. Used to color the vulnerability ID badge, since a single vulnerability can
+    /// carry several ratings (e.g. from different sources) at different severities.
+    fn highest_severity(vuln: &Vulnerability) -> Option<String> {
+        vuln.vulnerability_ratings
+            .as_ref()?
+            .0
+            .iter()
+            .filter_map(|rating| rating.severity.as_ref())
+            .map(|severity| severity.to_string())
+            .max_by_key(|severity| Self::severity_rank(severity))
+    }
+
+    /// The highest CVSS v3.1 base score computed from any of `vuln`'s rating vectors, or `0.0`
+    /// if it has none (or none parse). Used as the tiebreaker when sorting same-severity
+    /// vulnerabilities, so a 9.8 lands above an 9.1 within the same Critical band.
+    fn highest_cvss_score(vuln: &Vulnerability) -> f64 {
+        vuln.vulnerability_ratings
+            .iter()
+            .flat_map(|ratings| &ratings.0)
+            .filter_map(|rating| rating.vector.as_ref())
+            .filter_map(|vector| cvss::parse_v31(&vector.to_string()))
+            .map(|cvss| cvss.base_score)
+            .fold(0.0_f64, f64::max)
+    }
+
+    /// Builds the one-line legend shown once at the top of the Vulnerabilities section, naming
+    /// each severity band next to a sample of its color.
+    fn severity_legend(&self) -> Paragraph {
+        let bands = [
+            ("Critical", self.severity_critical_style),
+            ("High", self.severity_high_style),
+            ("Medium", self.severity_medium_style),
+            ("Low", self.severity_low_style),
+            ("None/Info", self.severity_none_style),
+        ];
+
+        let mut legend = Paragraph::default().styled_string("Legend: ", self.indent_style.bold());
+        for (i, (label, style)) in bands.iter().enumerate() {
+            if i > 0 {
+                legend.push("   ");
+            }
+            legend.push_styled(*label, *style);
+        }
+
+        legend
+    }
+
+    /// Classifies `vuln` into one of the six severity bands used by
+    /// [`Self::severity_summary_row`] (and [`crate::files_proc::run_summary`]'s per-file
+    /// breakdown), falling back to "Unknown" when it carries no ratings with a severity at all
+    /// (as opposed to an explicit "none"/"info" rating, which is its own band).
+    pub(crate) fn severity_bucket(vuln: &Vulnerability) -> &'static str {
+        match Self::highest_severity(vuln) {
+            None => "Unknown",
+            Some(severity) => match Self::normalize_filter_token(&severity).as_str() {
+                "critical" => "Critical",
+                "high" => "High",
+                "medium" | "moderate" => "Medium",
+                "low" => "Low",
+                _ => "None",
+            },
+        }
+    }
+
+    /// Builds the count-by-severity summary row shown above the detailed vulnerability list,
+    /// giving readers the risk profile at a glance.
+    fn severity_summary_row(&self, vulns: &[&Vulnerability]) -> Paragraph {
+        let mut counts: HashMap<&'static str, usize> = HashMap::new();
+        for vuln in vulns {
+            *counts.entry(Self::severity_bucket(vuln)).or_insert(0) += 1;
+        }
+
+        let bands = [
+            ("Critical", self.severity_critical_style),
+            ("High", self.severity_high_style),
+            ("Medium", self.severity_medium_style),
+            ("Low", self.severity_low_style),
+            ("None", self.severity_none_style),
+            ("Unknown", self.indent_style),
+        ];
+
+        let mut row =
+            Paragraph::default().styled_string("Severity Summary: ", self.indent_style.bold());
+        let mut first = true;
+        for (label, style) in bands {
+            let count = counts.get(label).copied().unwrap_or(0);
+            if count == 0 {
+                continue;
+            }
+            if !first {
+                row.push("   ");
+            }
+            first = false;
+            row.push_styled(format!("{label}: {count}"), style);
+        }
+
+        row
+    }
+
+    /// Sorts `items` in place by `name_of` using the version-aware [`natural_cmp`] comparator,
+    /// but only when [`crate::lib_utils::config::Config::natural_sort_lists`] is enabled —
+    /// otherwise `items` is left in its original (BOM iteration) order.
+    fn sort_by_name_if_enabled<T>(&self, items: &mut [T], name_of: impl Fn(&T) -> String) {
+        if self.config.natural_sort_lists {
+            items.sort_by(|a, b| natural_cmp(&name_of(a), &name_of(b)));
+        }
+    }
+
+    /// Whether any of the `--only-severity`/`--skip-severity`/`--skip-state` filters are
+    /// configured, so callers can skip the "filtered out" summary line when none are.
+    fn severity_state_filters_active(&self) -> bool {
+        !self.config.only_severity.is_empty()
+            || !self.config.skip_severity.is_empty()
+            || !self.config.skip_state.is_empty()
+    }
+
+    /// Whether `vuln` should appear in the report given the configured severity/state filters.
+    ///
+    /// A vulnerability with no ratings is treated as severity `"none"`; one with no `analysis`
+    /// is treated as state `"none"`, so `--skip-state none` (for example) hides unanalyzed
+    /// entries.
+    fn vulnerability_passes_filters(&self, vuln: &Vulnerability) -> bool {
+        let severities = Self::vuln_severities(vuln);
+
+        if !self.config.only_severity.is_empty()
+            && !Self::any_normalized_match(&severities, &self.config.only_severity)
+        {
+            return false;
+        }
+
+        if !self.config.skip_severity.is_empty()
+            && Self::any_normalized_match(&severities, &self.config.skip_severity)
+        {
+            return false;
+        }
+
+        if !self.config.skip_state.is_empty() {
+            let state = Self::vuln_state(vuln);
+            if Self::any_normalized_match(std::slice::from_ref(&state), &self.config.skip_state) {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    /// Collects the display form of every severity rating on `vuln`, or `["none"]` if it has
+    /// none, so filtering always has at least one category to compare against.
+    fn vuln_severities(vuln: &Vulnerability) -> Vec<String> {
+        let severities: Vec<String> = vuln
+            .vulnerability_ratings
+            .as_ref()
+            .map(|ratings| {
+                ratings
+                    .0
+                    .iter()
+                    .filter_map(|rating| rating.severity.as_ref())
+                    .map(|severity| severity.to_string())
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        if severities.is_empty() {
+            vec!["none".to_string()]
+        } else {
+            severities
+        }
+    }
+
+    /// The display form of `vuln.analysis.state`, or `"none"` if it has no analysis.
+    pub(crate) fn vuln_state(vuln: &Vulnerability) -> String {
+        vuln.analysis
+            .as_ref()
+            .and_then(|analysis| analysis.state.as_ref())
+            .map(|state| state.to_string())
+            .unwrap_or_else(|| "none".to_string())
+    }
+
+    /// Renders `vuln`'s VEX impact analysis (state, justification, response, detail) as a
+    /// single paragraph, or `None` if it has no analysis at all. This is the expanded
+    /// `analysis` detail CycloneDX 1.6 documents carry beyond the bare state used for
+    /// `--skip-state` filtering; the `affects` structure naming impacted components/versions
+    /// is a separate, unrendered part of the 1.6 object model.
+    fn analysis_paragraph(&self, vuln: &Vulnerability) -> Option<Paragraph> {
+        let analysis = vuln.analysis.as_ref()?;
+
+        let mut para = Paragraph::default().styled_string("Analysis: ", self.indent_style.bold());
+
+        if let Some(state) = &analysis.state {
+            para.push_styled(state.to_string(), self.indent_style);
+        } else {
+            para.push_styled("none", self.indent_style);
+        }
+
+        if let Some(justification) = &analysis.justification {
+            para.push_styled(" — Justification: ", self.indent_style.bold());
+            para.push_styled(justification.to_string(), self.indent_style);
+        }
+
+        if let Some(responses) = &analysis.response {
+            if !responses.is_empty() {
+                let response_list = responses
+                    .iter()
+                    .map(|response| response.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                para.push_styled(" — Response: ", self.indent_style.bold());
+                para.push_styled(response_list, self.indent_style);
+            }
+        }
+
+        if let Some(detail) = &analysis.detail {
+            para.push_styled(" — Detail: ", self.indent_style.bold());
+            para.push_styled(detail.to_string(), self.indent_style);
+        }
+
+        Some(para)
+    }
+
+    /// Whether any of `values` matches any of `candidates`, ignoring case and punctuation (so
+    /// a CLI token like `not_affected` matches a Display form like `"Not Affected"`).
+    fn any_normalized_match(values: &[String], candidates: &[String]) -> bool {
+        values.iter().any(|value| {
+            let normalized_value = Self::normalize_filter_token(value);
+            candidates
+                .iter()
+                .any(|candidate| Self::normalize_filter_token(candidate) == normalized_value)
+        })
+    }
+
+    /// Lowercases `token` and strips everything but ASCII alphanumerics, so severity/state
+    /// names compare equal regardless of case, spaces, underscores or hyphens.
+    ///
+    /// `pub(crate)` rather than private: [`crate::files_proc::severity_gate::check`] needs the
+    /// same normalization to compare user-supplied `--max-allowed`/`--fail-on-severity` keys
+    /// against [`Self::severity_bucket`]'s capitalized band names.
+    pub(crate) fn normalize_filter_token(token: &str) -> String {
+        token
+            .chars()
+            .filter(|c| c.is_ascii_alphanumeric())
+            .flat_map(char::to_lowercase)
+            .collect()
+    }
+
     fn render_components(&self, mut doc: Document, vex: &Bom) -> Document {
         if let Some(components) = &vex.components {
             doc.push(Paragraph::default().styled_string("Components", self.header_style));
             doc.push(genpdf::elements::Break::new(0.5));
 
-            for component in &components.0 {
+            let mut components: Vec<_> = components.0.iter().collect();
+            if self.config.natural_sort_lists {
+                components.sort_by(|a, b| {
+                    let name_ord = natural_cmp(&a.name.to_string(), &b.name.to_string());
+                    if name_ord != std::cmp::Ordering::Equal {
+                        return name_ord;
+                    }
+                    let version_a = a
+                        .version
+                        .as_ref()
+                        .map(|v| v.to_string())
+                        .unwrap_or_default();
+                    let version_b = b
+                        .version
+                        .as_ref()
+                        .map(|v| v.to_string())
+                        .unwrap_or_default();
+                    natural_cmp(&version_a, &version_b)
+                });
+            }
+
+            for component in components {
                 doc.push(
                     Paragraph::default()
                         .styled_string("Name: ", self.indent_style)
@@ -634,10 +1346,249 @@ impl<'a, 'b> PdfGenerator<'a> {
                     );
                 }
 
+                doc.push(
+                    Paragraph::default()
+                        .styled_string("Type: ", self.indent_style)
+                        .styled_string(format!("{}", component.component_type), self.version_style),
+                );
+
+                if let Some(bom_ref) = &component.bom_ref {
+                    doc.push(
+                        Paragraph::default()
+                            .styled_string("BOM-Ref: ", self.indent_style)
+                            .styled_string(format!("{bom_ref}"), self.version_style),
+                    );
+                }
+
+                if let Some(group) = &component.group {
+                    doc.push(
+                        Paragraph::default()
+                            .styled_string("Group: ", self.indent_style)
+                            .styled_string(format!("{group}"), self.version_style),
+                    );
+                }
+
+                if let Some(supplier_name) = component
+                    .supplier
+                    .as_ref()
+                    .and_then(|supplier| supplier.name.as_ref())
+                {
+                    doc.push(
+                        Paragraph::default()
+                            .styled_string("Supplier: ", self.indent_style)
+                            .styled_string(format!("{supplier_name}"), self.version_style),
+                    );
+                }
+
+                if let Some(publisher) = &component.publisher {
+                    doc.push(
+                        Paragraph::default()
+                            .styled_string("Publisher: ", self.indent_style)
+                            .styled_string(format!("{publisher}"), self.version_style),
+                    );
+                }
+
+                if let Some(purl) = &component.purl {
+                    doc.push(
+                        Paragraph::default()
+                            .styled_string("PURL: ", self.indent_style)
+                            .styled_string(format!("{purl}"), self.version_style),
+                    );
+                }
+
+                if let Some(cpe) = &component.cpe {
+                    doc.push(
+                        Paragraph::default()
+                            .styled_string("CPE: ", self.indent_style)
+                            .styled_string(format!("{cpe}"), self.version_style),
+                    );
+                }
+
+                if self.config.show_component_licenses {
+                    if let Some(license_list) = self.component_license_summary(component) {
+                        doc.push(
+                            Paragraph::default()
+                                .styled_string("Licenses: ", self.indent_style)
+                                .styled_string(license_list, self.version_style),
+                        );
+                    }
+                }
+
+                if self.config.show_component_hashes {
+                    if let Some(hash_list) = self.component_hash_summary(component) {
+                        doc.push(
+                            Paragraph::default()
+                                .styled_string("Hashes: ", self.indent_style)
+                                .styled_string(hash_list, self.version_style),
+                        );
+                    }
+                }
+
                 doc.push(genpdf::elements::Break::new(0.5));
             }
         }
 
         doc
     }
+
+    /// Builds a comma-separated summary of a component's declared licenses, or `None` if the
+    /// BoM doesn't state any.
+    fn component_license_summary(&self, component: &Component) -> Option<String> {
+        let licenses = component.licenses.as_ref()?;
+        let names: Vec<String> = licenses
+            .0
+            .iter()
+            .map(|license_choice| match license_choice {
+                LicenseChoice::License(license) => match &license.license_identifier {
+                    LicenseIdentifier::SpdxId(id) => id.to_string(),
+                    LicenseIdentifier::Name(name) => name.to_string(),
+                },
+                LicenseChoice::Expression(expression) => expression.to_string(),
+            })
+            .collect();
+
+        if names.is_empty() {
+            None
+        } else {
+            Some(names.join(", "))
+        }
+    }
+
+    /// Builds a comma-separated `algorithm: digest` summary of a component's declared hashes,
+    /// or `None` if the BoM doesn't state any.
+    fn component_hash_summary(&self, component: &Component) -> Option<String> {
+        let hashes = component.hashes.as_ref()?;
+        let parts: Vec<String> = hashes
+            .0
+            .iter()
+            .map(|hash| format!("{}: {}", hash.alg, hash.content))
+            .collect();
+
+        if parts.is_empty() {
+            None
+        } else {
+            Some(parts.join(", "))
+        }
+    }
+
+    /// Renders the BoM's `dependencies` graph (which component depends on which, by bom-ref)
+    /// as an indented tree, root components first. No-op if the BoM has no dependency section.
+    fn render_dependencies(&self, mut doc: Document, vex: &Bom) -> Document {
+        let Some(dependencies) = &vex.dependencies else {
+            return doc;
+        };
+
+        if dependencies.0.is_empty() {
+            return doc;
+        }
+
+        doc.push(Paragraph::default().styled_string("Dependency Graph", self.header_style));
+        doc.push(genpdf::elements::Break::new(0.5));
+
+        let mut edge_map: HashMap<&str, &Vec<String>> = HashMap::new();
+        for dependency in &dependencies.0 {
+            edge_map.insert(dependency.dependency_ref.as_str(), &dependency.dependencies);
+        }
+
+        let mut name_map: HashMap<&str, String> = HashMap::new();
+        if let Some(components) = &vex.components {
+            for component in &components.0 {
+                if let Some(bom_ref) = &component.bom_ref {
+                    name_map.insert(bom_ref.as_str(), component.name.to_string());
+                }
+            }
+        }
+
+        // A component only counts as a root if nothing else in the graph depends on it.
+        let mut referenced: HashSet<&str> = HashSet::new();
+        for dependency in &dependencies.0 {
+            for child_ref in &dependency.dependencies {
+                referenced.insert(child_ref.as_str());
+            }
+        }
+
+        let mut roots: Vec<&str> = dependencies
+            .0
+            .iter()
+            .map(|dependency| dependency.dependency_ref.as_str())
+            .filter(|dependency_ref| !referenced.contains(dependency_ref))
+            .collect();
+
+        // A fully cyclic graph has no root; fall back to rendering every declared node once.
+        if roots.is_empty() {
+            roots = dependencies
+                .0
+                .iter()
+                .map(|dependency| dependency.dependency_ref.as_str())
+                .collect();
+        }
+
+        if self.config.natural_sort_lists {
+            roots.sort_by(|a, b| {
+                natural_cmp(
+                    name_map.get(a).map(String::as_str).unwrap_or(a),
+                    name_map.get(b).map(String::as_str).unwrap_or(b),
+                )
+            });
+        }
+
+        for root in roots {
+            let mut path = HashSet::new();
+            self.render_dependency_node(&mut doc, root, &edge_map, &name_map, &mut path, 0);
+        }
+
+        doc
+    }
+
+    /// Renders a single dependency-graph node and recurses into its children, tracking the
+    /// bom-refs visited on the current path so a dependency cycle is shown once as
+    /// "(already shown)" instead of descended into again.
+    fn render_dependency_node<'a>(
+        &self,
+        doc: &mut Document,
+        bom_ref: &'a str,
+        edge_map: &HashMap<&'a str, &'a Vec<String>>,
+        name_map: &HashMap<&'a str, String>,
+        path: &mut HashSet<&'a str>,
+        depth: usize,
+    ) {
+        let label = name_map
+            .get(bom_ref)
+            .cloned()
+            .unwrap_or_else(|| bom_ref.to_string());
+        let prefix = "  ".repeat(depth);
+
+        if !path.insert(bom_ref) {
+            doc.push(
+                Paragraph::default()
+                    .styled_string(format!("{prefix}- "), self.indent_style)
+                    .styled_string(label, self.comp_name_style)
+                    .styled_string(" (already shown)", self.indent_style.italic()),
+            );
+            return;
+        }
+
+        doc.push(
+            Paragraph::default()
+                .styled_string(format!("{prefix}- "), self.indent_style)
+                .styled_string(label, self.comp_name_style),
+        );
+
+        if let Some(children) = edge_map.get(bom_ref) {
+            let mut children: Vec<&str> = children.iter().map(String::as_str).collect();
+            if self.config.natural_sort_lists {
+                children.sort_by(|a, b| {
+                    natural_cmp(
+                        name_map.get(a).map(String::as_str).unwrap_or(a),
+                        name_map.get(b).map(String::as_str).unwrap_or(b),
+                    )
+                });
+            }
+            for child in children {
+                self.render_dependency_node(doc, child, edge_map, name_map, path, depth + 1);
+            }
+        }
+
+        path.remove(bom_ref);
+    }
 }
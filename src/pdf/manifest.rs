@@ -0,0 +1,187 @@
+//! Checksum manifest sidecar for generated PDFs.
+//!
+//! [`write_manifest`] records a BLAKE3 digest for each converted PDF alongside its path, in the
+//! same `<hex digest>  <path>` shape as `sha256sum`/`b3sum` output. [`run_check_manifest`] (behind
+//! `vex2pdf check-manifest`) reads such a manifest back and recomputes every entry's digest,
+//! reporting tamper or drift instead of silently trusting the archived report.
+
+use crate::lib_utils::errors::Vex2PdfError;
+use crate::lib_utils::fs_context;
+use log::info;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// A single manifest row: a generated PDF's path and its BLAKE3 digest.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ManifestEntry {
+    pub path: PathBuf,
+    pub checksum: String,
+}
+
+/// Hashes `path`'s raw bytes with BLAKE3, returning the digest as a lowercase hex string.
+pub fn compute_pdf_checksum(path: &Path) -> Result<String, Vex2PdfError> {
+    let content = fs_context::read(path)?;
+    Ok(blake3::hash(&content).to_hex().to_string())
+}
+
+/// Writes `entries` to `manifest_path`, one `<checksum>  <path>` line per entry, sorted by path
+/// for a deterministic manifest across runs over the same input set.
+pub fn write_manifest(entries: &[ManifestEntry], manifest_path: &Path) -> Result<(), Vex2PdfError> {
+    let mut sorted: Vec<&ManifestEntry> = entries.iter().collect();
+    sorted.sort_by(|a, b| a.path.cmp(&b.path));
+
+    let mut contents = String::new();
+    for entry in sorted {
+        contents.push_str(&entry.checksum);
+        contents.push_str("  ");
+        contents.push_str(&entry.path.to_string_lossy());
+        contents.push('\n');
+    }
+
+    fs_context::write(manifest_path, contents)?;
+    info!(
+        "Wrote checksum manifest for {} file{} to {}",
+        entries.len(),
+        if entries.len() == 1 { "" } else { "s" },
+        manifest_path.display()
+    );
+
+    Ok(())
+}
+
+/// Parses a manifest line of the form `<checksum>  <path>` into its two fields.
+fn parse_manifest_line(line: &str) -> Option<(&str, &str)> {
+    let line = line.trim();
+    if line.is_empty() {
+        return None;
+    }
+    line.split_once("  ")
+        .or_else(|| line.split_once(char::is_whitespace))
+}
+
+/// Re-hashes every file listed in `manifest_path` and reports any whose digest no longer
+/// matches, or that are now missing. Relative paths in the manifest are resolved against the
+/// manifest file's own directory, matching where `--manifest` wrote them from.
+///
+/// Every entry is checked even if an earlier one fails, so one mismatch doesn't hide problems
+/// with the rest of the manifest.
+pub fn run_check_manifest(manifest_path: &Path) -> Result<(), Vex2PdfError> {
+    let contents = fs_context::read_to_string(manifest_path)?;
+    let base_dir = manifest_path.parent().unwrap_or_else(|| Path::new("."));
+
+    let mut mismatches = Vec::new();
+    let mut checked = 0usize;
+
+    for line in contents.lines() {
+        let Some((expected_checksum, rel_path)) = parse_manifest_line(line) else {
+            continue;
+        };
+        checked += 1;
+
+        let entry_path = Path::new(rel_path);
+        let resolved_path = if entry_path.is_absolute() {
+            entry_path.to_path_buf()
+        } else {
+            base_dir.join(entry_path)
+        };
+
+        match compute_pdf_checksum(&resolved_path) {
+            Ok(actual_checksum) if actual_checksum == expected_checksum => {
+                info!("OK: {rel_path}");
+            }
+            Ok(actual_checksum) => mismatches.push(format!(
+                "{rel_path}: checksum mismatch (expected {expected_checksum}, got {actual_checksum})"
+            )),
+            Err(e) => mismatches.push(format!("{rel_path}: {e}")),
+        }
+    }
+
+    info!("Checked {checked} entries from {}", manifest_path.display());
+
+    if mismatches.is_empty() {
+        Ok(())
+    } else {
+        Err(Vex2PdfError::ManifestMismatch(mismatches.join("\n")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_and_check_manifest_round_trip() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let pdf_path = temp_dir.path().join("report.pdf");
+        fs::write(&pdf_path, b"%PDF-1.7 fake content").unwrap();
+
+        let checksum = compute_pdf_checksum(&pdf_path).unwrap();
+        let entries = vec![ManifestEntry {
+            path: PathBuf::from("report.pdf"),
+            checksum: checksum.clone(),
+        }];
+
+        let manifest_path = temp_dir.path().join("manifest.txt");
+        write_manifest(&entries, &manifest_path).unwrap();
+
+        assert!(run_check_manifest(&manifest_path).is_ok());
+    }
+
+    #[test]
+    fn test_check_manifest_detects_tampered_file() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let pdf_path = temp_dir.path().join("report.pdf");
+        fs::write(&pdf_path, b"%PDF-1.7 original content").unwrap();
+
+        let checksum = compute_pdf_checksum(&pdf_path).unwrap();
+        let entries = vec![ManifestEntry {
+            path: PathBuf::from("report.pdf"),
+            checksum,
+        }];
+
+        let manifest_path = temp_dir.path().join("manifest.txt");
+        write_manifest(&entries, &manifest_path).unwrap();
+
+        fs::write(&pdf_path, b"%PDF-1.7 tampered content").unwrap();
+
+        match run_check_manifest(&manifest_path) {
+            Err(Vex2PdfError::ManifestMismatch(msg)) => assert!(msg.contains("mismatch")),
+            other => panic!("expected ManifestMismatch, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_check_manifest_detects_missing_file() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let manifest_path = temp_dir.path().join("manifest.txt");
+        fs::write(&manifest_path, "deadbeef  missing.pdf\n").unwrap();
+
+        match run_check_manifest(&manifest_path) {
+            Err(Vex2PdfError::ManifestMismatch(msg)) => assert!(msg.contains("missing.pdf")),
+            other => panic!("expected ManifestMismatch, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_write_manifest_sorts_entries_by_path() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let manifest_path = temp_dir.path().join("manifest.txt");
+
+        let entries = vec![
+            ManifestEntry {
+                path: PathBuf::from("b.pdf"),
+                checksum: "bbb".to_string(),
+            },
+            ManifestEntry {
+                path: PathBuf::from("a.pdf"),
+                checksum: "aaa".to_string(),
+            },
+        ];
+
+        write_manifest(&entries, &manifest_path).unwrap();
+        let contents = fs::read_to_string(&manifest_path).unwrap();
+        let lines: Vec<&str> = contents.lines().collect();
+
+        assert_eq!(lines, vec!["aaa  a.pdf", "bbb  b.pdf"]);
+    }
+}
@@ -0,0 +1,304 @@
+//! Deterministic merging of several parsed CycloneDX documents into one consolidated [`Bom`],
+//! backing `--merge` mode.
+//!
+//! Components are deduplicated by `bom-ref` (falling back to `purl`, then name+version when
+//! neither is present). Vulnerabilities are unioned by id (an unkeyed vulnerability, lacking an
+//! id, is always kept as-is); when the same id appears in more than one source with conflicting
+//! `analysis.state`, the most severe state wins and every losing entry is recorded in
+//! [`MergedBom::conflicts`] rather than silently dropped.
+
+use cyclonedx_bom::models::component::{Component, Components};
+use cyclonedx_bom::models::vulnerability::{Vulnerabilities, Vulnerability};
+use cyclonedx_bom::prelude::Bom;
+use std::collections::{HashMap, HashSet};
+
+/// One source document folded into a [`MergedBom`]: its filename and the `Bom` parsed from it.
+pub struct MergeSource {
+    pub filename: String,
+    pub bom: Bom,
+}
+
+/// A single losing `analysis.state`/`justification` entry that was dropped in favor of a more
+/// severe one for the same vulnerability id.
+#[derive(Debug, Clone)]
+pub struct LosingAnalysis {
+    pub source_filename: String,
+    pub state: Option<String>,
+    pub justification: Option<String>,
+}
+
+/// One vulnerability id that appeared with conflicting `analysis` data across sources.
+#[derive(Debug, Clone)]
+pub struct AnalysisConflict {
+    pub vulnerability_id: String,
+    pub kept_source_filename: String,
+    pub kept_state: Option<String>,
+    pub losing: Vec<LosingAnalysis>,
+}
+
+/// The record of one source document, kept for the merged report's provenance appendix.
+#[derive(Debug, Clone)]
+pub struct SourceProvenance {
+    pub filename: String,
+    pub serial_number: Option<String>,
+}
+
+/// The result of [`merge_sources`]: a single consolidated `Bom` plus everything needed to render
+/// a "where did this come from" appendix.
+pub struct MergedBom {
+    pub bom: Bom,
+    pub sources: Vec<SourceProvenance>,
+    pub conflicts: Vec<AnalysisConflict>,
+}
+
+/// The identity used to deduplicate components across sources: `bom-ref` when present,
+/// otherwise `purl`, otherwise name+version. Two components sharing none of these are treated
+/// as distinct even if their names happen to match, since that's the best information we have.
+fn component_identity(component: &Component) -> String {
+    if let Some(bom_ref) = &component.bom_ref {
+        return format!("ref:{bom_ref}");
+    }
+    if let Some(purl) = &component.purl {
+        return format!("purl:{purl}");
+    }
+    format!(
+        "nv:{}:{}",
+        component.name,
+        component
+            .version
+            .as_ref()
+            .map(|version| version.to_string())
+            .unwrap_or_default()
+    )
+}
+
+/// Ranks an `analysis.state` string by severity, highest first, so the most severe of several
+/// conflicting analyses for the same vulnerability id wins. Anything unrecognized (including no
+/// state at all) ranks lowest.
+fn state_rank(state: Option<&str>) -> u8 {
+    let normalized: String = state
+        .unwrap_or_default()
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect();
+
+    match normalized.as_str() {
+        "exploitable" | "affected" | "intriage" | "underinvestigation" => 2,
+        "falsepositive" | "notaffected" | "resolved" | "resolvedwithpedigree" => 1,
+        _ => 0,
+    }
+}
+
+fn analysis_state_of(vuln: &Vulnerability) -> Option<String> {
+    vuln.analysis
+        .as_ref()
+        .and_then(|analysis| analysis.state.as_ref())
+        .map(|state| state.to_string())
+}
+
+fn analysis_justification_of(vuln: &Vulnerability) -> Option<String> {
+    vuln.analysis
+        .as_ref()
+        .and_then(|analysis| analysis.justification.as_ref())
+        .map(|justification| justification.to_string())
+}
+
+/// Deduplicates components across `sources` by [`component_identity`], keeping the first
+/// occurrence (source order, then document order) of each identity.
+fn merge_components(sources: &[MergeSource]) -> Vec<Component> {
+    let mut seen = HashSet::new();
+    let mut merged = Vec::new();
+
+    for source in sources {
+        if let Some(components) = &source.bom.components {
+            for component in &components.0 {
+                if seen.insert(component_identity(component)) {
+                    merged.push(component.clone());
+                }
+            }
+        }
+    }
+
+    merged
+}
+
+/// Unions vulnerabilities across `sources` by id, resolving same-id conflicts by the most severe
+/// `analysis.state` and recording every losing entry. A vulnerability with no id can't be
+/// grouped, so every occurrence is kept independently.
+fn merge_vulnerabilities(sources: &[MergeSource]) -> (Vec<Vulnerability>, Vec<AnalysisConflict>) {
+    let mut grouped: HashMap<String, Vec<(String, Vulnerability)>> = HashMap::new();
+    let mut merged = Vec::new();
+
+    for source in sources {
+        if let Some(vulnerabilities) = &source.bom.vulnerabilities {
+            for vuln in &vulnerabilities.0 {
+                match vuln.id.as_ref().map(|id| id.to_string()) {
+                    Some(id) => grouped
+                        .entry(id)
+                        .or_default()
+                        .push((source.filename.clone(), vuln.clone())),
+                    None => merged.push(vuln.clone()),
+                }
+            }
+        }
+    }
+
+    let mut ids: Vec<&String> = grouped.keys().collect();
+    ids.sort();
+
+    let mut conflicts = Vec::new();
+
+    for id in ids {
+        let entries = &grouped[id];
+
+        if entries.len() == 1 {
+            merged.push(entries[0].1.clone());
+            continue;
+        }
+
+        let winner_index = entries
+            .iter()
+            .enumerate()
+            .max_by_key(|(_, (_, vuln))| state_rank(analysis_state_of(vuln).as_deref()))
+            .map(|(index, _)| index)
+            .unwrap_or(0);
+
+        let kept_state = analysis_state_of(&entries[winner_index].1);
+        let kept_justification = analysis_justification_of(&entries[winner_index].1);
+
+        // An entry whose analysis matches the winner's is a plain duplicate, not a conflict.
+        let losing: Vec<LosingAnalysis> = entries
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| *index != winner_index)
+            .map(|(_, (filename, vuln))| LosingAnalysis {
+                source_filename: filename.clone(),
+                state: analysis_state_of(vuln),
+                justification: analysis_justification_of(vuln),
+            })
+            .filter(|losing| {
+                losing.state != kept_state || losing.justification != kept_justification
+            })
+            .collect();
+
+        if !losing.is_empty() {
+            conflicts.push(AnalysisConflict {
+                vulnerability_id: id.clone(),
+                kept_source_filename: entries[winner_index].0.clone(),
+                kept_state,
+                losing,
+            });
+        }
+
+        merged.push(entries[winner_index].1.clone());
+    }
+
+    (merged, conflicts)
+}
+
+/// Merges `sources` into one consolidated [`Bom`], deterministically: the spec version and
+/// metadata of the first source are kept as the merged document's own (sources are typically
+/// exports of the same tooling pipeline), its components and vulnerabilities are replaced with
+/// the deduplicated/unioned sets built from every source.
+pub fn merge_sources(sources: Vec<MergeSource>) -> MergedBom {
+    let provenance = sources
+        .iter()
+        .map(|source| SourceProvenance {
+            filename: source.filename.clone(),
+            serial_number: source.bom.serial_number.as_ref().map(|s| s.to_string()),
+        })
+        .collect();
+
+    let merged_components = merge_components(&sources);
+    let (merged_vulnerabilities, conflicts) = merge_vulnerabilities(&sources);
+
+    let base_bom = sources
+        .first()
+        .map(|source| source.bom.clone())
+        .unwrap_or_default();
+
+    let bom = Bom {
+        components: Some(Components(merged_components)),
+        vulnerabilities: Some(Vulnerabilities(merged_vulnerabilities)),
+        ..base_bom
+    };
+
+    MergedBom {
+        bom,
+        sources: provenance,
+        conflicts,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cyclonedx_bom::prelude::NormalizedString;
+
+    fn vuln_with_id(id: &str) -> Vulnerability {
+        Vulnerability {
+            id: Some(NormalizedString::new(id)),
+            ..Vulnerability::default()
+        }
+    }
+
+    #[test]
+    fn test_state_rank_prioritizes_affected_over_not_affected() {
+        assert!(state_rank(Some("affected")) > state_rank(Some("not_affected")));
+        assert!(state_rank(Some("exploitable")) > state_rank(Some("resolved")));
+    }
+
+    #[test]
+    fn test_state_rank_unknown_state_ranks_lowest() {
+        assert_eq!(state_rank(None), 0);
+        assert_eq!(state_rank(Some("bogus")), 0);
+    }
+
+    #[test]
+    fn test_merge_vulnerabilities_unions_distinct_ids() {
+        let sources = vec![
+            MergeSource {
+                filename: "a.json".to_string(),
+                bom: Bom {
+                    vulnerabilities: Some(Vulnerabilities(vec![vuln_with_id("CVE-2024-0001")])),
+                    ..Bom::default()
+                },
+            },
+            MergeSource {
+                filename: "b.json".to_string(),
+                bom: Bom {
+                    vulnerabilities: Some(Vulnerabilities(vec![vuln_with_id("CVE-2024-0002")])),
+                    ..Bom::default()
+                },
+            },
+        ];
+
+        let (merged, conflicts) = merge_vulnerabilities(&sources);
+        assert_eq!(merged.len(), 2);
+        assert!(conflicts.is_empty());
+    }
+
+    #[test]
+    fn test_merge_vulnerabilities_keeps_unkeyed_vulnerabilities_independently() {
+        let sources = vec![
+            MergeSource {
+                filename: "a.json".to_string(),
+                bom: Bom {
+                    vulnerabilities: Some(Vulnerabilities(vec![Vulnerability::default()])),
+                    ..Bom::default()
+                },
+            },
+            MergeSource {
+                filename: "b.json".to_string(),
+                bom: Bom {
+                    vulnerabilities: Some(Vulnerabilities(vec![Vulnerability::default()])),
+                    ..Bom::default()
+                },
+            },
+        ];
+
+        let (merged, _conflicts) = merge_vulnerabilities(&sources);
+        assert_eq!(merged.len(), 2);
+    }
+}
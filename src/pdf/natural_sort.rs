@@ -0,0 +1,101 @@
+//! Version-aware ("natural") string ordering.
+//!
+//! Plain lexical comparison sorts `v1.10.0` before `v1.9.0` because `'1'` < `'9'` byte-wise.
+//! [`natural_cmp`] instead splits each string into alternating runs of digits and non-digits,
+//! compares digit runs numerically and non-digit runs lexically, so version-like names sort in
+//! the order a human expects.
+
+use std::cmp::Ordering;
+
+/// One contiguous run of either ASCII digits or non-digits within a string.
+#[derive(Debug, PartialEq, Eq)]
+enum Run<'a> {
+    Number(&'a str),
+    Text(&'a str),
+}
+
+/// Splits `s` into alternating [`Run::Number`]/[`Run::Text`] chunks, in order.
+fn split_runs(s: &str) -> Vec<Run<'_>> {
+    let mut runs = Vec::new();
+    let mut start = 0;
+    let mut in_digits = false;
+
+    for (i, c) in s.char_indices() {
+        let is_digit = c.is_ascii_digit();
+        if i == 0 {
+            in_digits = is_digit;
+        } else if is_digit != in_digits {
+            runs.push(if in_digits {
+                Run::Number(&s[start..i])
+            } else {
+                Run::Text(&s[start..i])
+            });
+            start = i;
+            in_digits = is_digit;
+        }
+    }
+
+    if start < s.len() {
+        runs.push(if in_digits {
+            Run::Number(&s[start..])
+        } else {
+            Run::Text(&s[start..])
+        });
+    }
+
+    runs
+}
+
+/// Compares `a` and `b` by alternating numeric/text run, so `"v1.9.0"` sorts before `"v1.10.0"`
+/// and `"item2"` sorts before `"item10"`. Falls back to lexical comparison of the run text when
+/// a numeric run overflows `u64` or the two strings' run structures otherwise don't line up.
+pub(crate) fn natural_cmp(a: &str, b: &str) -> Ordering {
+    let runs_a = split_runs(a);
+    let runs_b = split_runs(b);
+
+    for (run_a, run_b) in runs_a.iter().zip(runs_b.iter()) {
+        let ordering = match (run_a, run_b) {
+            (Run::Number(na), Run::Number(nb)) => match (na.parse::<u64>(), nb.parse::<u64>()) {
+                (Ok(va), Ok(vb)) => va.cmp(&vb).then_with(|| na.cmp(nb)),
+                _ => na.cmp(nb),
+            },
+            (Run::Text(ta), Run::Text(tb)) => ta.cmp(tb),
+            (Run::Number(na), Run::Text(tb)) => na.cmp(tb),
+            (Run::Text(ta), Run::Number(nb)) => ta.cmp(nb),
+        };
+
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+
+    runs_a.len().cmp(&runs_b.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_natural_cmp_orders_versions_numerically() {
+        assert_eq!(natural_cmp("v1.9.0", "v1.10.0"), Ordering::Less);
+        assert_eq!(natural_cmp("v1.10.0", "v1.9.0"), Ordering::Greater);
+        assert_eq!(natural_cmp("v1.9.0", "v1.9.0"), Ordering::Equal);
+    }
+
+    #[test]
+    fn test_natural_cmp_orders_item_names_numerically() {
+        assert_eq!(natural_cmp("item2", "item10"), Ordering::Less);
+        assert_eq!(natural_cmp("item10", "item2"), Ordering::Greater);
+    }
+
+    #[test]
+    fn test_natural_cmp_falls_back_to_lexical_for_non_numeric_text() {
+        assert_eq!(natural_cmp("alpha", "beta"), Ordering::Less);
+    }
+
+    #[test]
+    fn test_natural_cmp_shorter_prefix_sorts_first() {
+        assert_eq!(natural_cmp("item1", "item1a"), Ordering::Less);
+    }
+}
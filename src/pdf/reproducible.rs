@@ -0,0 +1,247 @@
+//! Post-processing pass that makes generated PDFs byte-identical for byte-identical input.
+//!
+//! genpdf (via `printpdf`) stamps every render with the current wall-clock time
+//! (`CreationDate`/`ModDate` and their XMP equivalents) and random-looking document/instance
+//! identifiers (the trailer `/ID` pair and the XMP `InstanceID`/`DocumentID`), so two runs over
+//! the same input never produce the same bytes. [`make_reproducible`] rewrites those fields in
+//! an already-rendered PDF: the dates are pinned to a caller-supplied instant (typically
+//! `SOURCE_DATE_EPOCH`), and the `/ID` pair plus the XMP ids are derived from a BLAKE3 hash of
+//! the otherwise-deterministic content instead.
+
+use lopdf::{Dictionary, Document, Object, StringFormat};
+use std::io;
+
+/// Fixed placeholder used for the trailer `/ID` and XMP ids while computing the content hash,
+/// so the hash doesn't depend on itself.
+const PLACEHOLDER_ID: [u8; 16] = [0u8; 16];
+
+/// Rewrites `pdf_bytes` so identical input yields byte-identical output.
+///
+/// `epoch_seconds` (typically parsed from `SOURCE_DATE_EPOCH`) pins `CreationDate`/`ModDate` and
+/// their XMP equivalents; the trailer `/ID` pair and XMP `InstanceID`/`DocumentID` are derived
+/// from a BLAKE3 hash of the content once those dates are pinned. Missing XMP metadata is not an
+/// error - the Info-dict dates and trailer `/ID` are rewritten regardless.
+pub(crate) fn make_reproducible(pdf_bytes: &[u8], epoch_seconds: i64) -> Result<Vec<u8>, io::Error> {
+    let mut doc = Document::load_mem(pdf_bytes).map_err(|e| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("failed to parse rendered PDF for reproducible post-processing: {e}"),
+        )
+    })?;
+
+    let pdf_date = format_pdf_date(epoch_seconds);
+    let xmp_date = format_xmp_date(epoch_seconds);
+
+    set_info_dates(&mut doc, &pdf_date);
+    set_trailer_id(&mut doc, &PLACEHOLDER_ID);
+    set_xmp_fields(&mut doc, &xmp_date, &uuid_urn(&PLACEHOLDER_ID));
+
+    // Serialize once with placeholder ids so the content hash doesn't depend on the final ids.
+    let mut pinned = Vec::new();
+    doc.save_to(&mut pinned).map_err(to_io_error)?;
+
+    let content_hash = blake3::hash(&pinned);
+    let id_bytes: [u8; 16] = content_hash.as_bytes()[..16].try_into().unwrap();
+
+    set_trailer_id(&mut doc, &id_bytes);
+    set_xmp_fields(&mut doc, &xmp_date, &uuid_urn(&id_bytes));
+
+    let mut out = Vec::new();
+    doc.save_to(&mut out).map_err(to_io_error)?;
+
+    Ok(out)
+}
+
+fn to_io_error(e: impl std::fmt::Display) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::Other,
+        format!("failed to serialize reproducible PDF: {e}"),
+    )
+}
+
+/// Sets `CreationDate`/`ModDate` on the document's Info dictionary, if one exists.
+fn set_info_dates(doc: &mut Document, pdf_date: &str) {
+    let Some(info_ref) = doc
+        .trailer
+        .get(b"Info")
+        .ok()
+        .and_then(|o| o.as_reference().ok())
+    else {
+        return;
+    };
+    let Some(info) = doc
+        .get_object_mut(info_ref)
+        .ok()
+        .and_then(|o| o.as_dict_mut().ok())
+    else {
+        return;
+    };
+
+    let value = Object::String(pdf_date.as_bytes().to_vec(), StringFormat::Literal);
+    info.set("CreationDate", value.clone());
+    info.set("ModDate", value);
+}
+
+/// Sets the trailer `/ID` pair to two copies of `id_bytes`, matching what `printpdf` itself
+/// writes when it can't tell the document apart across saves.
+fn set_trailer_id(doc: &mut Document, id_bytes: &[u8; 16]) {
+    let id_obj = Object::String(id_bytes.to_vec(), StringFormat::Hexadecimal);
+    doc.trailer
+        .set("ID", Object::Array(vec![id_obj.clone(), id_obj]));
+}
+
+/// Rewrites the XMP metadata stream's date and id fields, if the document embeds one. The
+/// stream is located via the document catalog's `/Metadata` entry; absence (or an unexpected
+/// shape) is not an error, since XMP is optional here.
+fn set_xmp_fields(doc: &mut Document, xmp_date: &str, uuid: &str) {
+    let Some(metadata_ref) = find_metadata_stream_ref(doc) else {
+        return;
+    };
+    let Some(stream) = doc
+        .get_object_mut(metadata_ref)
+        .ok()
+        .and_then(|o| o.as_stream_mut().ok())
+    else {
+        return;
+    };
+    let Ok(xml) = String::from_utf8(stream.content.clone()) else {
+        return;
+    };
+
+    let mut xml = xml;
+    for tag in ["xmp:CreateDate", "xmp:ModifyDate", "xmp:MetadataDate"] {
+        xml = replace_xmp_element_text(&xml, tag, xmp_date);
+    }
+    for tag in ["xmpMM:InstanceID", "xmpMM:DocumentID"] {
+        xml = replace_xmp_element_text(&xml, tag, uuid);
+    }
+
+    stream.content = xml.into_bytes();
+}
+
+fn find_metadata_stream_ref(doc: &Document) -> Option<(u32, u16)> {
+    let root_ref = doc.trailer.get(b"Root").ok()?.as_reference().ok()?;
+    let catalog: &Dictionary = doc.get_object(root_ref).ok()?.as_dict().ok()?;
+    catalog.get(b"Metadata").ok()?.as_reference().ok()
+}
+
+/// Replaces the text content of every `<tag>...</tag>` element with `value`, leaving the
+/// surrounding document untouched if the tag isn't present.
+fn replace_xmp_element_text(xml: &str, tag: &str, value: &str) -> String {
+    let open = format!("<{tag}>");
+    let close = format!("</{tag}>");
+
+    let Some(start) = xml.find(&open) else {
+        return xml.to_string();
+    };
+    let content_start = start + open.len();
+    let Some(close_offset) = xml[content_start..].find(&close) else {
+        return xml.to_string();
+    };
+    let content_end = content_start + close_offset;
+
+    format!("{}{}{}", &xml[..content_start], value, &xml[content_end..])
+}
+
+/// Formats a `uuid:`-prefixed identifier from 16 hash bytes, matching the shape of the random
+/// UUIDs XMP tooling normally emits.
+fn uuid_urn(bytes: &[u8; 16]) -> String {
+    format!(
+        "uuid:{:02x}{:02x}{:02x}{:02x}-{:02x}{:02x}-{:02x}{:02x}-{:02x}{:02x}-{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}",
+        bytes[0], bytes[1], bytes[2], bytes[3],
+        bytes[4], bytes[5],
+        bytes[6], bytes[7],
+        bytes[8], bytes[9],
+        bytes[10], bytes[11], bytes[12], bytes[13], bytes[14], bytes[15],
+    )
+}
+
+/// Formats a Unix timestamp as a PDF date string: `D:YYYYMMDDHHmmSSZ`.
+fn format_pdf_date(epoch_seconds: i64) -> String {
+    let (y, mo, d, h, mi, s) = civil_from_unix(epoch_seconds);
+    format!("D:{y:04}{mo:02}{d:02}{h:02}{mi:02}{s:02}Z")
+}
+
+/// Formats a Unix timestamp as an XMP/ISO-8601 date string: `YYYY-MM-DDThh:mm:ssZ`.
+fn format_xmp_date(epoch_seconds: i64) -> String {
+    let (y, mo, d, h, mi, s) = civil_from_unix(epoch_seconds);
+    format!("{y:04}-{mo:02}-{d:02}T{h:02}:{mi:02}:{s:02}Z")
+}
+
+/// Converts a Unix timestamp (UTC, assumed non-negative) into `(year, month, day, hour, minute,
+/// second)`. Implements Howard Hinnant's `civil_from_days` algorithm so this module doesn't need
+/// a date/time dependency of its own for what is otherwise a single formatting need.
+fn civil_from_unix(epoch_seconds: i64) -> (i64, u32, u32, u32, u32, u32) {
+    let epoch_seconds = epoch_seconds.max(0);
+    let days = epoch_seconds.div_euclid(86400);
+    let time_of_day = epoch_seconds.rem_euclid(86400);
+
+    let z = days + 719468;
+    let era = if z >= 0 { z } else { z - 146096 } / 146097;
+    let doe = (z - era * 146097) as u64;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let y = yoe as i64 + era * 400;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let y = if m <= 2 { y + 1 } else { y };
+
+    let h = (time_of_day / 3600) as u32;
+    let mi = ((time_of_day % 3600) / 60) as u32;
+    let s = (time_of_day % 60) as u32;
+
+    (y, m, d, h, mi, s)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_civil_from_unix_epoch() {
+        assert_eq!(civil_from_unix(0), (1970, 1, 1, 0, 0, 0));
+    }
+
+    #[test]
+    fn test_civil_from_unix_known_instant() {
+        // 2024-01-01T00:00:00Z
+        assert_eq!(civil_from_unix(1_704_067_200), (2024, 1, 1, 0, 0, 0));
+    }
+
+    #[test]
+    fn test_format_pdf_date() {
+        assert_eq!(format_pdf_date(1_704_067_200), "D:20240101000000Z");
+    }
+
+    #[test]
+    fn test_format_xmp_date() {
+        assert_eq!(format_xmp_date(1_704_067_200), "2024-01-01T00:00:00Z");
+    }
+
+    #[test]
+    fn test_replace_xmp_element_text_replaces_content() {
+        let xml = "<rdf:li><xmp:CreateDate>2020-01-01T00:00:00Z</xmp:CreateDate></rdf:li>";
+        let replaced = replace_xmp_element_text(xml, "xmp:CreateDate", "2024-01-01T00:00:00Z");
+        assert_eq!(
+            replaced,
+            "<rdf:li><xmp:CreateDate>2024-01-01T00:00:00Z</xmp:CreateDate></rdf:li>"
+        );
+    }
+
+    #[test]
+    fn test_replace_xmp_element_text_missing_tag_is_noop() {
+        let xml = "<rdf:li>no dates here</rdf:li>";
+        assert_eq!(
+            replace_xmp_element_text(xml, "xmp:CreateDate", "2024-01-01T00:00:00Z"),
+            xml
+        );
+    }
+
+    #[test]
+    fn test_uuid_urn_format() {
+        let bytes = [0xabu8; 16];
+        let uuid = uuid_urn(&bytes);
+        assert_eq!(uuid, "uuid:abababab-abab-abab-abab-abababababab");
+    }
+}
@@ -0,0 +1,279 @@
+//! PDF checksum snapshot testing, shared by `examples/generate_checksums` and the integration
+//! test suite in `tests/`. Modeled on `ui_test`'s output-conflict handling: dynamic content
+//! (timestamps, randomly generated IDs) is stripped line-by-line via a set of user-extensible
+//! [`regex::Regex`] filters before hashing, and [`OutputConflictHandling`] decides whether a
+//! difference from the recorded checksum is reported ([`OutputConflictHandling::Verify`]) or
+//! simply re-recorded ([`OutputConflictHandling::Bless`]).
+//!
+//! Unlike [`crate::pdf::manifest`], which ships as the `--manifest`/`check-manifest` user-facing
+//! feature, this module exists purely to keep the reference PDFs this crate tests against out of
+//! version control: a `.pdf`'s normalized checksum is committed instead of the 42MB of PDFs
+//! themselves.
+
+use crate::lib_utils::errors::Vex2PdfError;
+use crate::lib_utils::fs_context;
+use log::info;
+use regex::Regex;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// How [`run_snapshot`] should treat a PDF whose normalized checksum no longer matches the one
+/// recorded in the checksums file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputConflictHandling {
+    /// Report every checksum that no longer matches instead of touching the checksums file.
+    Verify,
+    /// Recompute every checksum and overwrite the checksums file with the fresh values, the
+    /// same one-command re-bless `ui_test` offers for an intentional snapshot change.
+    Bless,
+}
+
+/// The default normalization filters: each a compiled regex matching a line that embeds a
+/// timestamp or a randomly generated ID rather than actual document content, so it should be
+/// stripped before hashing. Extend this list (or build an unrelated one) to ignore additional
+/// dynamic fields without touching [`normalize_pdf_content`] itself.
+pub fn default_normalization_filters() -> Vec<Regex> {
+    [
+        "CreateDate",
+        "ModifyDate",
+        "MetadataDate",
+        "CreationDate",
+        "ModDate",
+        "InstanceID", // XMP metadata UUID
+        "DocumentID", // XMP document UUID
+        r"/ID\[",     // PDF document IDs
+    ]
+    .iter()
+    .map(|pattern| Regex::new(pattern).expect("default normalization pattern is valid regex"))
+    .collect()
+}
+
+/// Strips every line matching any of `filters` from `content`, so the result hashes the same
+/// across runs that only differ in timestamps or randomly generated IDs.
+pub fn normalize_pdf_content(content: &str, filters: &[Regex]) -> String {
+    content
+        .lines()
+        .filter(|line| !filters.iter().any(|filter| filter.is_match(line)))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Reads `pdf_path` and returns the BLAKE3 checksum of its normalized content, as a lowercase
+/// hex string.
+pub fn compute_normalized_checksum(
+    pdf_path: &Path,
+    filters: &[Regex],
+) -> Result<String, Vex2PdfError> {
+    let content = fs_context::read(pdf_path)?;
+    let content_str = String::from_utf8_lossy(&content);
+    let normalized = normalize_pdf_content(&content_str, filters);
+    Ok(blake3::hash(normalized.as_bytes()).to_hex().to_string())
+}
+
+/// Parses a checksums-file line of the form `<checksum>  <filename>`, the same
+/// `<digest>  <path>` shape [`crate::pdf::manifest`] uses for its own manifest lines.
+fn parse_checksum_line(line: &str) -> Option<(&str, &str)> {
+    let line = line.trim();
+    if line.is_empty() {
+        return None;
+    }
+    line.split_once("  ")
+        .or_else(|| line.split_once(char::is_whitespace))
+}
+
+/// Every `.pdf` file directly under `dir`, sorted by filename for deterministic output.
+fn list_pdfs(dir: &Path) -> Result<Vec<PathBuf>, Vex2PdfError> {
+    let mut paths: Vec<PathBuf> = fs::read_dir(dir)?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.extension()
+                .is_some_and(|ext| ext.eq_ignore_ascii_case("pdf"))
+        })
+        .collect();
+    paths.sort();
+    Ok(paths)
+}
+
+/// Recomputes every PDF in `pdfs_dir`'s normalized checksum and overwrites `checksums_file` with
+/// the fresh `<checksum>  <filename>` lines, sorted by filename. Returns how many were written.
+fn bless(pdfs_dir: &Path, checksums_file: &Path, filters: &[Regex]) -> Result<usize, Vex2PdfError> {
+    let pdfs = list_pdfs(pdfs_dir)?;
+
+    let mut lines = Vec::with_capacity(pdfs.len());
+    for pdf in &pdfs {
+        let filename = pdf
+            .file_name()
+            .and_then(|name| name.to_str())
+            .ok_or_else(|| Vex2PdfError::InvalidFileStem(pdf.clone()))?;
+        let checksum = compute_normalized_checksum(pdf, filters)?;
+        lines.push(format!("{checksum}  {filename}"));
+    }
+
+    let contents = if lines.is_empty() {
+        String::new()
+    } else {
+        lines.join("\n") + "\n"
+    };
+    fs_context::write(checksums_file, contents)?;
+
+    Ok(lines.len())
+}
+
+/// Compares every PDF in `pdfs_dir` against the checksum recorded for it in `checksums_file`,
+/// reporting one line per PDF whose normalized content no longer matches (or that has no
+/// recorded checksum at all), not just the first.
+fn verify(pdfs_dir: &Path, checksums_file: &Path, filters: &[Regex]) -> Result<(), Vex2PdfError> {
+    let recorded_contents = fs_context::read_to_string(checksums_file)?;
+    let recorded: HashMap<&str, &str> = recorded_contents
+        .lines()
+        .filter_map(parse_checksum_line)
+        .map(|(checksum, filename)| (filename, checksum))
+        .collect();
+
+    let mut mismatches = Vec::new();
+    for pdf in list_pdfs(pdfs_dir)? {
+        let Some(filename) = pdf.file_name().and_then(|name| name.to_str()) else {
+            continue;
+        };
+
+        let actual = compute_normalized_checksum(&pdf, filters)?;
+        match recorded.get(filename) {
+            Some(&expected) if expected == actual => {}
+            Some(expected) => mismatches.push(format!(
+                "{filename}: checksum mismatch (expected {expected}, got {actual})"
+            )),
+            None => mismatches.push(format!("{filename}: no recorded checksum")),
+        }
+    }
+
+    if mismatches.is_empty() {
+        Ok(())
+    } else {
+        Err(Vex2PdfError::SnapshotMismatch(mismatches))
+    }
+}
+
+/// Runs the PDF checksum snapshot subsystem for every `.pdf` directly under `pdfs_dir` against
+/// `checksums_file`. In [`OutputConflictHandling::Verify`] mode this reports every PDF whose
+/// normalized checksum no longer matches the recorded one; in [`OutputConflictHandling::Bless`]
+/// mode it instead overwrites `checksums_file` with freshly computed checksums.
+pub fn run_snapshot(
+    pdfs_dir: &Path,
+    checksums_file: &Path,
+    filters: &[Regex],
+    mode: OutputConflictHandling,
+) -> Result<(), Vex2PdfError> {
+    match mode {
+        OutputConflictHandling::Bless => {
+            let count = bless(pdfs_dir, checksums_file, filters)?;
+            info!(
+                "Blessed {count} checksum{} in {}",
+                if count == 1 { "" } else { "s" },
+                checksums_file.display()
+            );
+            Ok(())
+        }
+        OutputConflictHandling::Verify => verify(pdfs_dir, checksums_file, filters),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_pdf_content_strips_default_dynamic_lines() {
+        let filters = default_normalization_filters();
+        let content = "/CreateDate (D:20250101000000Z)\nKept line\n/ID[<aaa><bbb>]\n";
+
+        let normalized = normalize_pdf_content(content, &filters);
+
+        assert_eq!(normalized, "Kept line");
+    }
+
+    #[test]
+    fn test_bless_then_verify_round_trip() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let pdfs_dir = temp_dir.path().join("pdfs");
+        fs::create_dir_all(&pdfs_dir).unwrap();
+        fs::write(pdfs_dir.join("report.pdf"), b"%PDF-1.7 stable content").unwrap();
+
+        let checksums_file = temp_dir.path().join("expected_pdfs_chksums.txt");
+        let filters = default_normalization_filters();
+
+        run_snapshot(
+            &pdfs_dir,
+            &checksums_file,
+            &filters,
+            OutputConflictHandling::Bless,
+        )
+        .unwrap();
+
+        assert!(run_snapshot(
+            &pdfs_dir,
+            &checksums_file,
+            &filters,
+            OutputConflictHandling::Verify
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_verify_reports_mismatched_content() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let pdfs_dir = temp_dir.path().join("pdfs");
+        fs::create_dir_all(&pdfs_dir).unwrap();
+        fs::write(pdfs_dir.join("report.pdf"), b"%PDF-1.7 original content").unwrap();
+
+        let checksums_file = temp_dir.path().join("expected_pdfs_chksums.txt");
+        let filters = default_normalization_filters();
+
+        run_snapshot(
+            &pdfs_dir,
+            &checksums_file,
+            &filters,
+            OutputConflictHandling::Bless,
+        )
+        .unwrap();
+
+        fs::write(pdfs_dir.join("report.pdf"), b"%PDF-1.7 changed content").unwrap();
+
+        match run_snapshot(
+            &pdfs_dir,
+            &checksums_file,
+            &filters,
+            OutputConflictHandling::Verify,
+        ) {
+            Err(Vex2PdfError::SnapshotMismatch(violations)) => {
+                assert_eq!(violations.len(), 1);
+                assert!(violations[0].contains("report.pdf"));
+            }
+            other => panic!("expected SnapshotMismatch, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_verify_reports_missing_recorded_checksum() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let pdfs_dir = temp_dir.path().join("pdfs");
+        fs::create_dir_all(&pdfs_dir).unwrap();
+        fs::write(pdfs_dir.join("new.pdf"), b"%PDF-1.7 brand new").unwrap();
+
+        let checksums_file = temp_dir.path().join("expected_pdfs_chksums.txt");
+        fs::write(&checksums_file, "").unwrap();
+
+        match run_snapshot(
+            &pdfs_dir,
+            &checksums_file,
+            &default_normalization_filters(),
+            OutputConflictHandling::Verify,
+        ) {
+            Err(Vex2PdfError::SnapshotMismatch(violations)) => {
+                assert!(violations[0].contains("no recorded checksum"));
+            }
+            other => panic!("expected SnapshotMismatch, got {other:?}"),
+        }
+    }
+}
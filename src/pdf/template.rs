@@ -0,0 +1,132 @@
+//! JSON-driven report template describing the ordered section layout of a generated PDF.
+//!
+//! [`ReportTemplate::load`] reads a small JSON file naming which sections appear and in what
+//! order, as an alternative to [`crate::pdf::generator::PdfGenerator`]'s fixed cover/metadata/
+//! vulnerabilities/components layout — letting an organization match its own VEX disclosure
+//! format without forking the crate. For example:
+//!
+//! ```json
+//! {
+//!   "header": "Acme Corp Security Disclosure",
+//!   "sections": [
+//!     { "type": "cover" },
+//!     { "type": "metadata" },
+//!     { "type": "vulnerabilities" },
+//!     { "type": "components" },
+//!     { "type": "custom", "title": "Disclosure Policy", "text": "Issues are triaged within 5 business days." }
+//!   ]
+//! }
+//! ```
+//!
+//! This controls section selection, ordering, and free-text blocks only. Per-section field
+//! selection and column ordering aren't implemented yet — every field the matching built-in
+//! section already renders is still rendered in full; narrowing that down would mean adding
+//! options to [`TemplateSection::Components`]/[`TemplateSection::Vulnerabilities`] themselves.
+
+use crate::lib_utils::errors::Vex2PdfError;
+use crate::lib_utils::fs_context;
+use std::path::Path;
+
+/// One entry in [`ReportTemplate::sections`], naming a block of content to render.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum TemplateSection {
+    /// The report title, as the first block on the first page.
+    Cover,
+    /// The "Document Information" block: timestamp, tools, the BOM's own component, and the
+    /// basic BOM format/spec version/serial number fields.
+    Metadata,
+    /// The vulnerability list. See
+    /// [`PdfGenerator::render_vulns`](crate::pdf::generator::PdfGenerator).
+    Vulnerabilities,
+    /// The component and dependency lists.
+    Components,
+    /// A free-text block (e.g. a disclosure policy or contact paragraph) that doesn't come from
+    /// the VEX document itself.
+    Custom {
+        /// Rendered as a section heading, in the same style as the built-in sections.
+        title: String,
+        /// Rendered as a single paragraph below `title`.
+        text: String,
+    },
+}
+
+/// An ordered report layout, optionally overriding the page header text.
+///
+/// Deserialized from a small JSON file (see the module docs) named by
+/// [`Config::template`](crate::lib_utils::config::Config::template) and loaded once by
+/// [`PdfGenerator::new`](crate::pdf::generator::PdfGenerator::new). A missing `template` keeps
+/// the built-in fixed layout driven by `pure_bom_novulns`/`show_components`.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
+pub struct ReportTemplate {
+    /// Overrides the page header text shown on pages after the first, in place of the document
+    /// title.
+    #[serde(default)]
+    pub header: Option<String>,
+    /// The sections to render, in order.
+    pub sections: Vec<TemplateSection>,
+}
+
+impl ReportTemplate {
+    /// Parses a report template from the JSON file at `path`.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, Vex2PdfError> {
+        let path = path.as_ref();
+        let contents = fs_context::read_to_string(path)?;
+        serde_json::from_str(&contents).map_err(|e| {
+            Vex2PdfError::Parse(format!("invalid report template `{}`: {e}", path.display()))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load_parses_ordered_sections_and_header() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("template.json");
+        std::fs::write(
+            &path,
+            r#"{
+                "header": "Acme Corp",
+                "sections": [
+                    {"type": "cover"},
+                    {"type": "vulnerabilities"},
+                    {"type": "custom", "title": "Policy", "text": "See our disclosure policy."}
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        let template = ReportTemplate::load(&path).unwrap();
+        assert_eq!(template.header.as_deref(), Some("Acme Corp"));
+        assert_eq!(
+            template.sections,
+            vec![
+                TemplateSection::Cover,
+                TemplateSection::Vulnerabilities,
+                TemplateSection::Custom {
+                    title: "Policy".to_string(),
+                    text: "See our disclosure policy.".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_load_rejects_invalid_json() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("template.json");
+        std::fs::write(&path, "not json").unwrap();
+
+        let err = ReportTemplate::load(&path).unwrap_err();
+        assert!(matches!(err, Vex2PdfError::Parse(_)));
+    }
+
+    #[test]
+    fn test_load_missing_file_is_io_error() {
+        let err = ReportTemplate::load("/nonexistent/vex2pdf-template.json").unwrap_err();
+        assert!(matches!(err, Vex2PdfError::Io(_)));
+    }
+}
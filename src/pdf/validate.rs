@@ -0,0 +1,93 @@
+//! Structural validation for generated PDFs, beyond a bare `%PDF-` header check.
+//!
+//! [`verify_pdf`] actually opens the file with the `pdf` crate, resolves its catalog, and walks
+//! its page tree, so a truncated or structurally broken document is caught instead of silently
+//! treated as valid. [`run_verify`] drives this over a single file or a directory of `.pdf`
+//! files, backing the `vex2pdf --verify` subcommand.
+
+use crate::lib_utils::errors::Vex2PdfError;
+use crate::lib_utils::fs_context;
+use log::info;
+use pdf::file::FileOptions;
+use std::path::{Path, PathBuf};
+
+/// The result of successfully parsing and walking a PDF's page tree.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PdfVerifyReport {
+    /// Number of pages reachable by walking the document's page tree.
+    pub page_count: usize,
+}
+
+/// Opens `path` as a PDF, resolves its catalog, and walks its page tree, returning an error if
+/// the document fails to parse or its page tree can't be walked.
+pub fn verify_pdf(path: &Path) -> Result<PdfVerifyReport, Vex2PdfError> {
+    let file = FileOptions::cached().open(path).map_err(|e| {
+        Vex2PdfError::PdfValidation(format!("failed to parse `{}`: {e}", path.display()))
+    })?;
+
+    let mut page_count = 0usize;
+    for page in file.pages() {
+        page.map_err(|e| {
+            Vex2PdfError::PdfValidation(format!(
+                "failed to walk page tree of `{}`: {e}",
+                path.display()
+            ))
+        })?;
+        page_count += 1;
+    }
+
+    Ok(PdfVerifyReport { page_count })
+}
+
+/// Verifies `path`: a single PDF file, or every `.pdf` file in the first level of a directory.
+///
+/// Every candidate is checked even if an earlier one fails, so one corrupted file doesn't hide
+/// problems with the rest; the first failure (if any) is still what's returned, carrying every
+/// file's error.
+pub fn run_verify(path: &Path) -> Result<(), Vex2PdfError> {
+    let candidates = collect_pdf_candidates(path)?;
+
+    let mut errors = Vec::new();
+    for candidate in &candidates {
+        match verify_pdf(candidate) {
+            Ok(report) => info!(
+                "OK: {} ({} page{})",
+                candidate.display(),
+                report.page_count,
+                if report.page_count == 1 { "" } else { "s" }
+            ),
+            Err(e) => errors.push(format!("{}: {e}", candidate.display())),
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(Vex2PdfError::PdfValidation(errors.join("\n")))
+    }
+}
+
+/// Resolves `path` into the list of PDF files it names: itself if it's a file, or every `.pdf`
+/// file in its first level if it's a directory.
+fn collect_pdf_candidates(path: &Path) -> Result<Vec<PathBuf>, Vex2PdfError> {
+    if path.is_file() {
+        return Ok(vec![path.to_path_buf()]);
+    }
+
+    if !path.is_dir() {
+        return Err(Vex2PdfError::PdfValidation(format!(
+            "`{}` is neither a file nor a directory",
+            path.display()
+        )));
+    }
+
+    let mut candidates = Vec::new();
+    for entry in fs_context::read_dir(path)? {
+        let entry_path = entry?.path();
+        if entry_path.is_file() && entry_path.extension().is_some_and(|ext| ext == "pdf") {
+            candidates.push(entry_path);
+        }
+    }
+
+    Ok(candidates)
+}
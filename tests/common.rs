@@ -120,6 +120,7 @@ pub mod utils {
     use std::fs;
     use std::path::Path;
     use std::string::String;
+    use vex2pdf::pdf::snapshot;
 
     /// Check if byte output contains the expected text
     pub fn contains_text(bytes: &[u8], text: &str) -> bool {
@@ -158,23 +159,11 @@ pub mod utils {
         );
     }
 
-    /// Strip timestamp and dynamic ID-related lines from PDF content for comparison
+    /// Strip timestamp and dynamic ID-related lines from PDF content for comparison. Delegates to
+    /// [`vex2pdf::pdf::snapshot`]'s default filters so this suite normalizes PDFs the same way
+    /// `examples/generate_checksums` does when it records them.
     fn strip_pdf_timestamps(content: &str) -> String {
-        content
-            .lines()
-            .filter(|line| {
-                // Filter out timestamp-related lines and dynamic IDs
-                !line.contains("CreateDate")
-                    && !line.contains("ModifyDate")
-                    && !line.contains("MetadataDate")
-                    && !line.contains("CreationDate")
-                    && !line.contains("ModDate")
-                    && !line.contains("InstanceID")  // XMP metadata UUID
-                    && !line.contains("DocumentID")  // XMP document UUID
-                    && !line.contains("/ID[") // PDF document IDs
-            })
-            .collect::<Vec<_>>()
-            .join("\n")
+        snapshot::normalize_pdf_content(content, &snapshot::default_normalization_filters())
     }
 
     /// Compare PDF content ignoring timestamps
@@ -272,11 +261,10 @@ pub mod utils {
         strip_pdf_timestamps(&content_str)
     }
 
-    /// Calculate BLAKE3 checksum of normalized PDF content
+    /// Calculate BLAKE3 checksum of normalized PDF content, via [`vex2pdf::pdf::snapshot`].
     pub fn calculate_normalized_checksum(pdf_path: &Path) -> String {
-        let normalized = normalize_pdf_content(pdf_path);
-        let hash = blake3::hash(normalized.as_bytes());
-        hash.to_hex().to_string()
+        snapshot::compute_normalized_checksum(pdf_path, &snapshot::default_normalization_filters())
+            .expect("Failed to compute normalized checksum")
     }
 
     /// Assert that a generated PDF's normalized checksum matches the expected checksum
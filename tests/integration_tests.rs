@@ -406,6 +406,49 @@ fn test_batch_non_recursive_scanning() {
     );
 }
 
+#[test]
+fn test_batch_recursive_scanning_with_mirrored_output() {
+    // Test that `--recursive` descends into subdirectories and, combined with
+    // `--mirror-output-structure`, reproduces the input subtree under the output directory
+    let temp_input_dir = TempDir::new().expect("Failed to create temp input dir");
+    let temp_output_dir = TempDir::new().expect("Failed to create temp output dir");
+
+    let subdir = temp_input_dir.path().join("subdir");
+    std::fs::create_dir(&subdir).expect("Failed to create subdir");
+
+    std::fs::copy(paths::SIMPLE_BOM_PATH, subdir.join("nested.json"))
+        .expect("Failed to copy to subdir");
+
+    std::fs::copy(
+        paths::BOM_VDR_WITH_NO_VULNS,
+        temp_input_dir.path().join("top_level1.json"),
+    )
+    .expect("Failed to copy to main dir");
+
+    let output = Command::new(paths::PATH_TO_EXE)
+        .arg("-d")
+        .arg(temp_output_dir.path())
+        .arg("--recursive")
+        .arg("true")
+        .arg("--mirror-output-structure")
+        .arg("true")
+        .arg(temp_input_dir.path())
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(
+        output.status.success(),
+        "Recursive batch processing failed: {}",
+        utils::bytes_to_str(&output.stderr)
+    );
+
+    // Top-level file lands directly in the output directory
+    utils::assert_pdf_created(&temp_output_dir.path().join("top_level1.pdf"));
+
+    // Nested file's subdirectory is mirrored under the output directory
+    utils::assert_pdf_created(&temp_output_dir.path().join("subdir").join("nested.pdf"));
+}
+
 #[test]
 fn test_batch_empty_directory() {
     // Test that running on empty directory handles gracefully